@@ -0,0 +1,70 @@
+// Compares the bot's search on one thread with Lazy SMP on several: the time
+// taken to finish each depth and the positions searched per second
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chess::search::smp::parallel_search;
+use chess::Chess;
+
+const DEPTH: u8 = 4;
+
+const POSITIONS: [(&str, &str); 3] = [
+    (
+        "start",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    ),
+    (
+        "italian",
+        "r1bqk2r/pppp1ppp/2n2n2/2b1p3/2B1P3/2P2N2/PP1P1PPP/RNBQK2R w KQkq - 1 5",
+    ),
+    ("endgame", "8/5pk1/6p1/3R4/8/6P1/5PK1/2r5 w - - 0 40"),
+];
+
+fn main() {
+    let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+    let threads = [1, 2, 4, 8]
+        .into_iter()
+        .filter(|&threads| threads <= cores.max(2))
+        .collect::<Vec<_>>();
+    for (name, fen) in POSITIONS {
+        let chess = Chess::from_fen(fen).expect("valid FEN");
+        println!("{name}: {fen}");
+        for &threads in &threads {
+            let stop = AtomicBool::new(false);
+            let start = Instant::now();
+            let mut depths = Vec::new();
+            let mut report = |progress: &chess::search::minimax::SearchProgress| {
+                if depths.len() < progress.depth as usize {
+                    depths.push(start.elapsed());
+                }
+            };
+            let progress = parallel_search(
+                &chess,
+                &[],
+                DEPTH,
+                threads,
+                Arc::default(),
+                None,
+                &stop,
+                &mut report,
+            );
+            let elapsed = start.elapsed();
+            let times = depths
+                .iter()
+                .enumerate()
+                .map(|(i, time)| format!("d{} {}", i + 1, millis(*time)))
+                .collect::<Vec<_>>()
+                .join("  ");
+            println!(
+                "  {threads} thread(s): {times}  | {} nodes, {:.0} nodes/s",
+                progress.nodes,
+                progress.nodes as f64 / elapsed.as_secs_f64()
+            );
+        }
+    }
+}
+
+fn millis(time: Duration) -> String {
+    format!("{:.0}ms", time.as_secs_f64() * 1000.0)
+}
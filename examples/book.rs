@@ -0,0 +1,69 @@
+// Builds the bundled opening book, `assets/book.bin`, from the main lines
+// below. A move's weight is the number of lines playing it in the position,
+// so transpositions add up. Run with `cargo run --example book`.
+use std::collections::BTreeMap;
+
+use chess::search::book::{encode_move, BookEntry, OpeningBook};
+use chess::Chess;
+
+const LINES: [&str; 22] = [
+    // Ruy Lopez, closed and Berlin
+    "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8",
+    "e2e4 e7e5 g1f3 b8c6 f1b5 g8f6 e1g1 f6e4 d2d4 e4d6",
+    // Italian
+    "e2e4 e7e5 g1f3 b8c6 f1c4 f8c5 c2c3 g8f6 d2d3 d7d6",
+    // Scotch
+    "e2e4 e7e5 g1f3 b8c6 d2d4 e5d4 f3d4 g8f6 d4c6 b7c6",
+    // Petroff
+    "e2e4 e7e5 g1f3 g8f6 f3e5 d7d6 e5f3 f6e4 d2d4 d6d5",
+    // Sicilian: Najdorf, Sveshnikov, Kan and closed
+    "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6",
+    "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e5",
+    "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 a7a6 f1d3",
+    "e2e4 c7c5 b1c3 b8c6 g2g3 g7g6 f1g2 f8g7",
+    // French
+    "e2e4 e7e6 d2d4 d7d5 b1c3 g8f6 c1g5 f8e7 e4e5 f6d7",
+    // Caro-Kann
+    "e2e4 c7c6 d2d4 d7d5 b1c3 d5e4 c3e4 c8f5 e4g3 f5g6",
+    // Scandinavian
+    "e2e4 d7d5 e4d5 d8d5 b1c3 d5a5 d2d4 g8f6",
+    // Queen's Gambit: declined, Slav and accepted
+    "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3",
+    "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 d5c4 a2a4 c8f5",
+    "d2d4 d7d5 c2c4 d5c4 g1f3 g8f6 e2e3 e7e6 f1c4 c7c5",
+    // London
+    "d2d4 g8f6 g1f3 d7d5 c1f4 e7e6 e2e3 c7c5 c2c3 b8c6",
+    // Nimzo-Indian, Queen's Indian, King's Indian and Grünfeld
+    "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 f1d3 d7d5",
+    "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6 g2g3 c8b7 f1g2 f8e7",
+    "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5",
+    "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c4d5 f6d5 e2e4 d5c3 b2c3 f8g7",
+    // English and Réti
+    "c2c4 e7e5 b1c3 g8f6 g1f3 b8c6 g2g3 d7d5 c4d5 f6d5",
+    "g1f3 d7d5 g2g3 g8f6 f1g2 e7e6 e1g1 f8e7 d2d3",
+];
+
+fn main() {
+    let mut weights = BTreeMap::<(u64, u16), u16>::new();
+    for line in LINES {
+        let mut chess = Chess::new();
+        for uci in line.split_whitespace() {
+            let mv = chess
+                .parse_uci(uci)
+                .unwrap_or_else(|| panic!("{uci} is illegal in {line}"));
+            *weights
+                .entry((chess.hash(), encode_move(&chess, &mv)))
+                .or_default() += 1;
+            chess.play(&mv).expect("the move is played");
+        }
+    }
+    let book = OpeningBook::from_entries(
+        weights
+            .into_iter()
+            .map(|((key, mv), weight)| BookEntry { key, mv, weight })
+            .collect(),
+    );
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/book.bin");
+    std::fs::write(path, book.to_bytes()).expect("the book is written");
+    println!("wrote {path}");
+}
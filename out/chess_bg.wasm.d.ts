@@ -1,28 +0,0 @@
-/* tslint:disable */
-/* eslint-disable */
-export const memory: WebAssembly.Memory;
-export function main(a: number, b: number): number;
-export function wgpu_render_bundle_set_pipeline(a: number, b: number): void;
-export function wgpu_render_bundle_set_bind_group(a: number, b: number, c: number, d: number, e: number): void;
-export function wgpu_render_bundle_set_vertex_buffer(a: number, b: number, c: number, d: number, e: number): void;
-export function wgpu_render_bundle_set_push_constants(a: number, b: number, c: number, d: number, e: number): void;
-export function wgpu_render_bundle_draw(a: number, b: number, c: number, d: number, e: number): void;
-export function wgpu_render_bundle_draw_indexed(a: number, b: number, c: number, d: number, e: number, f: number): void;
-export function wgpu_render_bundle_draw_indirect(a: number, b: number, c: number): void;
-export function wgpu_render_bundle_draw_indexed_indirect(a: number, b: number, c: number): void;
-export function wgpu_render_bundle_set_index_buffer(a: number, b: number, c: number, d: number, e: number): void;
-export function wgpu_render_bundle_pop_debug_group(a: number): void;
-export function wgpu_render_bundle_insert_debug_marker(a: number, b: number): void;
-export function wgpu_render_bundle_push_debug_group(a: number, b: number): void;
-export function __wbindgen_malloc(a: number, b: number): number;
-export function __wbindgen_realloc(a: number, b: number, c: number, d: number): number;
-export const __wbindgen_export_2: WebAssembly.Table;
-export function _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h7702316efaa48dbe(a: number, b: number): void;
-export function _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__hb2297ff6d7cc832c(a: number, b: number): void;
-export function _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h1b3fc28bb100e636(a: number, b: number, c: number): void;
-export function _dyn_core__ops__function__FnMut__A_B___Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h23a294cd50ba000e(a: number, b: number, c: number, d: number): void;
-export function _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h4e00ba97495ae591(a: number, b: number): void;
-export function _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__he07e63f0b846af07(a: number, b: number, c: number): void;
-export function __wbindgen_exn_store(a: number): void;
-export function __wbindgen_free(a: number, b: number, c: number): void;
-export function __wbindgen_start(): void;
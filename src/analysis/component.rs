@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use chess::search::analysis::Analysis;
+
+use super::constants::DEFAULT_LINES;
+
+// How many of the best moves analysis shows
+#[derive(Resource, Debug)]
+pub struct AnalysisSettings {
+    pub lines: usize,
+}
+
+impl Default for AnalysisSettings {
+    fn default() -> Self {
+        Self {
+            lines: DEFAULT_LINES,
+        }
+    }
+}
+
+// The latest analysis of the position with `hash`, the one on the board
+#[derive(Resource, Debug, Default)]
+pub struct PositionAnalysis {
+    pub hash: Option<u64>,
+    pub analysis: Analysis,
+}
+
+// White's share of the evaluation bar, grown up from White's side
+#[derive(Component, Debug)]
+pub struct EvalBarFill;
+
+// Depth and best lines beside the board
+#[derive(Component, Debug)]
+pub struct AnalysisText;
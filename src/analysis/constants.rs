@@ -0,0 +1,13 @@
+use bevy::color::*;
+
+use crate::chessboard::constants::SQUARE_SIZE;
+
+pub const EVAL_BAR_WIDTH: f32 = 16.;
+pub const EVAL_BAR_HEIGHT: f32 = 8. * SQUARE_SIZE;
+// Centre of the bar, just left of the board
+pub const EVAL_BAR_X: f32 = -4. * SQUARE_SIZE - EVAL_BAR_WIDTH;
+pub const EVAL_BAR_BLACK: Srgba = Srgba::new(0.2, 0.2, 0.22, 1.);
+pub const EVAL_BAR_WHITE: Srgba = Srgba::new(0.95, 0.95, 0.95, 1.);
+
+// Best moves shown, each with the line expected to follow it
+pub const DEFAULT_LINES: usize = 3;
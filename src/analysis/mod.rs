@@ -0,0 +1,38 @@
+mod component;
+mod constants;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::bot::AnalysisWorker;
+use crate::chessboard::component::GameState;
+use crate::chessboard::system::{check_state_changed, handle_board_event, handle_game_command};
+use component::*;
+use system::*;
+
+// Free analysis: both sides are moved by hand while the engine searches the
+// position on the board in the background, showing an evaluation bar and its
+// best lines
+pub struct AnalysisPlugin;
+
+impl Plugin for AnalysisPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AnalysisSettings>()
+            .init_resource::<PositionAnalysis>()
+            .insert_non_send_resource(AnalysisWorker::default())
+            .add_systems(OnEnter(GameState::Analysis), start_analysis)
+            .add_systems(OnExit(GameState::Analysis), stop_analysis)
+            .add_systems(
+                Update,
+                (
+                    analyse_position
+                        .after(handle_board_event)
+                        .after(handle_game_command),
+                    show_analysis
+                        .after(analyse_position)
+                        .run_if(resource_changed::<PositionAnalysis>.or_else(check_state_changed)),
+                )
+                    .run_if(in_state(GameState::Analysis)),
+            );
+    }
+}
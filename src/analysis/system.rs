@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use chess::search::analysis::{Analysis, Line};
+
+use super::component::*;
+use super::constants::*;
+use crate::bot::component::Tablebases;
+use crate::bot::AnalysisWorker;
+use crate::chessboard::component::{Board, GameState, MoveHistory, PlayerKind, Players};
+use crate::engine::chess::Chess;
+
+// Both sides are moved by hand, under an evaluation bar and the best lines
+pub fn start_analysis(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut players: ResMut<Players>,
+) {
+    *players = Players {
+        white: PlayerKind::Human,
+        black: PlayerKind::Human,
+    };
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: EVAL_BAR_BLACK.into(),
+                custom_size: Some(Vec2::new(EVAL_BAR_WIDTH, EVAL_BAR_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_xyz(EVAL_BAR_X, 0., 1.),
+            ..default()
+        },
+        StateScoped(GameState::Analysis),
+    ));
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: EVAL_BAR_WHITE.into(),
+                custom_size: Some(Vec2::new(EVAL_BAR_WIDTH, EVAL_BAR_HEIGHT / 2.)),
+                anchor: Anchor::BottomCenter,
+                ..default()
+            },
+            transform: Transform::from_xyz(EVAL_BAR_X, -EVAL_BAR_HEIGHT / 2., 2.),
+            ..default()
+        },
+        EvalBarFill,
+        StateScoped(GameState::Analysis),
+    ));
+    commands.spawn((
+        TextBundle::from_section(
+            "Analysing..",
+            TextStyle {
+                font: asset_server.load("fonts/Gantari.ttf"),
+                font_size: 20.,
+                color: bevy::color::Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.),
+            top: Val::Px(60.),
+            ..default()
+        }),
+        AnalysisText,
+        StateScoped(GameState::Analysis),
+    ));
+}
+
+// Leaving analysis stops it, and games are against the bot again
+pub fn stop_analysis(
+    mut worker: NonSendMut<AnalysisWorker>,
+    mut analysis: ResMut<PositionAnalysis>,
+    mut players: ResMut<Players>,
+) {
+    worker.cancel();
+    *analysis = PositionAnalysis::default();
+    *players = Players::default();
+}
+
+// Starts analysing each new position on the board, as moves are played or
+// taken back, and picks up the analysis as it deepens
+pub fn analyse_position(
+    q_chess: Query<&Chess>,
+    history: Res<MoveHistory>,
+    settings: Res<AnalysisSettings>,
+    tablebases: Res<Tablebases>,
+    mut worker: NonSendMut<AnalysisWorker>,
+    mut analysis: ResMut<PositionAnalysis>,
+) {
+    let chess = q_chess.single();
+    let hash = chess.hash();
+    if analysis.hash != Some(hash) {
+        worker.start(
+            chess,
+            history.positions.iter().map(Chess::hash).collect(),
+            settings.lines,
+            tablebases.0.clone(),
+        );
+        *analysis = PositionAnalysis {
+            hash: Some(hash),
+            analysis: Analysis::default(),
+        };
+    }
+    if let Some((_, latest)) = worker
+        .progress()
+        .into_iter()
+        .rfind(|(searched, _)| *searched == hash)
+    {
+        analysis.analysis = latest;
+    }
+}
+
+// White's chance of winning from a score in tenths of a pawn, on the curve
+// Lichess uses for its evaluation bar
+fn white_share(score: i32) -> f32 {
+    let centipawns = score as f32 * 10.;
+    1. / (1. + (-0.003_682_08 * centipawns).exp())
+}
+
+// A line's score and moves in SAN, numbered from the position analysed
+fn line_text(chess: &Chess, analysis: &Analysis, line: &Line) -> String {
+    let mut text = analysis.score_text(line.score);
+    let mut position = chess.clone();
+    for (ply, mv) in line.moves.iter().enumerate() {
+        if position.white_turn {
+            text.push_str(&format!(" {}.", position.fullmove_number));
+        } else if ply == 0 {
+            text.push_str(&format!(" {}...", position.fullmove_number));
+        }
+        text.push_str(&format!(" {}", position.to_san(mv)));
+        position.play(mv);
+    }
+    text
+}
+
+pub fn show_analysis(
+    q_chess: Query<&Chess>,
+    q_board: Query<&Board>,
+    analysis: Res<PositionAnalysis>,
+    mut q_fill: Query<(&mut Sprite, &mut Transform), With<EvalBarFill>>,
+    mut q_text: Query<&mut Text, With<AnalysisText>>,
+) {
+    let chess = q_chess.single();
+    let board = q_board.single();
+    let analysis = Some(&analysis.analysis).filter(|_| analysis.hash == Some(chess.hash()));
+    let game_over = chess.legal_moves().is_empty();
+    let share = if game_over && chess.is_in_check() {
+        if chess.white_turn {
+            0.
+        } else {
+            1.
+        }
+    } else {
+        analysis.and_then(Analysis::score).map_or(0.5, white_share)
+    };
+    // White's share grows from the side of the board White is on
+    for (mut sprite, mut transform) in q_fill.iter_mut() {
+        sprite.custom_size = Some(Vec2::new(EVAL_BAR_WIDTH, EVAL_BAR_HEIGHT * share));
+        let (anchor, y_t) = if board.flipped {
+            (Anchor::TopCenter, EVAL_BAR_HEIGHT / 2.)
+        } else {
+            (Anchor::BottomCenter, -EVAL_BAR_HEIGHT / 2.)
+        };
+        sprite.anchor = anchor;
+        transform.translation.y = y_t;
+    }
+    let text = match analysis.filter(|analysis| !analysis.lines.is_empty()) {
+        _ if game_over && chess.is_in_check() => "Checkmate".to_string(),
+        _ if game_over => "Stalemate".to_string(),
+        Some(analysis) => {
+            let mut text = format!("Depth {}, {} positions", analysis.depth, analysis.nodes);
+            for line in &analysis.lines {
+                text.push('\n');
+                text.push_str(&line_text(chess, analysis, line));
+            }
+            text
+        }
+        None => "Analysing..".to_string(),
+    };
+    for mut analysis_text in q_text.iter_mut() {
+        analysis_text.sections[0].value = text.clone();
+    }
+}
@@ -0,0 +1,30 @@
+// Indexes a PGN database for the opening explorer:
+// `opening_index games.pgn [index.bin] [plies]`, writing `explorer.bin` from
+// the first 30 plies of each game by default.
+use chess::engine::pgn::PgnGame;
+use chess::search::explorer::OpeningIndex;
+
+const DEFAULT_PLIES: usize = 30;
+
+fn main() -> std::io::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let Some(input) = args.next() else {
+        eprintln!("usage: opening_index games.pgn [index.bin] [plies]");
+        std::process::exit(2);
+    };
+    let output = args.next().unwrap_or_else(|| "explorer.bin".to_string());
+    let plies = args
+        .next()
+        .and_then(|plies| plies.parse().ok())
+        .unwrap_or(DEFAULT_PLIES);
+
+    let games = PgnGame::parse_all(&std::fs::read_to_string(&input)?);
+    let index = OpeningIndex::build(&games, plies);
+    std::fs::write(&output, index.to_bytes())?;
+    println!(
+        "Indexed {} games from {input} into {output}, {} moves",
+        games.len(),
+        index.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,15 @@
+// Relay server for online games: `relay [address]`, listening on
+// 127.0.0.1:9001 by default.
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> std::io::Result<()> {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:9001".to_string());
+    let listener = std::net::TcpListener::bind(&address)?;
+    println!("Relay listening on ws://{}", listener.local_addr()?);
+    chess::net::relay::serve(listener)
+}
+
+// The relay only runs natively
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use chess::search::minimax::SearchProgress;
+use chess::search::syzygy::Tablebase;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::chess::Move;
+
+// How strong the bot plays, as the depth it searches to, and how many threads
+// it searches with
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BotSettings {
+    pub depth: u8,
+    // Depends on the machine, so it isn't saved with the game
+    #[serde(skip)]
+    pub threads: usize,
+    // Plies from the start of the game the bot plays from its opening book
+    // for, 0 for never
+    pub book_depth: u16,
+}
+
+impl Default for BotSettings {
+    fn default() -> Self {
+        Self {
+            depth: 3,
+            threads: default_threads(),
+            book_depth: 16,
+        }
+    }
+}
+
+// CHESS_THREADS if set, otherwise every core but one, left for drawing frames
+fn default_threads() -> usize {
+    std::env::var("CHESS_THREADS")
+        .ok()
+        .and_then(|threads| threads.parse().ok())
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism().map_or(1, |cores| cores.get().saturating_sub(1))
+        })
+        .max(1)
+}
+
+// How far the bot's search has got in the position with `hash`
+#[derive(Event, Debug, Clone)]
+pub struct BotProgress {
+    pub hash: u64,
+    pub progress: SearchProgress,
+}
+
+// Syzygy tables from the directory in SYZYGY_PATH, when set and the game is
+// built with the `syzygy` feature
+#[derive(Resource, Default)]
+pub struct Tablebases(pub Option<Arc<Tablebase>>);
+
+impl Tablebases {
+    pub fn from_env() -> Tablebases {
+        let Ok(dir) = std::env::var("SYZYGY_PATH") else {
+            return Tablebases(None);
+        };
+        match Tablebase::open(&dir) {
+            Ok(tablebase) => {
+                info!(
+                    "Syzygy tables up to {} pieces from {dir}",
+                    tablebase.max_pieces()
+                );
+                Tablebases(Some(Arc::new(tablebase)))
+            }
+            Err(err) => {
+                warn!("Can't open Syzygy tables in {dir}: {err}");
+                Tablebases(None)
+            }
+        }
+    }
+}
+
+// A hint in the position with `hash`: the bot searches for a while, then the
+// piece to move is shown and, when asked for again, where it goes
+#[derive(Resource, Debug, Default)]
+pub struct Hint {
+    pub hash: u64,
+    // Squares asked for, 1 for the piece and 2 with its destination
+    pub asked: u8,
+    // Squares on the board since the hint was last asked for
+    pub shown: u8,
+    // Best move of the deepest search so far
+    pub best: Option<Move>,
+    // Time left for the search, None once it is over
+    pub search: Option<Timer>,
+}
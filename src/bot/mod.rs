@@ -1,16 +1,50 @@
-mod bot1;
+pub mod component;
 mod system;
+mod worker;
 
 use bevy::prelude::*;
+use chess::search::book::OpeningBook;
 
-use crate::chessboard::component::BoardEvent;
+use crate::chessboard::component::{BoardEvent, GameState};
+use crate::chessboard::system::{animations_idle, handle_board_event, handle_game_command};
+use component::{BotProgress, BotSettings, Hint, Tablebases};
 use system::*;
+pub use worker::{AnalysisWorker, ReviewWorker};
+use worker::{HintWorker, SearchWorker};
 
 pub struct BotPlugin;
 
 impl Plugin for BotPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (handle_move,))
+        // Wait for the previous move to finish animating so the bot's reply
+        // is played out where the human can follow it.
+        app.init_resource::<BotSettings>()
+            .insert_resource(OpeningBook::bundled())
+            .insert_resource(Tablebases::from_env())
+            .add_event::<BotProgress>()
+            .init_resource::<Hint>()
+            .insert_non_send_resource(SearchWorker::default())
+            .insert_non_send_resource(HintWorker::default())
+            .add_systems(
+                Update,
+                (
+                    cancel_search.after(handle_game_command),
+                    // After the move is applied, so the position searched is
+                    // the one now on the board
+                    handle_move
+                        .after(cancel_search)
+                        .after(handle_board_event)
+                        .run_if(in_state(GameState::Playing).and_then(animations_idle)),
+                    show_bot_progress.after(handle_move),
+                    answer_draw_offer,
+                    request_hint
+                        .after(handle_game_command)
+                        .run_if(in_state(GameState::Playing)),
+                    show_hint
+                        .after(request_hint)
+                        .run_if(in_state(GameState::Playing)),
+                ),
+            )
             .add_event::<BoardEvent>();
     }
 }
@@ -1,49 +1,277 @@
-use std::{i32, time::Duration};
+use bevy::prelude::*;
+use chess::search::book::OpeningBook;
+use chess::search::minimax::accepts_draw;
 
-use bevy::prelude::{EventWriter, Query};
-use bevy_async_task::{AsyncTaskRunner, AsyncTaskStatus};
+use crate::{
+    chessboard::component::{
+        Board, BoardEvent, Clock, GameCommand, GameEvent, MoveHistory, PlayerKind, Players,
+        TextInfo,
+    },
+    engine::chess::{Chess, Move},
+};
 
-use crate::{chessboard::component::BoardEvent, engine::chess::Chess};
+use super::component::{BotProgress, BotSettings, Hint, Tablebases};
+use super::worker::{HintWorker, SearchStatus, SearchWorker};
 
-use super::bot1::minimax;
+// The bot takes at least this long over a move at the default depth, so it
+// doesn't reply the instant the human has moved
+const MIN_THINK_SECS: f32 = 0.8;
 
+// A hint is the best move found in this long, unless the search reaches
+// HINT_DEPTH first
+const HINT_SECS: f32 = 2.;
+const HINT_DEPTH: u8 = 10;
+
+#[allow(clippy::too_many_arguments)]
 pub fn handle_move(
     q_chess: Query<&Chess>,
-    mut async_runner: AsyncTaskRunner<Chess>,
+    q_board: Query<&Board>,
+    players: Res<Players>,
+    settings: Res<BotSettings>,
+    book: Res<OpeningBook>,
+    tablebases: Res<Tablebases>,
+    history: Res<MoveHistory>,
+    time: Res<Time>,
+    mut worker: NonSendMut<SearchWorker>,
+    mut think: Local<Timer>,
+    // A book move waiting out the think time, with the hash of its position
+    mut book_move: Local<Option<(u64, Move)>>,
     mut ev_board: EventWriter<BoardEvent>,
+    mut ev_progress: EventWriter<BotProgress>,
 ) {
-    let chess = q_chess.single().clone();
+    let chess = q_chess.single();
 
-    if chess.is_checkmate() {
+    if q_board.single().game_over || chess.is_checkmate() {
         return;
     }
 
-    if chess.white_turn {
+    if players.kind(chess.white_turn) != PlayerKind::Bot {
         return;
     }
 
-    async fn get_move(c: Chess) -> Chess {
-        // let rnd = thread_rng().gen_range(0..10);
-        let depth = 3;
-        if depth == 3 {
-            async_std::task::sleep(Duration::from_millis(800)).await;
+    for (hash, progress) in worker.progress() {
+        ev_progress.send(BotProgress { hash, progress });
+    }
+    if !think.tick(time.delta()).finished() {
+        return;
+    }
+    if let Some((hash, mv)) = book_move.take() {
+        if hash == chess.hash() {
+            play_move(&mut ev_board, mv);
+        }
+        return;
+    }
+    match worker.poll() {
+        SearchStatus::Idle => {
+            // Book moves stop once the game is `book_depth` plies old
+            let ply = chess.fullmove_number.saturating_sub(1) * 2 + !chess.white_turn as u16;
+            match book.pick(chess).filter(|_| ply < settings.book_depth) {
+                Some(mv) => *book_move = Some((chess.hash(), mv)),
+                None => worker.start(
+                    chess,
+                    history.positions.iter().map(Chess::hash).collect(),
+                    settings.depth,
+                    settings.threads,
+                    tablebases.0.clone(),
+                ),
+            }
+            let secs = if settings.depth == 3 {
+                MIN_THINK_SECS
+            } else {
+                0.
+            };
+            *think = Timer::from_seconds(secs, TimerMode::Once);
+        }
+        SearchStatus::Searching => {}
+        // A search of another position is dropped, and the next frame
+        // starts one for this position
+        SearchStatus::Finished { hash, best } if hash == chess.hash() => {
+            if let Some(mv) = best {
+                play_move(&mut ev_board, mv);
+            }
         }
-        let (chess, _) = minimax(&c, depth, false, i32::MIN, i32::MAX).await;
-        let chess = chess.unwrap();
-        return chess.clone();
+        SearchStatus::Finished { .. } => {}
     }
+}
 
-    match async_runner.poll() {
-        AsyncTaskStatus::Idle => {
-            async_runner.start(get_move(chess.clone()));
+fn play_move(ev_board: &mut EventWriter<BoardEvent>, mv: Move) {
+    let from = Chess::index_to_position(mv.from);
+    let to = Chess::index_to_position(mv.to);
+    ev_board.send(match mv.promotion {
+        Some(piece) => BoardEvent::PromotePiece(from, to, piece),
+        None => BoardEvent::MovePiece(from, to),
+    });
+}
+
+// Shows how deep the bot has searched under its status
+pub fn show_bot_progress(
+    mut ev_progress: EventReader<BotProgress>,
+    q_chess: Query<&Chess>,
+    mut q_text: Query<(&TextInfo, &mut Text)>,
+) {
+    let chess = q_chess.single();
+    let Some(ev) = ev_progress
+        .read()
+        .filter(|ev| ev.hash == chess.hash())
+        .last()
+    else {
+        return;
+    };
+    let status_type = if chess.white_turn { 3 } else { 4 };
+    let check = if chess.is_in_check() { "Check! " } else { "" };
+    for (text_info, mut text) in q_text.iter_mut() {
+        if text_info.text_type == status_type {
+            text.sections[0].value = format!(
+                "{check}Thinking.. depth {}, {} positions",
+                ev.progress.depth, ev.progress.nodes
+            );
+        }
+    }
+}
+
+// Anything changing the game, like a new game, an undo or a resignation,
+// stops the bot's search. Another game also clears the transposition table.
+pub fn cancel_search(
+    mut ev_command: EventReader<GameCommand>,
+    mut worker: NonSendMut<SearchWorker>,
+) {
+    for ev in ev_command.read() {
+        if matches!(
+            ev,
+            GameCommand::NewGame | GameCommand::LoadGame { .. } | GameCommand::ResumeGame
+        ) {
+            worker.clear_table();
         }
-        AsyncTaskStatus::Pending => {}
-        AsyncTaskStatus::Finished(c) => {
-            if let Some(last_move) = c.last_move {
-                let from = Chess::index_to_position(last_move.0);
-                let to = Chess::index_to_position(last_move.1);
-                ev_board.send(BoardEvent::MovePiece(from, to));
+        if !matches!(
+            ev,
+            GameCommand::OfferDraw { .. }
+                | GameCommand::FlipBoard
+                | GameCommand::ExportPgn
+                | GameCommand::Hint
+        ) {
+            worker.cancel();
+        }
+    }
+}
+
+// Answers the human's draw offers
+pub fn answer_draw_offer(
+    q_chess: Query<&Chess>,
+    q_board: Query<&Board>,
+    players: Res<Players>,
+    mut ev_command: ParamSet<(EventReader<GameCommand>, EventWriter<GameCommand>)>,
+    mut ev_game: EventWriter<GameEvent>,
+) {
+    let offered = ev_command
+        .p0()
+        .read()
+        .any(|command| matches!(command, GameCommand::OfferDraw { is_white: true }));
+    if !offered || q_board.single().game_over || players.black != PlayerKind::Bot {
+        return;
+    }
+    if accepts_draw(q_chess.single()) {
+        ev_command.p1().send(GameCommand::AgreeDraw);
+    } else {
+        ev_game.send(GameEvent::DrawDeclined);
+    }
+}
+
+// Starts searching for a hint on the human's turn, counting it for their side,
+// or shows more of the hint already found
+#[allow(clippy::too_many_arguments)]
+pub fn request_hint(
+    mut ev_command: EventReader<GameCommand>,
+    q_chess: Query<&Chess>,
+    q_board: Query<&Board>,
+    players: Res<Players>,
+    settings: Res<BotSettings>,
+    tablebases: Res<Tablebases>,
+    mut history: ResMut<MoveHistory>,
+    mut hint: ResMut<Hint>,
+    mut worker: NonSendMut<HintWorker>,
+) {
+    if !ev_command
+        .read()
+        .any(|command| matches!(command, GameCommand::Hint))
+    {
+        return;
+    }
+    let chess = q_chess.single();
+    if q_board.single().game_over || players.is_online() || !players.is_human(chess.white_turn) {
+        return;
+    }
+    if hint.asked == 0 || hint.hash != chess.hash() {
+        *hint = Hint {
+            hash: chess.hash(),
+            search: Some(Timer::from_seconds(HINT_SECS, TimerMode::Once)),
+            ..default()
+        };
+        worker.0.start(
+            chess,
+            history.positions.iter().map(Chess::hash).collect(),
+            HINT_DEPTH,
+            settings.threads,
+            tablebases.0.clone(),
+        );
+        history.hints[Clock::side(chess.white_turn)] += 1;
+    }
+    hint.asked = (hint.asked + 1).min(2);
+    // Selecting a piece clears the hint from the board, so it is shown again
+    hint.shown = 0;
+}
+
+// Ends the hint's search when it finishes or its time is up, and highlights
+// as many of its squares as were asked for. Moving drops the hint.
+pub fn show_hint(
+    q_chess: Query<&Chess>,
+    time: Res<Time>,
+    mut hint: ResMut<Hint>,
+    mut worker: NonSendMut<HintWorker>,
+    mut ev_board: EventWriter<BoardEvent>,
+) {
+    if hint.asked == 0 {
+        return;
+    }
+    if hint.hash != q_chess.single().hash() {
+        worker.0.cancel();
+        *hint = Hint::default();
+        return;
+    }
+    if hint.search.is_some() {
+        for (hash, progress) in worker.0.progress() {
+            if hash == hint.hash && progress.best.is_some() {
+                hint.best = progress.best;
+            }
+        }
+        let finished = match worker.0.poll() {
+            SearchStatus::Searching => false,
+            SearchStatus::Finished { hash, best } if hash == hint.hash => {
+                hint.best = best.or(hint.best);
+                true
             }
+            _ => true,
+        };
+        let delta = time.delta();
+        let timed_out = hint
+            .search
+            .as_mut()
+            .is_some_and(|timer| timer.tick(delta).finished());
+        if !finished && !timed_out {
+            return;
         }
+        worker.0.cancel();
+        hint.search = None;
+    }
+    let Some(best) = hint.best else {
+        return;
+    };
+    if hint.shown == hint.asked {
+        return;
     }
+    hint.shown = hint.asked;
+    let to = (hint.asked > 1).then(|| Chess::index_to_position(best.to));
+    ev_board.send(BoardEvent::ShowHint(
+        Chess::index_to_position(best.from),
+        to,
+    ));
 }
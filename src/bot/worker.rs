@@ -0,0 +1,749 @@
+// Runs the bot's search away from the frame loop: natively on Bevy's async
+// compute thread pool, with Lazy SMP helper threads alongside, and on the web
+// in a Web Worker (`worker.js`) that loads this same module. Progress and
+// results are picked up by polling, and carry the hash of the position searched.
+// Analysis runs the same way, searching until it is cancelled, and so do game
+// reviews, reporting each move as it is reviewed.
+use std::sync::Arc;
+
+use chess::search::analysis::Analysis;
+use chess::search::minimax::SearchProgress;
+use chess::search::review::MoveReview;
+use chess::search::syzygy::Tablebase;
+
+use crate::engine::chess::{Chess, Move};
+
+// How deep analysis goes before it stops on its own
+const ANALYSIS_DEPTH: u8 = 12;
+
+pub enum SearchStatus {
+    Idle,
+    Searching,
+    // The best move in the position with `hash`, None if there was none
+    Finished { hash: u64, best: Option<Move> },
+}
+
+// The bot's search run for the human's hints, apart from its own moves
+#[derive(Default)]
+pub struct HintWorker(pub SearchWorker);
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::*;
+#[cfg(target_arch = "wasm32")]
+pub use web::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::{channel, Receiver};
+
+    use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+    use chess::search::analysis::analyse;
+    use chess::search::review::review_game;
+    use chess::search::smp::parallel_search;
+    use chess::search::tt::TranspositionTable;
+
+    use super::*;
+
+    struct RunningSearch {
+        task: Task<Option<Move>>,
+        hash: u64,
+        progress: Receiver<SearchProgress>,
+        stop: Arc<AtomicBool>,
+    }
+
+    #[derive(Default)]
+    pub struct SearchWorker {
+        search: Option<RunningSearch>,
+        // Kept between searches, so each starts from what the last one found
+        table: Arc<TranspositionTable>,
+    }
+
+    impl SearchWorker {
+        // Searches the position, stopping any search still running. `history`
+        // holds the hashes of the game's positions before it.
+        pub fn start(
+            &mut self,
+            chess: &Chess,
+            history: Vec<u64>,
+            depth: u8,
+            threads: usize,
+            tablebase: Option<Arc<Tablebase>>,
+        ) {
+            self.cancel();
+            let chess = chess.clone();
+            let hash = chess.hash();
+            let stop = Arc::new(AtomicBool::new(false));
+            let (progress_tx, progress) = channel();
+            let task = AsyncComputeTaskPool::get().spawn({
+                let stop = stop.clone();
+                let table = self.table.clone();
+                async move {
+                    let mut report = |progress: &SearchProgress| {
+                        let _ = progress_tx.send(*progress);
+                    };
+                    parallel_search(
+                        &chess,
+                        &history,
+                        depth,
+                        threads,
+                        table,
+                        tablebase,
+                        &stop,
+                        &mut report,
+                    )
+                    .best
+                }
+            });
+            self.search = Some(RunningSearch {
+                task,
+                hash,
+                progress,
+                stop,
+            });
+        }
+
+        pub fn cancel(&mut self) {
+            if let Some(search) = self.search.take() {
+                search.stop.store(true, Ordering::Relaxed);
+            }
+        }
+
+        // Drops what earlier searches found, for when another game starts
+        pub fn clear_table(&mut self) {
+            self.table = Arc::default();
+        }
+
+        // Progress reported since the last call, with the hash of the position
+        pub fn progress(&mut self) -> Vec<(u64, SearchProgress)> {
+            let Some(search) = &self.search else {
+                return Vec::new();
+            };
+            search
+                .progress
+                .try_iter()
+                .map(|progress| (search.hash, progress))
+                .collect()
+        }
+
+        pub fn poll(&mut self) -> SearchStatus {
+            let Some(search) = self.search.as_mut() else {
+                return SearchStatus::Idle;
+            };
+            match block_on(poll_once(&mut search.task)) {
+                Some(best) => {
+                    let hash = search.hash;
+                    self.search = None;
+                    SearchStatus::Finished { hash, best }
+                }
+                None => SearchStatus::Searching,
+            }
+        }
+    }
+
+    struct RunningAnalysis {
+        _task: Task<()>,
+        hash: u64,
+        progress: Receiver<Analysis>,
+        stop: Arc<AtomicBool>,
+    }
+
+    #[derive(Default)]
+    pub struct AnalysisWorker {
+        analysis: Option<RunningAnalysis>,
+    }
+
+    impl AnalysisWorker {
+        // Analyses the position for its best `lines` moves, stopping any
+        // analysis still running. `history` holds the hashes of the game's
+        // positions before it.
+        pub fn start(
+            &mut self,
+            chess: &Chess,
+            history: Vec<u64>,
+            lines: usize,
+            tablebase: Option<Arc<Tablebase>>,
+        ) {
+            self.cancel();
+            let chess = chess.clone();
+            let hash = chess.hash();
+            let stop = Arc::new(AtomicBool::new(false));
+            let (progress_tx, progress) = channel();
+            let task = AsyncComputeTaskPool::get().spawn({
+                let stop = stop.clone();
+                async move {
+                    let mut report = |analysis: &Analysis| {
+                        let _ = progress_tx.send(analysis.clone());
+                    };
+                    analyse(
+                        &chess,
+                        &history,
+                        ANALYSIS_DEPTH,
+                        lines,
+                        tablebase,
+                        &stop,
+                        &mut report,
+                    );
+                }
+            });
+            self.analysis = Some(RunningAnalysis {
+                _task: task,
+                hash,
+                progress,
+                stop,
+            });
+        }
+
+        pub fn cancel(&mut self) {
+            if let Some(analysis) = self.analysis.take() {
+                analysis.stop.store(true, Ordering::Relaxed);
+            }
+        }
+
+        // Analyses reported since the last call, with the hash of the position
+        pub fn progress(&mut self) -> Vec<(u64, Analysis)> {
+            let Some(analysis) = &self.analysis else {
+                return Vec::new();
+            };
+            analysis
+                .progress
+                .try_iter()
+                .map(|progress| (analysis.hash, progress))
+                .collect()
+        }
+    }
+
+    struct RunningReview {
+        _task: Task<()>,
+        progress: Receiver<(usize, MoveReview)>,
+        stop: Arc<AtomicBool>,
+    }
+
+    #[derive(Default)]
+    pub struct ReviewWorker {
+        review: Option<RunningReview>,
+    }
+
+    impl ReviewWorker {
+        // Reviews a game from the positions before each move and the moves
+        // played, searching to `depth`, and stops any review still running
+        pub fn start(&mut self, positions: Vec<Chess>, moves: Vec<Move>, depth: u8) {
+            self.cancel();
+            let stop = Arc::new(AtomicBool::new(false));
+            let (progress_tx, progress) = channel();
+            let task = AsyncComputeTaskPool::get().spawn({
+                let stop = stop.clone();
+                async move {
+                    let mut report = |ply: usize, review: &MoveReview| {
+                        let _ = progress_tx.send((ply, *review));
+                    };
+                    review_game(&positions, &moves, depth, &stop, &mut report);
+                }
+            });
+            self.review = Some(RunningReview {
+                _task: task,
+                progress,
+                stop,
+            });
+        }
+
+        pub fn cancel(&mut self) {
+            if let Some(review) = self.review.take() {
+                review.stop.store(true, Ordering::Relaxed);
+            }
+        }
+
+        // Moves reviewed since the last call, with their ply
+        pub fn progress(&mut self) -> Vec<(usize, MoveReview)> {
+            let Some(review) = &self.review else {
+                return Vec::new();
+            };
+            review.progress.try_iter().collect()
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::cell::{Cell, RefCell};
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+    use std::sync::atomic::AtomicBool;
+
+    use chess::search::analysis::{analyse, Line};
+    use chess::search::minimax::Search;
+    use chess::search::review::review_game;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+    use web_sys::{MessageEvent, Worker, WorkerOptions, WorkerType};
+
+    use super::*;
+
+    // What the worker posts back, as JSON text. Moves are in UCI notation.
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum WorkerMessage {
+        Progress {
+            depth: u8,
+            nodes: u64,
+            best: Option<String>,
+            score: i32,
+        },
+        Done {
+            best: Option<String>,
+        },
+        // Lines as their moves and score
+        Analysis {
+            depth: u8,
+            nodes: u64,
+            lines: Vec<(Vec<String>, i32)>,
+        },
+        Reviewed {
+            ply: usize,
+            best: String,
+            best_score: i32,
+            score: i32,
+        },
+    }
+
+    // The game's earlier positions as `worker.js` passes them on, space
+    // separated hexadecimal hashes
+    fn encode_history(history: &[u64]) -> String {
+        history
+            .iter()
+            .map(|hash| format!("{hash:x}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn decode_history(history: &str) -> Vec<u64> {
+        history
+            .split_whitespace()
+            .filter_map(|hash| u64::from_str_radix(hash, 16).ok())
+            .collect()
+    }
+
+    fn post_message(post: &js_sys::Function, message: WorkerMessage) {
+        if let Ok(text) = serde_json::to_string(&message) {
+            let _ = post.call1(&JsValue::NULL, &JsValue::from_str(&text));
+        }
+    }
+
+    // Called by `worker.js` for each search, with the game's earlier positions
+    // as space separated hexadecimal hashes and a function posting the
+    // worker's messages to the page
+    #[wasm_bindgen(js_name = searchBestMove)]
+    pub fn search_best_move(fen: &str, history: &str, depth: u8, post: &js_sys::Function) {
+        let send = |message: WorkerMessage| post_message(post, message);
+        let Some(chess) = Chess::from_fen(fen) else {
+            return send(WorkerMessage::Done { best: None });
+        };
+        let stop = AtomicBool::new(false);
+        let mut report = |progress: &SearchProgress| {
+            send(WorkerMessage::Progress {
+                depth: progress.depth,
+                nodes: progress.nodes,
+                best: progress.best.map(|mv| mv.to_uci()),
+                score: progress.score,
+            })
+        };
+        let best = Search::new(&stop, &mut report)
+            .with_history(decode_history(history))
+            .run(&chess, depth);
+        send(WorkerMessage::Done {
+            best: best.map(|mv| mv.to_uci()),
+        });
+    }
+
+    // Called by `worker.js` to analyse a position, posting each iteration's
+    // lines until the worker is terminated
+    #[wasm_bindgen(js_name = analysePosition)]
+    pub fn analyse_position(fen: &str, history: &str, lines: usize, post: &js_sys::Function) {
+        let Some(chess) = Chess::from_fen(fen) else {
+            return;
+        };
+        let stop = AtomicBool::new(false);
+        let mut report = |analysis: &Analysis| {
+            post_message(
+                post,
+                WorkerMessage::Analysis {
+                    depth: analysis.depth,
+                    nodes: analysis.nodes,
+                    lines: analysis
+                        .lines
+                        .iter()
+                        .map(|line| {
+                            (
+                                line.moves.iter().map(|mv| mv.to_uci()).collect(),
+                                line.score,
+                            )
+                        })
+                        .collect(),
+                },
+            )
+        };
+        analyse(
+            &chess,
+            &decode_history(history),
+            ANALYSIS_DEPTH,
+            lines,
+            None,
+            &stop,
+            &mut report,
+        );
+    }
+
+    // Called by `worker.js` to review a game, given as the starting position
+    // and its moves in UCI separated by spaces, posting each move's review
+    #[wasm_bindgen(js_name = reviewGame)]
+    pub fn review_game_moves(fen: &str, moves: &str, depth: u8, post: &js_sys::Function) {
+        let Some(mut chess) = Chess::from_fen(fen) else {
+            return;
+        };
+        let mut positions = Vec::new();
+        let mut played = Vec::new();
+        for uci in moves.split_whitespace() {
+            let Some(mv) = chess.parse_uci(uci) else {
+                break;
+            };
+            positions.push(chess.clone());
+            played.push(mv);
+            chess.play(&mv);
+        }
+        let stop = AtomicBool::new(false);
+        let mut report = |ply: usize, review: &MoveReview| {
+            post_message(
+                post,
+                WorkerMessage::Reviewed {
+                    ply,
+                    best: review.best.to_uci(),
+                    best_score: review.best_score,
+                    score: review.score,
+                },
+            )
+        };
+        review_game(&positions, &played, depth, &stop, &mut report);
+    }
+
+    struct RunningWorker {
+        worker: Worker,
+        // The worker's messages not yet picked up
+        messages: Rc<RefCell<VecDeque<WorkerMessage>>>,
+        _on_message: Closure<dyn FnMut(MessageEvent)>,
+        _on_error: Closure<dyn FnMut()>,
+    }
+
+    impl RunningWorker {
+        // Starts `worker.js` on the request, setting `unavailable` when the
+        // worker can't be loaded
+        fn spawn(
+            request: &serde_json::Value,
+            unavailable: &Rc<Cell<bool>>,
+        ) -> Option<RunningWorker> {
+            let mut options = WorkerOptions::new();
+            options.type_(WorkerType::Module);
+            let Ok(worker) = Worker::new_with_options("worker.js", &options) else {
+                unavailable.set(true);
+                return None;
+            };
+            let messages = Rc::new(RefCell::new(VecDeque::new()));
+            let on_message = {
+                let messages = messages.clone();
+                Closure::<dyn FnMut(MessageEvent)>::new(move |ev: MessageEvent| {
+                    if let Some(message) = ev
+                        .data()
+                        .as_string()
+                        .and_then(|text| serde_json::from_str(&text).ok())
+                    {
+                        messages.borrow_mut().push_back(message);
+                    }
+                })
+            };
+            let on_error = {
+                let unavailable = unavailable.clone();
+                Closure::<dyn FnMut()>::new(move || unavailable.set(true))
+            };
+            worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+            worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+            if let Ok(request) = js_sys::JSON::parse(&request.to_string()) {
+                let _ = worker.post_message(&request);
+            }
+            Some(RunningWorker {
+                worker,
+                messages,
+                _on_message: on_message,
+                _on_error: on_error,
+            })
+        }
+    }
+
+    #[derive(Default)]
+    pub struct SearchWorker {
+        worker: Option<RunningWorker>,
+        // The search in progress, with the game's earlier positions, run on
+        // the page instead when the worker can't be loaded
+        search: Option<(Chess, Vec<u64>, u8)>,
+        unavailable: Rc<Cell<bool>>,
+    }
+
+    impl SearchWorker {
+        // Searches the position in a fresh worker, stopping any search still
+        // running. The worker searches on one thread and without tablebases,
+        // whatever the arguments say.
+        pub fn start(
+            &mut self,
+            chess: &Chess,
+            history: Vec<u64>,
+            depth: u8,
+            _threads: usize,
+            _tablebase: Option<Arc<Tablebase>>,
+        ) {
+            self.cancel();
+            let request = json!({
+                "fen": chess.to_fen(),
+                "history": encode_history(&history),
+                "depth": depth,
+            });
+            self.search = Some((chess.clone(), history, depth));
+            if !self.unavailable.get() {
+                self.worker = RunningWorker::spawn(&request, &self.unavailable);
+            }
+        }
+
+        pub fn cancel(&mut self) {
+            if let Some(running) = self.worker.take() {
+                running.worker.terminate();
+            }
+            self.search = None;
+        }
+
+        // Each search starts in a fresh worker, so there is no table to clear
+        pub fn clear_table(&mut self) {}
+
+        // Progress reported since the last call, with the hash of the position
+        pub fn progress(&mut self) -> Vec<(u64, SearchProgress)> {
+            let (Some((chess, ..)), Some(running)) = (&self.search, &self.worker) else {
+                return Vec::new();
+            };
+            let mut messages = running.messages.borrow_mut();
+            let mut progress = Vec::new();
+            // The result stays queued for `poll`
+            while let Some(WorkerMessage::Progress { .. }) = messages.front() {
+                if let Some(WorkerMessage::Progress {
+                    depth,
+                    nodes,
+                    best,
+                    score,
+                }) = messages.pop_front()
+                {
+                    progress.push((
+                        chess.hash(),
+                        SearchProgress {
+                            depth,
+                            nodes,
+                            best: best.and_then(|uci| chess.parse_uci(&uci)),
+                            score,
+                        },
+                    ));
+                }
+            }
+            progress
+        }
+
+        pub fn poll(&mut self) -> SearchStatus {
+            let Some((chess, history, depth)) = &self.search else {
+                return SearchStatus::Idle;
+            };
+            let hash = chess.hash();
+            let best = if self.unavailable.get() {
+                let stop = AtomicBool::new(false);
+                Some(
+                    Search::new(&stop, &mut |_| {})
+                        .with_history(history.clone())
+                        .run(chess, *depth),
+                )
+            } else {
+                let done = self.worker.as_ref().and_then(|running| {
+                    let mut messages = running.messages.borrow_mut();
+                    messages.retain(|message| matches!(message, WorkerMessage::Done { .. }));
+                    messages.pop_front()
+                });
+                match done {
+                    Some(WorkerMessage::Done { best }) => {
+                        Some(best.and_then(|uci| chess.parse_uci(&uci)))
+                    }
+                    _ => None,
+                }
+            };
+            match best {
+                Some(best) => {
+                    self.cancel();
+                    SearchStatus::Finished { hash, best }
+                }
+                None => SearchStatus::Searching,
+            }
+        }
+    }
+
+    #[derive(Default)]
+    pub struct AnalysisWorker {
+        worker: Option<RunningWorker>,
+        // The position being analysed
+        chess: Option<Chess>,
+        unavailable: Rc<Cell<bool>>,
+    }
+
+    impl AnalysisWorker {
+        // Analyses the position in a fresh worker for its best `lines` moves,
+        // stopping any analysis still running. There is no analysis when the
+        // worker can't be loaded, as it would hold up the page.
+        pub fn start(
+            &mut self,
+            chess: &Chess,
+            history: Vec<u64>,
+            lines: usize,
+            _tablebase: Option<Arc<Tablebase>>,
+        ) {
+            self.cancel();
+            if self.unavailable.get() {
+                return;
+            }
+            let request = json!({
+                "fen": chess.to_fen(),
+                "history": encode_history(&history),
+                "lines": lines,
+            });
+            self.worker = RunningWorker::spawn(&request, &self.unavailable);
+            self.chess = Some(chess.clone());
+        }
+
+        pub fn cancel(&mut self) {
+            if let Some(running) = self.worker.take() {
+                running.worker.terminate();
+            }
+            self.chess = None;
+        }
+
+        // Analyses reported since the last call, with the hash of the position
+        pub fn progress(&mut self) -> Vec<(u64, Analysis)> {
+            let (Some(chess), Some(running)) = (&self.chess, &self.worker) else {
+                return Vec::new();
+            };
+            let mut analyses = Vec::new();
+            for message in running.messages.borrow_mut().drain(..) {
+                let WorkerMessage::Analysis {
+                    depth,
+                    nodes,
+                    lines,
+                } = message
+                else {
+                    continue;
+                };
+                let lines = lines
+                    .into_iter()
+                    .map(|(moves, score)| {
+                        // Each move is read in the position the ones before lead to
+                        let mut after = chess.clone();
+                        let moves = moves
+                            .iter()
+                            .map_while(|uci| {
+                                let mv = after.parse_uci(uci)?;
+                                after.play(&mv);
+                                Some(mv)
+                            })
+                            .collect();
+                        Line { moves, score }
+                    })
+                    .collect();
+                analyses.push((
+                    chess.hash(),
+                    Analysis {
+                        depth,
+                        nodes,
+                        lines,
+                    },
+                ));
+            }
+            analyses
+        }
+    }
+
+    #[derive(Default)]
+    pub struct ReviewWorker {
+        worker: Option<RunningWorker>,
+        // The positions before each move of the game being reviewed
+        positions: Vec<Chess>,
+        unavailable: Rc<Cell<bool>>,
+    }
+
+    impl ReviewWorker {
+        // Reviews a game in a fresh worker from the positions before each move
+        // and the moves played, searching to `depth`, and stops any review
+        // still running. There is no review when the worker can't be loaded.
+        pub fn start(&mut self, positions: Vec<Chess>, moves: Vec<Move>, depth: u8) {
+            self.cancel();
+            let Some(start) = positions.first() else {
+                return;
+            };
+            if self.unavailable.get() {
+                return;
+            }
+            let moves = moves
+                .iter()
+                .map(|mv| mv.to_uci())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let request = json!({
+                "fen": start.to_fen(),
+                "moves": moves,
+                "depth": depth,
+                "review": true,
+            });
+            self.worker = RunningWorker::spawn(&request, &self.unavailable);
+            self.positions = positions;
+        }
+
+        pub fn cancel(&mut self) {
+            if let Some(running) = self.worker.take() {
+                running.worker.terminate();
+            }
+            self.positions.clear();
+        }
+
+        // Moves reviewed since the last call, with their ply
+        pub fn progress(&mut self) -> Vec<(usize, MoveReview)> {
+            let Some(running) = &self.worker else {
+                return Vec::new();
+            };
+            let mut reviews = Vec::new();
+            for message in running.messages.borrow_mut().drain(..) {
+                let WorkerMessage::Reviewed {
+                    ply,
+                    best,
+                    best_score,
+                    score,
+                } = message
+                else {
+                    continue;
+                };
+                let Some(chess) = self.positions.get(ply) else {
+                    continue;
+                };
+                if let Some(best) = chess.parse_uci(&best) {
+                    reviews.push((
+                        ply,
+                        MoveReview {
+                            white: chess.white_turn,
+                            best,
+                            best_score,
+                            score,
+                        },
+                    ));
+                }
+            }
+            reviews
+        }
+    }
+}
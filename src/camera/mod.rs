@@ -1,4 +1,3 @@
-
 mod system;
 use bevy::prelude::*;
 
@@ -6,11 +5,8 @@ use system::spawn_camera;
 
 pub struct CameraPlugin;
 
-
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, spawn_camera);
     }
 }
-
-
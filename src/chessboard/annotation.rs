@@ -0,0 +1,179 @@
+use super::component::*;
+use super::constants::*;
+use super::utils::*;
+use crate::engine::chess::*;
+use bevy::prelude::*;
+use bevy::sprite::*;
+use bevy::window::*;
+
+// Right-click toggles a square highlight, right-click dragging toggles an
+// arrow. Shift draws in red, Alt in blue and Ctrl in yellow. A left-click
+// clears the board.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_annotation_input(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+    q_board: Query<&Board>,
+    history: Res<MoveHistory>,
+    mut annotations: ResMut<Annotations>,
+) {
+    if mouse.just_pressed(MouseButton::Left) && !annotations.shapes.is_empty() {
+        annotations.shapes.clear();
+    }
+    let pressed = mouse.just_pressed(MouseButton::Right);
+    let released = mouse.just_released(MouseButton::Right);
+    if !pressed && !released {
+        return;
+    }
+    let (camera, camera_transform) = q_camera.single();
+    let board = q_board.single();
+    let square = q_window
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
+        .map(|ray| ray.origin.truncate())
+        .and_then(|world| world_position_to_chess_position((world.x, world.y), board.flipped));
+    if pressed {
+        annotations.drag_start = square;
+        return;
+    }
+    let (Some(from), Some(to)) = (annotations.drag_start.take(), square) else {
+        return;
+    };
+    let color = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        AnnotationColor::Red
+    } else if keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) {
+        AnnotationColor::Blue
+    } else if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        AnnotationColor::Yellow
+    } else {
+        AnnotationColor::Green
+    };
+    let shape = if from == to {
+        Annotation::Highlight {
+            square: Chess::position_to_index(&to),
+            color,
+        }
+    } else {
+        Annotation::Arrow {
+            from: Chess::position_to_index(&from),
+            to: Chess::position_to_index(&to),
+            color,
+        }
+    };
+    annotations.toggle(shape, history.moves.len());
+}
+
+// Moves clear the board, taking moves back forgets what was drawn on the
+// positions after, and flipping redraws the shapes from the other side.
+pub fn update_annotations(
+    mut ev_command: EventReader<GameCommand>,
+    mut ev_game: EventReader<GameEvent>,
+    history: Res<MoveHistory>,
+    mut annotations: ResMut<Annotations>,
+) {
+    for ev in ev_command.read() {
+        match ev {
+            GameCommand::NewGame | GameCommand::LoadGame { .. } => {
+                *annotations = Annotations::default()
+            }
+            GameCommand::Undo => {
+                let ply = history.moves.len();
+                annotations.shapes.clear();
+                annotations.by_ply.retain(|shape_ply, _| *shape_ply <= ply);
+            }
+            GameCommand::FlipBoard => annotations.set_changed(),
+            _ => {}
+        }
+    }
+    for ev in ev_game.read() {
+        if matches!(ev, GameEvent::Moved(_)) && !annotations.shapes.is_empty() {
+            annotations.shapes.clear();
+        }
+    }
+}
+
+fn annotation_color(color: AnnotationColor) -> Srgba {
+    match color {
+        AnnotationColor::Green => ANNOTATION_GREEN,
+        AnnotationColor::Red => ANNOTATION_RED,
+        AnnotationColor::Blue => ANNOTATION_BLUE,
+        AnnotationColor::Yellow => ANNOTATION_YELLOW,
+    }
+}
+
+pub fn render_annotations(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    annotations: Res<Annotations>,
+    q_board: Query<&Board>,
+    q_shapes: Query<Entity, With<AnnotationShape>>,
+) {
+    for entity in q_shapes.iter() {
+        commands.entity(entity).despawn();
+    }
+    let flipped = q_board.single().flipped;
+    let world = |idx: u8| {
+        let (x_t, y_t) = chess_position_to_world_position(Chess::index_to_position(idx), flipped);
+        Vec2::new(x_t, y_t)
+    };
+    for shape in annotations.shapes.iter() {
+        match *shape {
+            Annotation::Highlight { square, color } => {
+                let center = world(square);
+                commands.spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: annotation_color(color).into(),
+                            custom_size: Some(Vec2::splat(SQUARE_SIZE)),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(center.x, center.y, 4.),
+                        ..default()
+                    },
+                    AnnotationShape,
+                ));
+            }
+            Annotation::Arrow { from, to, color } => {
+                let (start, end) = (world(from), world(to));
+                let direction = (end - start).normalize();
+                let angle = direction.y.atan2(direction.x);
+                let head_length = SQUARE_SIZE * 0.4;
+                let shaft_end = end - direction * head_length;
+                let shaft_length = (shaft_end - start).length();
+                let material = materials.add(ColorMaterial::from_color(annotation_color(color)));
+                let shaft_center = (start + shaft_end) / 2.;
+                commands.spawn((
+                    MaterialMesh2dBundle {
+                        mesh: Mesh2dHandle(
+                            meshes.add(Rectangle::new(shaft_length, SQUARE_SIZE * 0.18)),
+                        ),
+                        material: material.clone(),
+                        transform: Transform::from_xyz(shaft_center.x, shaft_center.y, 20.)
+                            .with_rotation(Quat::from_rotation_z(angle)),
+                        ..default()
+                    },
+                    AnnotationShape,
+                ));
+                commands.spawn((
+                    MaterialMesh2dBundle {
+                        mesh: Mesh2dHandle(meshes.add(Triangle2d::new(
+                            Vec2::new(head_length, 0.),
+                            Vec2::new(0., head_length * 0.7),
+                            Vec2::new(0., -head_length * 0.7),
+                        ))),
+                        material,
+                        transform: Transform::from_xyz(shaft_end.x, shaft_end.y, 20.)
+                            .with_rotation(Quat::from_rotation_z(angle)),
+                        ..default()
+                    },
+                    AnnotationShape,
+                ));
+            }
+        }
+    }
+}
@@ -1,11 +1,195 @@
+use super::constants::ANIMATION_SPEEDS;
 use crate::engine::chess::*;
+use crate::engine::notation::square_name;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Event, Debug)]
 pub enum BoardEvent {
     SelectPiece(Position),
     DeselectAll,
     MovePiece(Position, Position),
+    // Moves a pawn to the last rank, promoting it to the given piece index
+    PromotePiece(Position, Position, u8),
+    // Highlights the piece a hint moves and, once asked for again, where to
+    ShowHint(Position, Option<Position>),
+}
+
+#[derive(Event, Debug)]
+pub enum GameCommand {
+    NewGame,
+    // Takes back moves until it is the human's turn again
+    Undo,
+    Resign { is_white: bool },
+    // Proposes a draw to the opponent, who answers with `AgreeDraw` or a
+    // `GameEvent::DrawDeclined`
+    OfferDraw { is_white: bool },
+    AgreeDraw,
+    // Sets up the position from FEN and replays the UCI moves from it
+    LoadGame { fen: String, moves: Vec<String> },
+    // Picks up the game saved when the app was last closed
+    ResumeGame,
+    FlipBoard,
+    ExportPgn,
+    // Asks the bot for a move for the human to play, shown a square at a time
+    Hint,
+}
+
+// Things that happened in the game, as reported by the engine and the clock
+#[derive(Event, Debug)]
+pub enum GameEvent {
+    Moved(MoveOutcome),
+    IllegalMove,
+    LowTime { is_white: bool },
+    // The side to move ran out of time
+    TimeOut,
+    Resigned,
+    DrawAgreed,
+    DrawDeclined,
+}
+
+// Lifecycle of the app: the start menu, a game in progress, a finished game
+// and its review, free analysis, where both sides are moved by hand and there
+// is no clock, setting up a position to play or analyse, solving puzzles and
+// browsing a database of games
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GameState {
+    #[default]
+    Menu,
+    Playing,
+    GameOver,
+    Review,
+    Analysis,
+    Setup,
+    Puzzle,
+    Database,
+}
+
+// Positions before each move, with the moves played from them
+#[derive(Resource, Debug, Default)]
+pub struct MoveHistory {
+    pub positions: Vec<Chess>,
+    pub moves: Vec<Move>,
+    pub san: Vec<String>,
+    // Positions White and Black asked for a hint in
+    pub hints: [u32; 2],
+}
+
+// The move being typed from the keyboard
+#[derive(Resource, Debug, Default)]
+pub struct MoveInput {
+    pub text: String,
+    pub error: Option<String>,
+}
+
+#[derive(Component, Debug)]
+pub struct MoveInputText;
+
+// Colors of the right-click annotations, chosen with the modifier keys
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnnotationColor {
+    Green,
+    Red,
+    Blue,
+    Yellow,
+}
+
+impl AnnotationColor {
+    // Letter used for the color in `[%csl]` and `[%cal]` PGN comments
+    pub fn code(self) -> char {
+        match self {
+            AnnotationColor::Green => 'G',
+            AnnotationColor::Red => 'R',
+            AnnotationColor::Blue => 'B',
+            AnnotationColor::Yellow => 'Y',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Annotation {
+    Highlight {
+        square: u8,
+        color: AnnotationColor,
+    },
+    Arrow {
+        from: u8,
+        to: u8,
+        color: AnnotationColor,
+    },
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct Annotations {
+    // Shapes currently drawn on the board
+    pub shapes: Vec<Annotation>,
+    // The last shapes drawn on each position, keyed by the number of moves
+    // played, which are kept for the PGN after the board is cleared
+    pub by_ply: BTreeMap<usize, Vec<Annotation>>,
+    pub drag_start: Option<Position>,
+}
+
+impl Annotations {
+    // Adds the shape, or removes it when it is already drawn in that color
+    pub fn toggle(&mut self, shape: Annotation, ply: usize) {
+        let same_place = |other: &Annotation| match (other, &shape) {
+            (Annotation::Highlight { square: a, .. }, Annotation::Highlight { square: b, .. }) => {
+                a == b
+            }
+            (
+                Annotation::Arrow { from: a, to: b, .. },
+                Annotation::Arrow { from: c, to: d, .. },
+            ) => a == c && b == d,
+            _ => false,
+        };
+        let existing = self.shapes.iter().position(same_place);
+        if let Some(idx) = existing {
+            if self.shapes.remove(idx) == shape {
+                self.by_ply.insert(ply, self.shapes.clone());
+                return;
+            }
+        }
+        self.shapes.push(shape);
+        self.by_ply.insert(ply, self.shapes.clone());
+    }
+
+    // The shapes drawn on a position as `[%csl]` and `[%cal]` commands
+    pub fn pgn_comment(&self, ply: usize) -> Option<String> {
+        let shapes = self.by_ply.get(&ply)?;
+        let mut squares = Vec::new();
+        let mut arrows = Vec::new();
+        for shape in shapes {
+            match shape {
+                Annotation::Highlight { square, color } => {
+                    squares.push(format!("{}{}", color.code(), square_name(*square)))
+                }
+                Annotation::Arrow { from, to, color } => arrows.push(format!(
+                    "{}{}{}",
+                    color.code(),
+                    square_name(*from),
+                    square_name(*to)
+                )),
+            }
+        }
+        let mut comment = String::new();
+        if !squares.is_empty() {
+            comment.push_str(&format!("[%csl {}]", squares.join(",")));
+        }
+        if !arrows.is_empty() {
+            comment.push_str(&format!("[%cal {}]", arrows.join(",")));
+        }
+        (!comment.is_empty()).then_some(comment)
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct AnnotationShape;
+
+// Square highlighted by the arrow keys, hidden until they are first used
+#[derive(Component, Debug)]
+pub struct KeyboardCursor {
+    pub position: Position,
 }
 
 #[derive(Component, Debug)]
@@ -17,6 +201,25 @@ pub struct Overlay;
 #[derive(Component, Debug)]
 pub struct LastMoveOverlay;
 
+#[derive(Component, Debug)]
+pub struct CheckOverlay;
+
+// Whether the pieces giving check are highlighted along with the checked king
+#[derive(Resource, Debug)]
+pub struct CheckDisplay {
+    pub highlight_checkers: bool,
+}
+
+impl Default for CheckDisplay {
+    fn default() -> Self {
+        Self {
+            highlight_checkers: true,
+        }
+    }
+}
+
+pub type AnyOverlay = Or<(With<Overlay>, With<LastMoveOverlay>)>;
+
 #[derive(Component, Debug)]
 pub struct Piece {
     pub is_white: bool,
@@ -30,6 +233,13 @@ pub struct Board {
     pub selected_piece: Option<Piece>,
     pub white_out_count: u8,
     pub black_out_count: u8,
+    pub game_over: bool,
+    // PGN result of the finished game, `1-0`, `0-1` or `1/2-1/2`
+    pub result: Option<String>,
+    // How the game ended, e.g. `Checkmate` or `Draw agreed`
+    pub reason: Option<String>,
+    // Whether the board is seen from black's side
+    pub flipped: bool,
 }
 impl Board {
     pub fn update_piece(&mut self, piece: Piece) {
@@ -41,6 +251,157 @@ impl Board {
     pub fn update_turn(&mut self, white_turn: bool) {
         self.white_turn = white_turn;
     }
+    pub fn end_game(&mut self, result: &str, reason: &str) {
+        self.game_over = true;
+        self.result = Some(result.to_string());
+        self.reason = Some(reason.to_string());
+    }
+    pub fn resume_game(&mut self) {
+        self.game_over = false;
+        self.result = None;
+        self.reason = None;
+    }
+}
+
+// Interpolates a piece's transform between two states. Removed once the
+// animation completes, so its presence means an animation is in flight.
+#[derive(Component, Debug)]
+pub struct Tween {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub start_scale: Vec3,
+    pub end_scale: Vec3,
+    // Fades the sprite out and back in while travelling, used for captures
+    pub fade: bool,
+    pub timer: Timer,
+}
+
+#[derive(Resource, Debug, Serialize, Deserialize)]
+pub struct AnimationSettings {
+    // Multiplier applied to the base animation durations; 0 disables animations
+    pub speed: f32,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        AnimationSettings { speed: 1. }
+    }
+}
+
+impl AnimationSettings {
+    // The next of `ANIMATION_SPEEDS` after the current speed
+    pub fn next_speed(&self) -> f32 {
+        let current = ANIMATION_SPEEDS
+            .iter()
+            .position(|speed| *speed == self.speed);
+        ANIMATION_SPEEDS[current.map_or(0, |idx| (idx + 1) % ANIMATION_SPEEDS.len())]
+    }
+
+    pub fn duration(&self, base_secs: f32) -> std::time::Duration {
+        if self.speed <= 0. {
+            return std::time::Duration::ZERO;
+        }
+        std::time::Duration::from_secs_f32(base_secs / self.speed)
+    }
+}
+
+// Remaining time in seconds for white and black. The clock is what the low
+// time warning sound counts down against; a side whose time runs out loses.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct Clock {
+    pub remaining: [f32; 2],
+    pub increment: f32,
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Clock {
+            remaining: [600.; 2],
+            increment: 0.,
+        }
+    }
+}
+
+impl Clock {
+    pub fn side(is_white: bool) -> usize {
+        if is_white {
+            0
+        } else {
+            1
+        }
+    }
+
+    pub fn add_increment(&mut self, is_white: bool) {
+        self.remaining[Clock::side(is_white)] += self.increment;
+    }
+
+    pub fn format(secs: f32) -> String {
+        let secs = secs.ceil() as u32;
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlayerKind {
+    Human,
+    Bot,
+    // Plays from another instance, over the network
+    Remote,
+}
+
+impl PlayerKind {
+    // The player's name in exported games
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlayerKind::Human => "Human",
+            PlayerKind::Bot => "Bot",
+            PlayerKind::Remote => "Remote player",
+        }
+    }
+}
+
+// Who plays each side
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct Players {
+    pub white: PlayerKind,
+    pub black: PlayerKind,
+}
+
+impl Default for Players {
+    fn default() -> Self {
+        Self {
+            white: PlayerKind::Human,
+            black: PlayerKind::Bot,
+        }
+    }
+}
+
+impl Players {
+    pub fn kind(&self, is_white: bool) -> PlayerKind {
+        if is_white {
+            self.white
+        } else {
+            self.black
+        }
+    }
+
+    pub fn is_human(&self, is_white: bool) -> bool {
+        self.kind(is_white) == PlayerKind::Human
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.white == PlayerKind::Remote || self.black == PlayerKind::Remote
+    }
+
+    // Watching an online game, with no side played here
+    pub fn is_spectating(&self) -> bool {
+        self.white == PlayerKind::Remote && self.black == PlayerKind::Remote
+    }
+
+    // The side the local player resigns or offers draws for
+    pub fn local_is_white(&self) -> bool {
+        self.white == PlayerKind::Human
+    }
 }
 
 #[derive(Component, Debug)]
@@ -1,13 +1,21 @@
 use bevy::color::*;
 
-pub const BLACK: Srgba = Srgba::rgb(0.7176470588235294, 0.7529411764705882, 0.8470588235294118);
-pub const WHITE: Srgba = Srgba::rgb(0.9098039215686274, 0.9294117647058824, 0.9764705882352941);
-pub const BLUE: Srgba = Srgba::new(0.4823529411764706, 0.3803921568627451, 1., 0.8);
+pub const BLACK: Srgba = Srgba::rgb(0.717_647_1, 0.752_941_2, 0.847_058_83);
+pub const WHITE: Srgba = Srgba::rgb(0.909_803_9, 0.929_411_77, 0.976_470_6);
+pub const BLUE: Srgba = Srgba::new(0.482_352_94, 0.380_392_16, 1., 0.8);
 
 pub const DARK_BLACK: Srgba = Srgba::rgb(0., 0., 0.);
 
 pub const GRAY: Srgba = Srgba::new(0.62, 0.62, 0.62, 1.);
 pub const GREEN: Srgba = Srgba::new(0.376, 0.922, 0.212, 1.);
+pub const CURSOR_YELLOW: Srgba = Srgba::new(0.98, 0.85, 0.2, 0.5);
+pub const ANNOTATION_GREEN: Srgba = Srgba::new(0.08, 0.47, 0.11, 0.7);
+pub const ANNOTATION_RED: Srgba = Srgba::new(0.53, 0.13, 0.13, 0.7);
+pub const ANNOTATION_BLUE: Srgba = Srgba::new(0., 0.19, 0.53, 0.7);
+pub const ANNOTATION_YELLOW: Srgba = Srgba::new(0.9, 0.63, 0.06, 0.7);
+pub const CHECK_RED: Srgba = Srgba::new(0.9, 0.1, 0.1, 0.75);
+pub const CHECKER_RED: Srgba = Srgba::new(0.9, 0.3, 0.2, 0.4);
+pub const HINT_GREEN: Srgba = Srgba::new(0.2, 0.75, 0.3, 0.6);
 pub const TRANSPARENT_PURPLE: Srgba = Srgba::new(0.524, 0., 0.524, 0.7);
 
 pub const SQUARE_SIZE: f32 = 64.;
@@ -15,6 +23,19 @@ pub const SQUARE_SIZE: f32 = 64.;
 pub const LEFT: f32 = -SQUARE_SIZE * 4. - SQUARE_SIZE / 2.;
 pub const BOTTOM: f32 = -SQUARE_SIZE * 4. - SQUARE_SIZE / 2.;
 
+pub const PIECE_Z: f32 = 10.;
+pub const MOVING_PIECE_Z: f32 = 15.;
+pub const MOVE_ANIMATION_SECS: f32 = 0.25;
+pub const CAPTURE_ANIMATION_SECS: f32 = 0.4;
+// Animation speeds `s` steps through, 0 turning animations off
+pub const ANIMATION_SPEEDS: [f32; 4] = [0.5, 1., 2., 0.];
+pub const ANIMATION_SETTINGS_KEY: &str = "animation";
+
+pub const LOW_TIME_SECS: f32 = 30.;
+
 pub const PIECES_CODE: [&str; 12] = [
     "wp", "wr", "wn", "wb", "wq", "wk", "bp", "br", "bn", "bb", "bq", "bk",
 ];
+
+// Number of pawns, rooks, knights, bishops, queens and kings each side starts with
+pub const STARTING_COUNTS: [u8; 6] = [8, 2, 2, 2, 1, 1];
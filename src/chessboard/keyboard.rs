@@ -0,0 +1,198 @@
+use super::component::*;
+use super::constants::*;
+use super::system::touch_square;
+use super::utils::*;
+use crate::engine::chess::*;
+use crate::storage;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+
+// Characters which can appear in a move typed in SAN or UCI
+const MOVE_CHARS: &str = "abcdefgh12345678NBRQKOo0x+#=-";
+
+pub fn spawn_keyboard_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font: Handle<Font> = asset_server.load("fonts/Gantari.ttf");
+    commands.spawn((SpriteBundle {
+        sprite: Sprite {
+            color: BLUE.with_alpha(0.4).into(),
+            custom_size: Some(Vec2::new(5. * SQUARE_SIZE, SQUARE_SIZE * 0.75)),
+            ..default()
+        },
+        transform: Transform::from_xyz(-7. * SQUARE_SIZE, -3. * SQUARE_SIZE, 1.),
+        ..default()
+    },));
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font,
+                    color: WHITE.into(),
+                    font_size: 22.,
+                },
+            )
+            .with_justify(JustifyText::Center),
+            transform: Transform::from_xyz(-7. * SQUARE_SIZE, -3. * SQUARE_SIZE, 30.),
+            ..default()
+        },
+        MoveInputText,
+    ));
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: CURSOR_YELLOW.into(),
+                custom_size: Some(Vec2::splat(SQUARE_SIZE)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0., 0., 6.),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        KeyboardCursor {
+            position: Position(5, 2),
+        },
+    ));
+}
+
+// Typing enters a move in the move box, arrow keys and Enter drive a cursor
+// over the board, and when the move box is empty u, v, n, r and p undo, flip
+// the board, start a new game, resign and export the game as PGN, k turns
+// the highlighting of the pieces giving check on and off and s changes the
+// animation speed.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_keyboard(
+    mut ev_keys: EventReader<KeyboardInput>,
+    mut move_input: ResMut<MoveInput>,
+    mut q_cursor: Query<(&mut KeyboardCursor, &mut Transform, &mut Visibility)>,
+    q_board: Query<&Board>,
+    q_chess: Query<&Chess>,
+    q_piece: Query<&Piece>,
+    q_tween: Query<(), With<Tween>>,
+    mut ev_board: EventWriter<BoardEvent>,
+    mut ev_command: EventWriter<GameCommand>,
+    mut ev_game: EventWriter<GameEvent>,
+    players: Res<Players>,
+    mut check_display: ResMut<CheckDisplay>,
+    mut animation: ResMut<AnimationSettings>,
+) {
+    let board = q_board.single();
+    let chess = q_chess.single();
+    let (mut cursor, mut cursor_transform, mut cursor_visibility) = q_cursor.single_mut();
+    let animating = !q_tween.is_empty();
+    for ev in ev_keys.read() {
+        if ev.state != ButtonState::Pressed {
+            continue;
+        }
+        // Arrow keys follow the screen, which is reversed when flipped
+        let sign: i8 = if board.flipped { -1 } else { 1 };
+        let step = match ev.key_code {
+            KeyCode::ArrowUp => Some((0, sign)),
+            KeyCode::ArrowDown => Some((0, -sign)),
+            KeyCode::ArrowRight => Some((sign, 0)),
+            KeyCode::ArrowLeft => Some((-sign, 0)),
+            _ => None,
+        };
+        if let Some((dx, dy)) = step {
+            if *cursor_visibility == Visibility::Hidden {
+                *cursor_visibility = Visibility::Visible;
+            } else {
+                cursor.position = Position(
+                    (cursor.position.0 as i8 + dx).clamp(1, 8) as u8,
+                    (cursor.position.1 as i8 + dy).clamp(1, 8) as u8,
+                );
+            }
+            continue;
+        }
+        match &ev.logical_key {
+            Key::Enter if !animating && players.is_human(chess.white_turn) => {
+                move_input.error = None;
+                if move_input.text.is_empty() {
+                    if *cursor_visibility == Visibility::Visible {
+                        touch_square(
+                            cursor.position.clone(),
+                            board,
+                            chess,
+                            &q_piece,
+                            &mut ev_board,
+                        );
+                    }
+                } else if board.game_over {
+                    move_input.text.clear();
+                } else if let Some(mv) = chess.parse_move(&move_input.text) {
+                    let from = Chess::index_to_position(mv.from);
+                    let to = Chess::index_to_position(mv.to);
+                    ev_board.send(match mv.promotion {
+                        Some(piece) => BoardEvent::PromotePiece(from, to, piece),
+                        None => BoardEvent::MovePiece(from, to),
+                    });
+                    move_input.text.clear();
+                } else {
+                    move_input.error = Some(format!("{} is not a legal move", move_input.text));
+                    move_input.text.clear();
+                    ev_game.send(GameEvent::IllegalMove);
+                }
+            }
+            Key::Backspace => {
+                move_input.text.pop();
+            }
+            Key::Escape => {
+                move_input.text.clear();
+                move_input.error = None;
+                ev_board.send(BoardEvent::DeselectAll);
+            }
+            Key::Character(text) => {
+                move_input.error = None;
+                if move_input.text.is_empty() && text.as_str() == "k" {
+                    check_display.highlight_checkers = !check_display.highlight_checkers;
+                    continue;
+                }
+                if move_input.text.is_empty() && text.as_str() == "s" {
+                    animation.speed = animation.next_speed();
+                    storage::save_json(ANIMATION_SETTINGS_KEY, &*animation);
+                    continue;
+                }
+                if move_input.text.is_empty() {
+                    let command = match text.as_str() {
+                        "u" => Some(GameCommand::Undo),
+                        "v" => Some(GameCommand::FlipBoard),
+                        "n" => Some(GameCommand::NewGame),
+                        "r" if !players.is_spectating() => Some(GameCommand::Resign {
+                            is_white: players.local_is_white(),
+                        }),
+                        "p" => Some(GameCommand::ExportPgn),
+                        _ => None,
+                    };
+                    if let Some(command) = command {
+                        if !animating {
+                            ev_command.send(command);
+                        }
+                        continue;
+                    }
+                }
+                for c in text.chars().filter(|c| MOVE_CHARS.contains(*c)) {
+                    move_input.text.push(c);
+                }
+            }
+            _ => {}
+        }
+    }
+    let (x_t, y_t) = chess_position_to_world_position(cursor.position.clone(), board.flipped);
+    cursor_transform.translation.x = x_t;
+    cursor_transform.translation.y = y_t;
+}
+
+pub fn update_move_input_text(
+    move_input: Res<MoveInput>,
+    mut q_text: Query<&mut Text, With<MoveInputText>>,
+) {
+    for mut text in q_text.iter_mut() {
+        text.sections[0].value = if let Some(error) = &move_input.error {
+            error.clone()
+        } else if move_input.text.is_empty() {
+            "Type a move (Nf3, e2e4)".to_string()
+        } else {
+            format!("{}_", move_input.text)
+        };
+    }
+}
@@ -1,13 +1,19 @@
+pub mod annotation;
 pub mod component;
-mod constants;
+pub mod constants;
+mod keyboard;
 pub mod system;
-mod utils;
+pub mod utils;
 
 use bevy::input::common_conditions::*;
 use bevy::prelude::*;
 
 use crate::engine::system::spawn_engine;
+use crate::storage;
+use annotation::*;
 use component::*;
+use constants::ANIMATION_SETTINGS_KEY;
+use keyboard::*;
 use system::*;
 
 pub struct ChessBoardPlugin;
@@ -15,14 +21,72 @@ pub struct ChessBoardPlugin;
 impl Plugin for ChessBoardPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(PreStartup, spawn_engine)
-            .add_systems(Startup, (spawn_board, spawn_pieces, spawn_texts))
+            .add_systems(
+                Startup,
+                (spawn_board, spawn_pieces, spawn_texts, spawn_keyboard_ui),
+            )
+            .init_state::<GameState>()
+            .enable_state_scoped_entities::<GameState>()
             .add_event::<BoardEvent>()
+            .add_event::<GameEvent>()
+            .add_event::<GameCommand>()
+            .insert_resource(storage::load_json::<AnimationSettings>(
+                ANIMATION_SETTINGS_KEY,
+            ))
+            .init_resource::<Clock>()
+            .init_resource::<Players>()
+            .init_resource::<MoveHistory>()
+            .init_resource::<MoveInput>()
+            .init_resource::<Annotations>()
+            .init_resource::<CheckDisplay>()
             .add_systems(
                 Update,
                 (
-                    handle_input.run_if(input_just_pressed(MouseButton::Left)),
+                    // The review, the position editor and the game database have
+                    // their own controls
+                    handle_input.run_if(
+                        has_board_controls
+                            .and_then(input_just_pressed(MouseButton::Left))
+                            .and_then(animations_idle),
+                    ),
+                    handle_keyboard
+                        .before(handle_game_command)
+                        .run_if(has_board_controls),
+                    handle_game_command.before(handle_board_event),
+                    update_move_input_text
+                        .after(handle_keyboard)
+                        .run_if(resource_changed::<MoveInput>),
                     handle_board_event,
+                    animate_pieces.after(handle_board_event),
+                    tick_clock.after(handle_board_event).run_if(not(
+                        in_state(GameState::Analysis).or_else(in_state(GameState::Puzzle))
+                    )),
+                    export_pgn.after(handle_board_event),
+                    update_check_overlay
+                        .after(handle_board_event)
+                        .after(handle_game_command)
+                        .run_if(check_state_changed.or_else(resource_changed::<CheckDisplay>)),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    handle_annotation_input,
+                    update_annotations
+                        .after(handle_annotation_input)
+                        .after(handle_board_event)
+                        .after(handle_game_command),
+                    render_annotations
+                        .after(update_annotations)
+                        .run_if(resource_changed::<Annotations>),
                 ),
             );
     }
 }
+
+fn has_board_controls(state: Res<State<GameState>>) -> bool {
+    !matches!(
+        state.get(),
+        GameState::Menu | GameState::Review | GameState::Setup | GameState::Database
+    )
+}
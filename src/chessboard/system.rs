@@ -2,6 +2,8 @@ use super::component::*;
 use super::constants::*;
 use super::utils::*;
 use crate::engine::chess::*;
+use crate::engine::pgn::PgnGame;
+use crate::storage;
 use bevy::prelude::*;
 use bevy::sprite::*;
 use bevy::window::*;
@@ -24,10 +26,14 @@ pub fn spawn_board(mut commands: Commands) {
         selected_piece: None,
         white_out_count: 0,
         black_out_count: 0,
+        game_over: false,
+        result: None,
+        reason: None,
+        flipped: false,
     });
 
     for i in 0..2 {
-        let is_white = if i == 0 { true } else { false };
+        let is_white = i == 0;
         let color = if is_white { GREEN.into() } else { GRAY.into() };
         let player_sprite = Sprite {
             color,
@@ -78,19 +84,16 @@ pub fn spawn_texts(mut commands: Commands, asset_server: ResMut<AssetServer>) {
         font: font.clone(),
         color: DARK_BLACK.into(),
         font_size: 90.0,
-        ..default()
     };
     let msg_style = TextStyle {
         font: d_font.clone(),
         color: DARK_BLACK.into(),
         font_size: 40.,
-        ..default()
     };
     let status_style = TextStyle {
         font: d_font.clone(),
         color: WHITE.into(),
         font_size: 32.,
-        ..default()
     };
     let text_justification = JustifyText::Center;
 
@@ -141,8 +144,20 @@ pub fn spawn_texts(mut commands: Commands, asset_server: ResMut<AssetServer>) {
         },
         TextInfo { text_type: 4 },
     ));
+    // Clocks, white's below black's on the right of the board
+    for (text_type, y_t) in [(5, -3.5 * SQUARE_SIZE), (6, 3.5 * SQUARE_SIZE)] {
+        commands.spawn((
+            Text2dBundle {
+                text: Text::from_section("", status_style.clone()).with_justify(text_justification),
+                transform: Transform::from_xyz(5.5 * SQUARE_SIZE, y_t, 30.),
+                ..default()
+            },
+            TextInfo { text_type },
+        ));
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_board_event(
     mut commands: Commands,
     mut ev_board: EventReader<BoardEvent>,
@@ -150,7 +165,12 @@ pub fn handle_board_event(
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut q_chess: Query<&mut Chess>,
     mut q_board: Query<&mut Board>,
-    mut q_piece: Query<(&mut Piece, &mut Transform), Without<TextInfo>>,
+    mut ev_game: EventWriter<GameEvent>,
+    mut clock: ResMut<Clock>,
+    mut history: ResMut<MoveHistory>,
+    animation: Res<AnimationSettings>,
+    asset_server: Res<AssetServer>,
+    mut q_piece: Query<(Entity, &mut Piece, &Transform, &mut Handle<Image>), Without<TextInfo>>,
     mut q_player: Query<(&mut Player, &mut Sprite)>,
     mut q_texts: Query<(&TextInfo, &mut Transform, &mut Text)>,
     q_overlay: Query<Entity, With<Overlay>>,
@@ -173,7 +193,7 @@ pub fn handle_board_event(
                     custom_size: Some(Vec2::splat(SQUARE_SIZE)),
                     ..Default::default()
                 };
-                let (x_t, y_t) = chess_position_to_world_position(position.clone());
+                let (x_t, y_t) = chess_position_to_world_position(position.clone(), board.flipped);
 
                 // Spawn color for selected square
                 commands.spawn((
@@ -191,7 +211,8 @@ pub fn handle_board_event(
                 // Spawn highlights for all possible moves
                 for pos_idx in indices.iter() {
                     let circle_pos = Chess::index_to_position(*pos_idx);
-                    let (cx_t, cy_t) = chess_position_to_world_position(circle_pos.clone());
+                    let (cx_t, cy_t) =
+                        chess_position_to_world_position(circle_pos.clone(), board.flipped);
 
                     if chess.contains_piece(&circle_pos) {
                         let annulus_mesh = Mesh2dHandle(
@@ -222,7 +243,7 @@ pub fn handle_board_event(
                     }
                 }
 
-                for (piece, _) in q_piece.iter() {
+                for (_, piece, _, _) in q_piece.iter() {
                     if piece.position.0 == position.0 && piece.position.1 == position.1 {
                         board.update_piece(Piece {
                             is_white: piece.is_white,
@@ -236,104 +257,329 @@ pub fn handle_board_event(
             BoardEvent::DeselectAll => {
                 board.remove_selected();
             }
-            BoardEvent::MovePiece(from, to) => {
-                if chess.move_piece(from, to) {
+            BoardEvent::ShowHint(from, to) => {
+                for position in std::iter::once(from).chain(to) {
+                    let (x_t, y_t) =
+                        chess_position_to_world_position(position.clone(), board.flipped);
+                    commands.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: HINT_GREEN.into(),
+                                custom_size: Some(Vec2::splat(SQUARE_SIZE)),
+                                ..Default::default()
+                            },
+                            transform: Transform::from_xyz(x_t, y_t, 5.),
+                            ..Default::default()
+                        },
+                        Overlay,
+                    ));
+                }
+            }
+            BoardEvent::MovePiece(from, to) | BoardEvent::PromotePiece(from, to, _) => {
+                let promotion = match ev {
+                    BoardEvent::PromotePiece(_, _, piece) => Some(*piece),
+                    _ => None,
+                };
+                let mv = Move {
+                    from: Chess::position_to_index(from),
+                    to: Chess::position_to_index(to),
+                    promotion,
+                };
+                let before = chess.clone();
+                let rook_move = chess.castling_rook_move(from, to);
+                let Some(outcome) = chess.make_move_with_promotion(from, to, promotion) else {
+                    if !board.game_over {
+                        ev_game.send(GameEvent::IllegalMove);
+                    }
+                    board.remove_selected();
+                    continue;
+                };
+                history.san.push(before.to_san(&mv));
+                history.moves.push(mv);
+                history.positions.push(before);
+                {
                     // Reflect the move in UI
-                    if let Some((mut out_piece, mut out_transform)) = q_piece
+                    let captured = outcome.capture_square.map(Chess::index_to_position);
+                    if let Some((out_entity, mut out_piece, out_transform, _)) = q_piece
                         .iter_mut()
-                        .find(|(piece, _)| piece.position.0 == to.0 && piece.position.1 == to.1)
+                        .find(|(_, piece, _, _)| Some(&piece.position) == captured.as_ref())
                     {
-                        if out_piece.is_white {
-                            board.white_out_count = board.white_out_count + 1;
-                            out_transform.translation.y = 4.5 * SQUARE_SIZE;
-                            out_transform.translation.x = 4. * SQUARE_SIZE
-                                - (SQUARE_SIZE * board.white_out_count as f32 / 4.);
+                        let count = if out_piece.is_white {
+                            board.white_out_count += 1;
+                            board.white_out_count
                         } else {
-                            board.black_out_count = board.black_out_count + 1;
-                            out_transform.translation.y = -4.5 * SQUARE_SIZE;
-                            out_transform.translation.x = -4. * SQUARE_SIZE
-                                + (SQUARE_SIZE * board.black_out_count as f32 / 4.);
-                        }
-                        out_transform.scale = out_transform.scale * 0.5;
+                            board.black_out_count += 1;
+                            board.black_out_count
+                        };
+                        commands.entity(out_entity).insert(Tween {
+                            start: out_transform.translation,
+                            end: capture_tray_position(out_piece.is_white, count, board.flipped),
+                            start_scale: out_transform.scale,
+                            end_scale: out_transform.scale * 0.5,
+                            fade: true,
+                            timer: Timer::new(
+                                animation.duration(CAPTURE_ANIMATION_SECS),
+                                TimerMode::Once,
+                            ),
+                        });
                         out_piece.position = Position(9, 9);
                     }
-                    if let Some((mut piece, mut transform)) = q_piece
-                        .iter_mut()
-                        .find(|(piece, _)| piece.position.0 == from.0 && piece.position.1 == from.1)
-                    {
-                        transform.translation.x = LEFT + to.0 as f32 * SQUARE_SIZE;
-                        transform.translation.y = BOTTOM + to.1 as f32 * SQUARE_SIZE;
-                        piece.position = Position(to.0, to.1);
+                    let mut moves = vec![(from.clone(), to.clone())];
+                    if let Some(LastMove(rook_from, rook_to)) = rook_move {
+                        moves.push((
+                            Chess::index_to_position(rook_from),
+                            Chess::index_to_position(rook_to),
+                        ));
                     }
-                    for (player, mut sprite) in q_player.iter_mut() {
-                        if chess.white_turn == player.is_white {
-                            sprite.color = GREEN.into();
-                        } else {
-                            sprite.color = GRAY.into();
+                    for (from, to) in moves {
+                        if let Some((entity, mut piece, transform, mut texture)) =
+                            q_piece.iter_mut().find(|(_, piece, _, _)| {
+                                piece.position.0 == from.0 && piece.position.1 == from.1
+                            })
+                        {
+                            if let Some(promoted) = outcome.promoted {
+                                let code = PIECES_CODE[promoted as usize];
+                                *texture = asset_server.load(format!("pieces/{code}.png"));
+                                piece.code = code.to_string();
+                            }
+                            let (x_t, y_t) =
+                                chess_position_to_world_position(to.clone(), board.flipped);
+                            commands.entity(entity).insert(Tween {
+                                start: transform.translation.with_z(MOVING_PIECE_Z),
+                                end: Vec3::new(x_t, y_t, PIECE_Z),
+                                start_scale: transform.scale,
+                                end_scale: transform.scale,
+                                fade: false,
+                                timer: Timer::new(
+                                    animation.duration(MOVE_ANIMATION_SECS),
+                                    TimerMode::Once,
+                                ),
+                            });
+                            piece.position = to;
                         }
                     }
                     board.update_turn(chess.white_turn);
-
-                    for (text_info, mut transform, _) in q_texts.iter_mut() {
-                        if text_info.text_type == 3 && chess.white_turn {
-                            transform.scale = Vec3::splat(1.);
-                        } else if text_info.text_type == 4 && !chess.white_turn {
-                            transform.scale = Vec3::splat(1.);
-                        } else {
-                            transform.scale = Vec3::splat(0.);
-                        }
-                    }
+                    update_turn_indicators(&chess, &mut q_player, &mut q_texts);
                 }
-                if chess.is_in_check() {
-                    if chess.is_checkmate() {
-                        let text_val = if chess.white_turn {
-                            "Black won"
-                        } else {
-                            "White Won"
-                        };
-                        for (text_info, mut transform, mut text) in q_texts.iter_mut() {
-                            if text_info.text_type == 1 {
-                                transform.scale = Vec3::splat(1.);
-                            } else if text_info.text_type == 2 {
-                                transform.scale = Vec3::splat(1.);
-                                text.sections[0].value = text_val.to_string();
-                            }
-                        }
-                        println!("CHECKMATE!! {} ", text_val)
+                clock.add_increment(!chess.white_turn);
+                if outcome.checkmate || outcome.stalemate {
+                    let (title, text_val, result, reason) = if outcome.stalemate {
+                        ("STALEMATE", "Draw", "1/2-1/2", "Stalemate")
+                    } else if chess.white_turn {
+                        ("CHECKMATE", "Black won", "0-1", "Checkmate")
                     } else {
-                        println!("Check!!");
-                    }
+                        ("CHECKMATE", "White Won", "1-0", "Checkmate")
+                    };
+                    show_game_over(&mut q_texts, title, text_val);
+                    board.end_game(result, reason);
                 }
+                ev_game.send(GameEvent::Moved(outcome));
                 board.remove_selected();
                 for lmo in q_last_move_overlay.iter() {
                     commands.entity(lmo).despawn();
                 }
-                if let Some(last_move) = &chess.last_move {
-                    for pos_idx in [last_move.0, last_move.1].iter() {
-                        let position = Chess::index_to_position(*pos_idx);
-                        let blue_square = Sprite {
-                            color: TRANSPARENT_PURPLE.into(),
-                            custom_size: Some(Vec2::splat(SQUARE_SIZE)),
-                            ..Default::default()
-                        };
-                        let (x_t, y_t) = chess_position_to_world_position(position.clone());
-
-                        // Spawn color for selected square
-                        commands.spawn((
-                            SpriteBundle {
-                                sprite: blue_square,
-                                transform: Transform::from_xyz(x_t, y_t, 5.),
-                                ..Default::default()
-                            },
-                            LastMoveOverlay,
-                        ));
-                    }
-                }
+                spawn_last_move_overlays(&mut commands, &chess, board.flipped);
             }
         }
     }
 }
 
+fn spawn_last_move_overlays(commands: &mut Commands, chess: &Chess, flipped: bool) {
+    if let Some(last_move) = &chess.last_move {
+        for pos_idx in [last_move.0, last_move.1].iter() {
+            let position = Chess::index_to_position(*pos_idx);
+            let blue_square = Sprite {
+                color: TRANSPARENT_PURPLE.into(),
+                custom_size: Some(Vec2::splat(SQUARE_SIZE)),
+                ..Default::default()
+            };
+            let (x_t, y_t) = chess_position_to_world_position(position.clone(), flipped);
+
+            // Spawn color for selected square
+            commands.spawn((
+                SpriteBundle {
+                    sprite: blue_square,
+                    transform: Transform::from_xyz(x_t, y_t, 5.),
+                    ..Default::default()
+                },
+                LastMoveOverlay,
+            ));
+        }
+    }
+}
+
+// Highlights the player to move and shows their status text
+fn update_turn_indicators(
+    chess: &Chess,
+    q_player: &mut Query<(&mut Player, &mut Sprite)>,
+    q_texts: &mut Query<(&TextInfo, &mut Transform, &mut Text)>,
+) {
+    for (player, mut sprite) in q_player.iter_mut() {
+        if chess.white_turn == player.is_white {
+            sprite.color = GREEN.into();
+        } else {
+            sprite.color = GRAY.into();
+        }
+    }
+    for (text_info, mut transform, mut text) in q_texts.iter_mut() {
+        if text_info.text_type == 3 || text_info.text_type == 4 {
+            text.sections[0].value = status_text(chess, text_info.text_type == 3);
+        }
+        if (text_info.text_type == 3 && chess.white_turn)
+            || (text_info.text_type == 4 && !chess.white_turn)
+        {
+            transform.scale = Vec3::splat(1.);
+        } else if text_info.text_type == 3 || text_info.text_type == 4 {
+            transform.scale = Vec3::splat(0.);
+        }
+    }
+}
+
+fn status_text(chess: &Chess, is_white: bool) -> String {
+    let status = if is_white { "Your turn" } else { "Thinking.." };
+    if chess.is_in_check() {
+        format!("Check! {}", status)
+    } else {
+        status.to_string()
+    }
+}
+
+// Re-renders the check highlight whenever the position or board orientation changes
+pub fn check_state_changed(
+    q_chess: Query<(), Changed<Chess>>,
+    q_board: Query<(), Changed<Board>>,
+) -> bool {
+    !q_chess.is_empty() || !q_board.is_empty()
+}
+
+// Marks the king in check in red and, when enabled, the pieces giving check
+pub fn update_check_overlay(
+    mut commands: Commands,
+    q_chess: Query<&Chess>,
+    q_board: Query<&Board>,
+    q_check_overlay: Query<Entity, With<CheckOverlay>>,
+    check_display: Res<CheckDisplay>,
+) {
+    for entity in q_check_overlay.iter() {
+        commands.entity(entity).despawn();
+    }
+    let chess = q_chess.single();
+    let board = q_board.single();
+    let checkers = chess.checkers();
+    if checkers == 0 {
+        return;
+    }
+    let king = if chess.white_turn {
+        chess.pieces[5]
+    } else {
+        chess.pieces[11]
+    };
+    let mut squares = vec![(king.trailing_zeros() as u8, CHECK_RED)];
+    if check_display.highlight_checkers {
+        for idx in get_indices_of_set_bits(checkers) {
+            squares.push((idx, CHECKER_RED));
+        }
+    }
+    for (idx, color) in squares {
+        let position = Chess::index_to_position(idx);
+        let (x_t, y_t) = chess_position_to_world_position(position, board.flipped);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: color.into(),
+                    custom_size: Some(Vec2::splat(SQUARE_SIZE)),
+                    ..Default::default()
+                },
+                transform: Transform::from_xyz(x_t, y_t, 6.),
+                ..Default::default()
+            },
+            CheckOverlay,
+        ));
+    }
+}
+
+pub fn show_game_over(
+    q_texts: &mut Query<(&TextInfo, &mut Transform, &mut Text)>,
+    title: &str,
+    message: &str,
+) {
+    for (text_info, mut transform, mut text) in q_texts.iter_mut() {
+        if text_info.text_type == 1 {
+            transform.scale = Vec3::splat(1.);
+            text.sections[0].value = title.to_string();
+        } else if text_info.text_type == 2 {
+            transform.scale = Vec3::splat(1.);
+            text.sections[0].value = message.to_string();
+        }
+    }
+}
+
+// Runs the side to move's clock once the first move has been played, warning
+// when time is low and ending the game when it runs out.
+pub fn tick_clock(
+    time: Res<Time>,
+    mut clock: ResMut<Clock>,
+    mut ev_game: EventWriter<GameEvent>,
+    mut q_board: Query<&mut Board>,
+    q_chess: Query<&Chess>,
+    mut q_texts: Query<(&TextInfo, &mut Transform, &mut Text)>,
+) {
+    let mut board = q_board.single_mut();
+    let chess = q_chess.single();
+    if !board.game_over && chess.last_move.is_some() {
+        let is_white = chess.white_turn;
+        let side = Clock::side(is_white);
+        let was_low = clock.remaining[side] <= LOW_TIME_SECS;
+        clock.remaining[side] = (clock.remaining[side] - time.delta_seconds()).max(0.);
+        if !was_low && clock.remaining[side] <= LOW_TIME_SECS {
+            ev_game.send(GameEvent::LowTime { is_white });
+        }
+        if clock.remaining[side] == 0. {
+            board.end_game(if is_white { "0-1" } else { "1-0" }, "Time out");
+            ev_game.send(GameEvent::TimeOut);
+            let text_val = if is_white { "Black won" } else { "White Won" };
+            show_game_over(&mut q_texts, "TIME OUT", text_val);
+        }
+    }
+    for (text_info, _, mut text) in q_texts.iter_mut() {
+        if text_info.text_type == 5 {
+            text.sections[0].value = Clock::format(clock.remaining[0]);
+        } else if text_info.text_type == 6 {
+            text.sections[0].value = Clock::format(clock.remaining[1]);
+        }
+    }
+}
+
+pub fn animate_pieces(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_tween: Query<(Entity, &mut Tween, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut tween, mut transform, mut sprite) in q_tween.iter_mut() {
+        tween.timer.tick(time.delta());
+        let t = tween.timer.fraction();
+        // Ease in and out so pieces settle softly onto their squares
+        let eased = t * t * (3. - 2. * t);
+        transform.translation = tween.start.lerp(tween.end, eased);
+        transform.scale = tween.start_scale.lerp(tween.end_scale, eased);
+        if tween.fade {
+            sprite.color.set_alpha((1. - 2. * t).abs());
+        }
+        if tween.timer.finished() {
+            transform.translation = tween.end;
+            transform.scale = tween.end_scale;
+            sprite.color.set_alpha(1.);
+            commands.entity(entity).remove::<Tween>();
+        }
+    }
+}
+
+// Run condition which holds while no piece is being animated
+pub fn animations_idle(q_tween: Query<(), With<Tween>>) -> bool {
+    q_tween.is_empty()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn handle_input(
     mouse: Res<ButtonInput<MouseButton>>,
     q_window: Query<&Window, With<PrimaryWindow>>,
@@ -342,6 +588,7 @@ pub fn handle_input(
     mut ev_board: EventWriter<BoardEvent>,
     q_board: Query<&Board>,
     q_chess: Query<&Chess>,
+    players: Res<Players>,
 ) {
     let (camera, camera_transform) = q_camera.single();
     let window = q_window.get_single().unwrap();
@@ -353,37 +600,52 @@ pub fn handle_input(
         .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
         .map(|ray| ray.origin.truncate())
     {
+        let board = q_board.get_single().unwrap();
         // Now world_position is the position of the mouse where we got click event
         if let Some(touch_pos) =
-            world_position_to_chess_position((world_position.x, world_position.y))
+            world_position_to_chess_position((world_position.x, world_position.y), board.flipped)
         {
-            let board = q_board.get_single().unwrap();
+            let chess = q_chess.get_single().unwrap();
+            if players.is_human(chess.white_turn) {
+                touch_square(touch_pos, board, chess, &q_piece, &mut ev_board);
+            }
+        }
+    }
+}
 
-            match &board.selected_piece {
-                Some(piece) => {
-                    let chess = q_chess.get_single().unwrap();
+// Selects the piece on the touched square, or moves the selected piece there
+pub fn touch_square(
+    touch_pos: Position,
+    board: &Board,
+    chess: &Chess,
+    q_piece: &Query<&Piece>,
+    ev_board: &mut EventWriter<BoardEvent>,
+) {
+    if board.game_over {
+        return;
+    }
 
-                    if chess.is_touch_valid_for_move(&touch_pos) {
-                        ev_board.send(BoardEvent::MovePiece(piece.position.clone(), touch_pos));
-                    } else {
-                        ev_board.send(BoardEvent::SelectPiece(touch_pos));
-                    }
+    match &board.selected_piece {
+        Some(piece) => {
+            if chess.is_touch_valid_for_move(&touch_pos) {
+                ev_board.send(BoardEvent::MovePiece(piece.position.clone(), touch_pos));
+            } else {
+                ev_board.send(BoardEvent::SelectPiece(touch_pos));
+            }
+        }
+        None => {
+            let mut clicked_in_piece = false;
+            for piece in q_piece.iter() {
+                if piece.position.0 == touch_pos.0 && piece.position.1 == touch_pos.1 {
+                    clicked_in_piece = true;
+                    break;
                 }
-                None => {
-                    let mut clicked_in_piece = false;
-                    for piece in q_piece.iter() {
-                        if piece.position.0 == touch_pos.0 && piece.position.1 == touch_pos.1 {
-                            clicked_in_piece = true;
-                            break;
-                        }
-                    }
+            }
 
-                    if clicked_in_piece {
-                        ev_board.send(BoardEvent::SelectPiece(touch_pos));
-                    } else {
-                        ev_board.send(BoardEvent::DeselectAll);
-                    }
-                }
+            if clicked_in_piece {
+                ev_board.send(BoardEvent::SelectPiece(touch_pos));
+            } else {
+                ev_board.send(BoardEvent::DeselectAll);
             }
         }
     }
@@ -396,33 +658,303 @@ pub fn spawn_pieces(
 ) {
     // Spawn Pieces logic implementations
     let chess = q_chess.get_single().unwrap();
+    spawn_piece_sprites(&mut commands, &asset_server, chess, false);
+}
 
+fn spawn_piece_sprite(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    code: &str,
+    position: Position,
+    transform: Transform,
+) {
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load(format!("pieces/{code}.png")),
+            transform,
+            sprite: Sprite {
+                custom_size: Some(Vec2::splat(SQUARE_SIZE)),
+                ..default()
+            },
+            ..default()
+        },
+        Piece {
+            is_white: code.starts_with('w'),
+            code: code.to_string(),
+            position,
+        },
+    ));
+}
+
+fn spawn_piece_sprites(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    chess: &Chess,
+    flipped: bool,
+) {
     for (idx, piece) in chess.pieces.iter().enumerate() {
         let code = PIECES_CODE[idx];
-        let texture = asset_server.load(format!("pieces/{code}.png"));
-
         let set_bits = get_indices_of_set_bits(*piece);
 
         for pos_idx in set_bits.iter() {
             let position = Chess::index_to_position(*pos_idx);
-            let world_pos = chess_position_to_world_position(position.clone());
-            commands.spawn((
-                SpriteBundle {
-                    texture: texture.clone(),
-                    transform: Transform::from_xyz(world_pos.0, world_pos.1, 10.)
-                        .with_scale(Vec3::splat(0.8)),
-                    sprite: Sprite {
-                        custom_size: Some(Vec2::splat(SQUARE_SIZE)),
-                        ..default()
-                    },
-                    ..default()
-                },
-                Piece {
-                    is_white: code.chars().nth(0).unwrap() == 'w',
-                    code: code.to_string(),
-                    position: Position(position.0, position.1),
-                },
-            ));
+            let world_pos = chess_position_to_world_position(position.clone(), flipped);
+            let transform =
+                Transform::from_xyz(world_pos.0, world_pos.1, PIECE_Z).with_scale(Vec3::splat(0.8));
+            spawn_piece_sprite(commands, asset_server, code, position, transform);
+        }
+    }
+}
+
+// Redraws the pieces, capture trays and last move from the engine state,
+// after the position was replaced or the board flipped.
+pub fn sync_board(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    chess: &Chess,
+    board: &mut Board,
+    q_piece: &Query<Entity, With<Piece>>,
+    q_overlays: &Query<Entity, AnyOverlay>,
+) {
+    for entity in q_piece.iter().chain(q_overlays.iter()) {
+        commands.entity(entity).despawn();
+    }
+    spawn_piece_sprites(commands, asset_server, chess, board.flipped);
+
+    // Pieces missing from the starting set are shown as captured
+    board.white_out_count = 0;
+    board.black_out_count = 0;
+    for (idx, piece) in chess.pieces.iter().enumerate() {
+        let missing = STARTING_COUNTS[idx % 6].saturating_sub(piece.count_ones() as u8);
+        for _ in 0..missing {
+            let is_white = idx < 6;
+            let count = if is_white {
+                board.white_out_count += 1;
+                board.white_out_count
+            } else {
+                board.black_out_count += 1;
+                board.black_out_count
+            };
+            let transform =
+                Transform::from_translation(capture_tray_position(is_white, count, board.flipped))
+                    .with_scale(Vec3::splat(0.4));
+            spawn_piece_sprite(
+                commands,
+                asset_server,
+                PIECES_CODE[idx],
+                Position(9, 9),
+                transform,
+            );
+        }
+    }
+    board.remove_selected();
+    board.update_turn(chess.white_turn);
+    spawn_last_move_overlays(commands, chess, board.flipped);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_game_command(
+    mut commands: Commands,
+    mut ev_command: EventReader<GameCommand>,
+    mut ev_game: EventWriter<GameEvent>,
+    asset_server: Res<AssetServer>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    mut history: ResMut<MoveHistory>,
+    mut clock: ResMut<Clock>,
+    players: Res<Players>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut q_player: Query<(&mut Player, &mut Sprite, &mut Transform), Without<TextInfo>>,
+    mut q_texts: Query<(&TextInfo, &mut Transform, &mut Text)>,
+) {
+    for ev in ev_command.read() {
+        let mut board = q_board.single_mut();
+        let mut chess = q_chess.single_mut();
+        match ev {
+            GameCommand::NewGame => {
+                *chess = Chess::new();
+                *history = MoveHistory::default();
+                *clock = Clock::default();
+                board.resume_game();
+            }
+            GameCommand::Undo => {
+                // The bot answers on its own, so only take back moves while
+                // it is not thinking
+                if players.is_online() || (!board.game_over && !players.is_human(chess.white_turn))
+                {
+                    continue;
+                }
+                while let Some(previous) = history.positions.pop() {
+                    history.moves.pop();
+                    history.san.pop();
+                    *chess = previous;
+                    if players.is_human(chess.white_turn) {
+                        break;
+                    }
+                }
+                board.resume_game();
+            }
+            GameCommand::LoadGame { fen, moves } => {
+                let Some(start) = Chess::from_fen(fen) else {
+                    warn!("Could not load the position {fen}");
+                    continue;
+                };
+                *chess = start;
+                *history = MoveHistory::default();
+                for uci in moves {
+                    let Some(mv) = chess.parse_uci(uci) else {
+                        warn!("Stopped loading the game at illegal move {uci}");
+                        break;
+                    };
+                    history.positions.push(chess.clone());
+                    history.san.push(chess.to_san(&mv));
+                    history.moves.push(mv);
+                    chess.play(&mv);
+                }
+                board.resume_game();
+            }
+            GameCommand::Resign { is_white } => {
+                if board.game_over {
+                    continue;
+                }
+                board.end_game(if *is_white { "0-1" } else { "1-0" }, "Resignation");
+                let text_val = if *is_white { "Black won" } else { "White Won" };
+                show_game_over(&mut q_texts, "RESIGNED", text_val);
+                ev_game.send(GameEvent::Resigned);
+                continue;
+            }
+            GameCommand::AgreeDraw => {
+                if board.game_over {
+                    continue;
+                }
+                board.end_game("1/2-1/2", "Draw agreed");
+                show_game_over(&mut q_texts, "DRAW", "Draw agreed");
+                ev_game.send(GameEvent::DrawAgreed);
+                continue;
+            }
+            GameCommand::OfferDraw { .. }
+            | GameCommand::ExportPgn
+            | GameCommand::ResumeGame
+            | GameCommand::Hint => continue,
+            GameCommand::FlipBoard => {
+                board.flipped = !board.flipped;
+                // Player markers sit diagonally opposite, so flipping mirrors
+                // them through the centre; the clocks stay on the right.
+                for (_, _, mut transform) in q_player.iter_mut() {
+                    transform.translation.x = -transform.translation.x;
+                    transform.translation.y = -transform.translation.y;
+                }
+                for (text_info, mut transform, _) in q_texts.iter_mut() {
+                    if text_info.text_type == 3 || text_info.text_type == 4 {
+                        transform.translation.x = -transform.translation.x;
+                    }
+                    if (3..=6).contains(&text_info.text_type) {
+                        transform.translation.y = -transform.translation.y;
+                    }
+                }
+            }
+        }
+        if !board.game_over {
+            for (text_info, mut transform, _) in q_texts.iter_mut() {
+                if text_info.text_type == 1 || text_info.text_type == 2 {
+                    transform.scale = Vec3::splat(0.);
+                }
+            }
+        }
+        sync_board(
+            &mut commands,
+            &asset_server,
+            &chess,
+            &mut board,
+            &q_piece,
+            &q_overlays,
+        );
+        for (player, mut sprite, _) in q_player.iter_mut() {
+            sprite.color = if chess.white_turn == player.is_white {
+                GREEN.into()
+            } else {
+                GRAY.into()
+            };
+        }
+        for (text_info, mut transform, mut text) in q_texts.iter_mut() {
+            if text_info.text_type == 3 || text_info.text_type == 4 {
+                text.sections[0].value = status_text(&chess, text_info.text_type == 3);
+                let shown = (text_info.text_type == 3) == chess.white_turn;
+                transform.scale = Vec3::splat(if shown { 1. } else { 0. });
+            }
+        }
+    }
+}
+
+// The game so far, with the annotations drawn on each position as comments
+// and the hints each side took as headers. Games that didn't start from the
+// standard position carry it in a FEN header, taken from `chess` when no
+// move has been played.
+pub fn game_pgn(
+    history: &MoveHistory,
+    annotations: &Annotations,
+    board: &Board,
+    players: &Players,
+    chess: &Chess,
+) -> PgnGame {
+    let result = board.result.clone().unwrap_or_else(|| "*".to_string());
+    let mut headers = vec![
+        ("Event", "Casual game".to_string()),
+        ("Site", "Yet another chess".to_string()),
+        ("Date", "????.??.??".to_string()),
+        ("Round", "-".to_string()),
+        ("White", players.white.name().to_string()),
+        ("Black", players.black.name().to_string()),
+        ("Result", result.clone()),
+    ];
+    // Games set up from another position say where they started
+    let start = history.positions.first().unwrap_or(chess).to_fen();
+    if start != Chess::new().to_fen() {
+        headers.push(("SetUp", "1".to_string()));
+        headers.push(("FEN", start));
+    }
+    let hints = [
+        ("WhiteHints", history.hints[0]),
+        ("BlackHints", history.hints[1]),
+    ];
+    PgnGame {
+        headers: headers
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .chain(
+                hints
+                    .iter()
+                    .filter(|(_, count)| *count > 0)
+                    .map(|(key, count)| (key.to_string(), count.to_string())),
+            )
+            .collect(),
+        moves: history.san.clone(),
+        comments: (0..=history.moves.len())
+            .filter_map(|ply| Some((ply, annotations.pgn_comment(ply)?)))
+            .collect(),
+        result,
+    }
+}
+
+pub fn export_pgn(
+    mut ev_command: EventReader<GameCommand>,
+    history: Res<MoveHistory>,
+    annotations: Res<Annotations>,
+    players: Res<Players>,
+    q_board: Query<&Board>,
+    q_chess: Query<&Chess>,
+) {
+    for ev in ev_command.read() {
+        if let GameCommand::ExportPgn = ev {
+            let game = game_pgn(
+                &history,
+                &annotations,
+                q_board.single(),
+                &players,
+                q_chess.single(),
+            );
+            storage::export_file("game.pgn", &game.to_pgn());
         }
     }
 }
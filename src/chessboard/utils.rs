@@ -1,5 +1,6 @@
 use super::constants::*;
 use crate::engine::chess::Position;
+use bevy::math::Vec3;
 
 pub fn get_indices_of_set_bits(value: u64) -> Vec<u8> {
     let mut number = value;
@@ -17,21 +18,50 @@ pub fn get_indices_of_set_bits(value: u64) -> Vec<u8> {
     indices
 }
 
-pub fn chess_position_to_world_position(chess_position: Position) -> (f32, f32) {
+// Mirrors a square through the board's centre, as seen from black's side
+fn flip_position(position: Position, flipped: bool) -> Position {
+    if flipped {
+        Position(9 - position.0, 9 - position.1)
+    } else {
+        position
+    }
+}
+
+pub fn chess_position_to_world_position(chess_position: Position, flipped: bool) -> (f32, f32) {
+    let chess_position = flip_position(chess_position, flipped);
     let x_t = LEFT + chess_position.0 as f32 * SQUARE_SIZE;
     let y_t = BOTTOM + chess_position.1 as f32 * SQUARE_SIZE;
-    return (x_t, y_t);
+    (x_t, y_t)
 }
 
-pub fn world_position_to_chess_position(world_position: (f32, f32)) -> Option<Position> {
+pub fn world_position_to_chess_position(
+    world_position: (f32, f32),
+    flipped: bool,
+) -> Option<Position> {
     let (x, y) = (
         ((world_position.0 + SQUARE_SIZE * 4.) / SQUARE_SIZE).trunc() as i8 + 1,
         ((world_position.1 + SQUARE_SIZE * 4.) / SQUARE_SIZE).trunc() as i8 + 1,
     );
 
-    if  x <= 8 && x >= 1 && y <= 8 && y >= 1  {
-        return Some(Position(x as u8, y as u8))
+    if (1..=8).contains(&x) && (1..=8).contains(&y) {
+        return Some(flip_position(Position(x as u8, y as u8), flipped));
     }
-    return None
+    None
+}
 
+// Where the `count`th captured piece of a color rests, beside its opponent
+pub fn capture_tray_position(is_white: bool, count: u8, flipped: bool) -> Vec3 {
+    let (x_t, y_t) = if is_white {
+        (
+            4. * SQUARE_SIZE - (SQUARE_SIZE * count as f32 / 4.),
+            4.5 * SQUARE_SIZE,
+        )
+    } else {
+        (
+            -4. * SQUARE_SIZE + (SQUARE_SIZE * count as f32 / 4.),
+            -4.5 * SQUARE_SIZE,
+        )
+    };
+    let sign = if flipped { -1. } else { 1. };
+    Vec3::new(sign * x_t, sign * y_t, PIECE_Z)
 }
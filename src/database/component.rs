@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use chess::engine::pgn::{PgnGame, ResultFilter};
+
+use super::constants::*;
+use crate::engine::chess::Chess;
+
+// Games of the PGN file in CHESS_GAMES, empty when it isn't set
+#[derive(Resource, Debug, Default)]
+pub struct GameDatabase(pub Vec<PgnGame>);
+
+impl GameDatabase {
+    pub fn from_env() -> GameDatabase {
+        let Ok(path) = std::env::var("CHESS_GAMES") else {
+            return GameDatabase::default();
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                let games = PgnGame::parse_all(&text);
+                info!("{} games from {path}", games.len());
+                GameDatabase(games)
+            }
+            Err(err) => {
+                warn!("Can't read games from {path}: {err}");
+                GameDatabase::default()
+            }
+        }
+    }
+}
+
+// Narrows the list down to games whose players, event or opening contain
+// `text`, with the result chosen
+#[derive(Resource, Debug, Default)]
+pub struct GameFilter {
+    pub text: String,
+    pub result: ResultFilter,
+}
+
+// The game replayed on the board
+#[derive(Resource, Debug)]
+pub struct Replay {
+    // Index of the game in the database
+    pub game: Option<usize>,
+    // Every position of the game, one more than the moves, and the moves' SAN
+    pub positions: Vec<Chess>,
+    pub san: Vec<String>,
+    // Moves played on the board
+    pub ply: usize,
+    pub autoplay: bool,
+    // Index into `AUTOPLAY_SECS`
+    pub speed: usize,
+    pub timer: Timer,
+    // The position on the board before the database was opened, put back on
+    // leaving it
+    pub original: Chess,
+}
+
+impl Default for Replay {
+    fn default() -> Self {
+        Self {
+            game: None,
+            positions: Vec::new(),
+            san: Vec::new(),
+            ply: 0,
+            autoplay: false,
+            speed: DEFAULT_SPEED,
+            timer: Timer::from_seconds(AUTOPLAY_SECS[DEFAULT_SPEED], TimerMode::Repeating),
+            original: Chess::new(),
+        }
+    }
+}
+
+impl Replay {
+    pub fn plies(&self) -> usize {
+        self.san.len()
+    }
+}
+
+// The games passing the filter, by index in the database, scrolled up by
+// `offset` pixels
+#[derive(Component, Debug, Default)]
+pub struct GameList {
+    pub games: Vec<usize>,
+    pub offset: f32,
+}
+
+#[derive(Component, Debug)]
+pub struct GameButton {
+    pub game: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component)]
+pub enum DatabaseButton {
+    Start,
+    Back,
+    Play,
+    Forward,
+    End,
+    Slower,
+    Faster,
+    Result,
+    Menu,
+}
+
+impl DatabaseButton {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DatabaseButton::Start => "|<",
+            DatabaseButton::Back => "<",
+            DatabaseButton::Play => "Play",
+            DatabaseButton::Forward => ">",
+            DatabaseButton::End => ">|",
+            DatabaseButton::Slower => "Slower",
+            DatabaseButton::Faster => "Faster",
+            DatabaseButton::Result => ResultFilter::All.label(),
+            DatabaseButton::Menu => "Menu",
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct FilterText;
+
+// How many games pass the filter
+#[derive(Component, Debug)]
+pub struct ListSummaryText;
+
+// Players, event and result of the game on the board
+#[derive(Component, Debug)]
+pub struct GameInfoText;
+
+// The last move played and the speed of autoplay
+#[derive(Component, Debug)]
+pub struct ReplayMoveText;
+
+// The game's comment on the position shown
+#[derive(Component, Debug)]
+pub struct CommentText;
+
+#[derive(Component, Debug)]
+pub struct PlayButtonText;
+
+#[derive(Component, Debug)]
+pub struct ResultFilterText;
+
+// Texts that follow the replay
+pub type ReplayTexts = AnyOf<(
+    &'static GameInfoText,
+    &'static ReplayMoveText,
+    &'static CommentText,
+    &'static PlayButtonText,
+)>;
@@ -0,0 +1,21 @@
+use bevy::color::*;
+
+// The game on the board in the list
+pub const GAME_SELECTED: Srgba = Srgba::new(0.482_352_94, 0.380_392_16, 1., 1.);
+
+pub const LIST_WIDTH: f32 = 280.;
+pub const GAME_BUTTON_HEIGHT: f32 = 44.;
+// Height of the game list before it scrolls
+pub const GAME_LIST_HEIGHT: f32 = 460.;
+pub const SCROLL_STEP: f32 = 24.;
+// Games listed at once; the filter narrows down larger databases
+pub const MAX_LISTED: usize = 200;
+pub const FILTER_LEN: usize = 24;
+
+pub const STEP_BUTTON_WIDTH: f32 = 40.;
+pub const CONTROL_BUTTON_WIDTH: f32 = 88.;
+pub const CONTROL_BUTTON_HEIGHT: f32 = 32.;
+pub const COMMENT_WIDTH: f32 = 240.;
+// Seconds between moves when replaying on its own, slowest first
+pub const AUTOPLAY_SECS: [f32; 5] = [4., 2., 1., 0.5, 0.25];
+pub const DEFAULT_SPEED: usize = 2;
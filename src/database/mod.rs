@@ -0,0 +1,46 @@
+mod component;
+mod constants;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::component::GameState;
+use component::*;
+use system::*;
+
+// Game database browser: the games of a PGN file listed with their headers,
+// filtered by player, opening and result, and replayed on the board move by
+// move or on their own, with the games' comments
+pub struct DatabasePlugin;
+
+impl Plugin for DatabasePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameDatabase::from_env())
+            .init_resource::<GameFilter>()
+            .init_resource::<Replay>()
+            .add_systems(OnEnter(GameState::Database), start_database)
+            .add_systems(OnExit(GameState::Database), stop_database)
+            .add_systems(
+                Update,
+                (
+                    type_filter,
+                    list_games
+                        .after(type_filter)
+                        .after(handle_database_buttons)
+                        .run_if(resource_changed::<GameFilter>),
+                    handle_game_buttons,
+                    handle_database_buttons,
+                    handle_replay_keys,
+                    autoplay,
+                    scroll_game_list,
+                    show_replay
+                        .after(handle_game_buttons)
+                        .after(handle_database_buttons)
+                        .after(handle_replay_keys)
+                        .after(autoplay)
+                        .run_if(resource_changed::<Replay>),
+                )
+                    .run_if(in_state(GameState::Database)),
+            );
+    }
+}
@@ -0,0 +1,652 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use chess::engine::pgn::PgnGame;
+
+use super::component::*;
+use super::constants::*;
+use crate::chessboard::component::{AnyOverlay, Board, GameState, Piece, TextInfo};
+use crate::chessboard::system::sync_board;
+use crate::engine::chess::Chess;
+use crate::menu::constants::*;
+use crate::menu::{button_text_style, side_panel};
+
+fn header<'a>(game: &'a PgnGame, key: &str) -> &'a str {
+    game.header(key)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("?")
+}
+
+fn spawn_database_button(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    button: DatabaseButton,
+    width: f32,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(width),
+                    height: Val::Px(CONTROL_BUTTON_HEIGHT),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: BUTTON_NORMAL.into(),
+                ..default()
+            },
+            button,
+        ))
+        .with_children(|parent| {
+            let label =
+                TextBundle::from_section(button.label(), button_text_style(asset_server, 18.));
+            match button {
+                DatabaseButton::Play => parent.spawn((label, PlayButtonText)),
+                DatabaseButton::Result => parent.spawn((label, ResultFilterText)),
+                _ => parent.spawn(label),
+            };
+        });
+}
+
+fn row() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            column_gap: Val::Px(4.),
+            ..default()
+        },
+        ..default()
+    }
+}
+
+// Lays out the game list on the left and the replay controls on the right,
+// keeping the position on the board to put back on leaving
+pub fn start_database(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut replay: ResMut<Replay>,
+    mut filter: ResMut<GameFilter>,
+    q_chess: Query<&Chess>,
+    mut q_texts: Query<(&TextInfo, &mut Transform)>,
+) {
+    *replay = Replay {
+        original: q_chess.single().clone(),
+        ..default()
+    };
+    // Lists the games again for the new panel
+    filter.set_changed();
+    // The game over banner would hide the board
+    for (text_info, mut transform) in q_texts.iter_mut() {
+        if text_info.text_type == 1 || text_info.text_type == 2 {
+            transform.scale = Vec3::splat(0.);
+        }
+    }
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(20.),
+                    top: Val::Px(20.),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.),
+                    padding: UiRect::all(Val::Px(12.)),
+                    ..default()
+                },
+                background_color: PANEL_BACKGROUND.into(),
+                ..default()
+            },
+            StateScoped(GameState::Database),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Game database",
+                button_text_style(&asset_server, 28.),
+            ));
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 18.)),
+                FilterText,
+            ));
+            spawn_database_button(parent, &asset_server, DatabaseButton::Result, LIST_WIDTH);
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 16.)),
+                ListSummaryText,
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(LIST_WIDTH),
+                        max_height: Val::Px(GAME_LIST_HEIGHT),
+                        overflow: Overflow::clip_y(),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.),
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(2.),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        GameList::default(),
+                    ));
+                });
+        });
+
+    commands
+        .spawn((side_panel(), StateScoped(GameState::Database)))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 18.)).with_style(
+                    Style {
+                        max_width: Val::Px(COMMENT_WIDTH),
+                        ..default()
+                    },
+                ),
+                GameInfoText,
+            ));
+            parent.spawn(row()).with_children(|parent| {
+                for button in [
+                    DatabaseButton::Start,
+                    DatabaseButton::Back,
+                    DatabaseButton::Forward,
+                    DatabaseButton::End,
+                ] {
+                    spawn_database_button(parent, &asset_server, button, STEP_BUTTON_WIDTH);
+                }
+            });
+            parent.spawn(row()).with_children(|parent| {
+                for button in [DatabaseButton::Slower, DatabaseButton::Faster] {
+                    spawn_database_button(parent, &asset_server, button, CONTROL_BUTTON_WIDTH);
+                }
+            });
+            spawn_database_button(
+                parent,
+                &asset_server,
+                DatabaseButton::Play,
+                2. * CONTROL_BUTTON_WIDTH + 4.,
+            );
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 18.)),
+                ReplayMoveText,
+            ));
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 16.)).with_style(
+                    Style {
+                        max_width: Val::Px(COMMENT_WIDTH),
+                        ..default()
+                    },
+                ),
+                CommentText,
+            ));
+            spawn_database_button(
+                parent,
+                &asset_server,
+                DatabaseButton::Menu,
+                2. * CONTROL_BUTTON_WIDTH + 4.,
+            );
+        });
+}
+
+// Puts back the position the board had before
+#[allow(clippy::too_many_arguments)]
+pub fn stop_database(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut replay: ResMut<Replay>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut q_texts: Query<(&TextInfo, &mut Transform)>,
+) {
+    let mut chess = q_chess.single_mut();
+    let mut board = q_board.single_mut();
+    *chess = replay.original.clone();
+    replay.game = None;
+    replay.autoplay = false;
+    sync_board(
+        &mut commands,
+        &asset_server,
+        &chess,
+        &mut board,
+        &q_piece,
+        &q_overlays,
+    );
+    if board.game_over {
+        for (text_info, mut transform) in q_texts.iter_mut() {
+            if text_info.text_type == 1 || text_info.text_type == 2 {
+                transform.scale = Vec3::splat(1.);
+            }
+        }
+    }
+}
+
+// Typing narrows the list down to players, events or openings
+pub fn type_filter(mut ev_keys: EventReader<KeyboardInput>, mut filter: ResMut<GameFilter>) {
+    for ev in ev_keys.read() {
+        if ev.state != ButtonState::Pressed {
+            continue;
+        }
+        match &ev.logical_key {
+            Key::Backspace => {
+                filter.text.pop();
+            }
+            Key::Character(text) => {
+                for c in text
+                    .chars()
+                    .filter(|c| c.is_alphanumeric() || matches!(c, '-' | '.' | ',' | '\''))
+                {
+                    if filter.text.chars().count() < FILTER_LEN {
+                        filter.text.push(c);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn game_label(game: &PgnGame) -> String {
+    format!(
+        "{} - {}\n{}  {}, {}",
+        header(game, "White"),
+        header(game, "Black"),
+        game.result,
+        header(game, "Event"),
+        header(game, "Date")
+    )
+}
+
+// Lists the games passing the filter, up to `MAX_LISTED`
+#[allow(clippy::too_many_arguments)]
+pub fn list_games(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    database: Res<GameDatabase>,
+    filter: Res<GameFilter>,
+    mut q_list: Query<(Entity, &mut GameList, &mut Style)>,
+    mut q_filter_text: Query<&mut Text, (With<FilterText>, Without<ListSummaryText>)>,
+    mut q_summary: Query<&mut Text, (With<ListSummaryText>, Without<ResultFilterText>)>,
+    mut q_result: Query<&mut Text, (With<ResultFilterText>, Without<FilterText>)>,
+) {
+    let games = database
+        .0
+        .iter()
+        .enumerate()
+        .filter(|(_, game)| game.matches(&filter.text, filter.result))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    for mut text in q_filter_text.iter_mut() {
+        text.sections[0].value = if filter.text.is_empty() {
+            "Type a player or opening".to_string()
+        } else {
+            format!("Search: {}", filter.text)
+        };
+    }
+    for mut text in q_result.iter_mut() {
+        text.sections[0].value = filter.result.label().to_string();
+    }
+    for mut text in q_summary.iter_mut() {
+        text.sections[0].value = if database.0.is_empty() {
+            "No games: set CHESS_GAMES to a PGN file".to_string()
+        } else if games.len() > MAX_LISTED {
+            format!("First {MAX_LISTED} of {} games", games.len())
+        } else {
+            format!("{} of {} games", games.len(), database.0.len())
+        };
+    }
+    for (entity, mut list, mut style) in q_list.iter_mut() {
+        list.games.clone_from(&games);
+        list.offset = 0.;
+        style.top = Val::Px(0.);
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for idx in games.iter().take(MAX_LISTED) {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Percent(100.),
+                                    height: Val::Px(GAME_BUTTON_HEIGHT),
+                                    flex_shrink: 0.,
+                                    padding: UiRect::horizontal(Val::Px(6.)),
+                                    align_items: AlignItems::Center,
+                                    overflow: Overflow::clip(),
+                                    ..default()
+                                },
+                                background_color: BUTTON_NORMAL.into(),
+                                ..default()
+                            },
+                            GameButton { game: *idx },
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                game_label(&database.0[*idx]),
+                                button_text_style(&asset_server, 15.),
+                            ));
+                        });
+                }
+            });
+    }
+}
+
+// Sets the game up on the board from its first position
+fn open_game(replay: &mut Replay, database: &GameDatabase, idx: usize) {
+    let game = &database.0[idx];
+    let Some((positions, moves)) = game.replay() else {
+        warn!("Could not set up the position of game {}", idx + 1);
+        return;
+    };
+    if moves.len() < game.moves.len() {
+        warn!(
+            "Game {} stops at its illegal move {}",
+            idx + 1,
+            game.moves[moves.len()]
+        );
+    }
+    replay.san = positions
+        .iter()
+        .zip(&moves)
+        .map(|(chess, mv)| chess.to_san(mv))
+        .collect();
+    replay.positions = positions;
+    replay.game = Some(idx);
+    replay.ply = 0;
+    replay.autoplay = false;
+}
+
+// Opens the game clicked, and marks the open game in the list
+pub fn handle_game_buttons(
+    database: Res<GameDatabase>,
+    mut replay: ResMut<Replay>,
+    mut q_buttons: Query<(&Interaction, &GameButton, &mut BackgroundColor)>,
+) {
+    for (interaction, button, _) in q_buttons.iter() {
+        if *interaction == Interaction::Pressed && replay.game != Some(button.game) {
+            open_game(&mut replay, &database, button.game);
+        }
+    }
+    for (interaction, button, mut background) in q_buttons.iter_mut() {
+        background.set_if_neq(
+            if replay.game == Some(button.game) {
+                GAME_SELECTED
+            } else if *interaction == Interaction::Hovered {
+                BUTTON_HOVERED
+            } else {
+                BUTTON_NORMAL
+            }
+            .into(),
+        );
+    }
+}
+
+fn set_speed(replay: &mut Replay, speed: usize) {
+    replay.speed = speed.min(AUTOPLAY_SECS.len() - 1);
+    let secs = AUTOPLAY_SECS[replay.speed];
+    replay
+        .timer
+        .set_duration(std::time::Duration::from_secs_f32(secs));
+    replay.timer.reset();
+}
+
+fn toggle_autoplay(replay: &mut Replay) {
+    if replay.game.is_none() {
+        return;
+    }
+    replay.autoplay = !replay.autoplay;
+    // Playing the game again from the end starts it over
+    if replay.autoplay && replay.ply == replay.plies() {
+        replay.ply = 0;
+    }
+    replay.timer.reset();
+}
+
+pub fn handle_database_buttons(
+    mut q_buttons: Query<
+        (&Interaction, &DatabaseButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut replay: ResMut<Replay>,
+    mut filter: ResMut<GameFilter>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, button, mut background) in q_buttons.iter_mut() {
+        *background = match interaction {
+            Interaction::Pressed => BUTTON_PRESSED.into(),
+            Interaction::Hovered => BUTTON_HOVERED.into(),
+            Interaction::None => BUTTON_NORMAL.into(),
+        };
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let plies = replay.plies();
+        match button {
+            DatabaseButton::Start => replay.ply = 0,
+            DatabaseButton::Back => replay.ply = replay.ply.saturating_sub(1),
+            DatabaseButton::Forward => replay.ply = (replay.ply + 1).min(plies),
+            DatabaseButton::End => replay.ply = plies,
+            DatabaseButton::Play => toggle_autoplay(&mut replay),
+            DatabaseButton::Slower => {
+                let speed = replay.speed.saturating_sub(1);
+                set_speed(&mut replay, speed);
+            }
+            DatabaseButton::Faster => {
+                let speed = replay.speed + 1;
+                set_speed(&mut replay, speed);
+            }
+            DatabaseButton::Result => filter.result = filter.result.next(),
+            DatabaseButton::Menu => next_state.set(GameState::Menu),
+        }
+    }
+}
+
+// Arrows step through the game and Up and Down through the list, Home and
+// End jump to either end, Space plays the game on its own at the speed set
+// with `[` and `]`, and Escape goes back to the menu
+pub fn handle_replay_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    database: Res<GameDatabase>,
+    mut replay: ResMut<Replay>,
+    q_list: Query<&GameList>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let plies = replay.plies();
+    if keys.just_pressed(KeyCode::ArrowLeft) && replay.ply > 0 {
+        replay.ply -= 1;
+    }
+    if keys.just_pressed(KeyCode::ArrowRight) && replay.ply < plies {
+        replay.ply += 1;
+    }
+    if keys.just_pressed(KeyCode::Home) && replay.ply > 0 {
+        replay.ply = 0;
+    }
+    if keys.just_pressed(KeyCode::End) && replay.ply < plies {
+        replay.ply = plies;
+    }
+    if keys.just_pressed(KeyCode::Space) {
+        toggle_autoplay(&mut replay);
+    }
+    if keys.just_pressed(KeyCode::BracketLeft) {
+        let speed = replay.speed.saturating_sub(1);
+        set_speed(&mut replay, speed);
+    }
+    if keys.just_pressed(KeyCode::BracketRight) {
+        let speed = replay.speed + 1;
+        set_speed(&mut replay, speed);
+    }
+    let step = match (
+        keys.just_pressed(KeyCode::ArrowUp),
+        keys.just_pressed(KeyCode::ArrowDown),
+    ) {
+        (true, false) => Some(false),
+        (false, true) => Some(true),
+        _ => None,
+    };
+    if let (Some(down), Ok(list)) = (step, q_list.get_single()) {
+        let listed = &list.games[..list.games.len().min(MAX_LISTED)];
+        let current = replay
+            .game
+            .and_then(|game| listed.iter().position(|idx| *idx == game));
+        let next = match current {
+            Some(pos) if down => listed.get(pos + 1),
+            Some(pos) => pos.checked_sub(1).and_then(|pos| listed.get(pos)),
+            None => listed.first(),
+        };
+        if let Some(idx) = next {
+            open_game(&mut replay, &database, *idx);
+        }
+    }
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::Menu);
+    }
+}
+
+// Steps the game forward on its own until its end
+pub fn autoplay(time: Res<Time>, mut replay: ResMut<Replay>) {
+    if !replay.autoplay {
+        return;
+    }
+    // The timer ticking isn't a change to show
+    if !replay
+        .bypass_change_detection()
+        .timer
+        .tick(time.delta())
+        .just_finished()
+    {
+        return;
+    }
+    if replay.ply < replay.plies() {
+        replay.ply += 1;
+    }
+    if replay.ply == replay.plies() {
+        replay.autoplay = false;
+    }
+}
+
+pub fn scroll_game_list(
+    mut ev_wheel: EventReader<MouseWheel>,
+    mut q_list: Query<(&mut GameList, &mut Style, &Parent, &Node)>,
+    q_node: Query<&Node>,
+) {
+    let scrolled = ev_wheel
+        .read()
+        .map(|ev| match ev.unit {
+            MouseScrollUnit::Line => ev.y * SCROLL_STEP,
+            MouseScrollUnit::Pixel => ev.y,
+        })
+        .sum::<f32>();
+    if scrolled == 0. {
+        return;
+    }
+    for (mut list, mut style, parent, node) in q_list.iter_mut() {
+        let visible = q_node
+            .get(parent.get())
+            .map_or(0., |parent| parent.size().y);
+        let hidden = (node.size().y - visible).max(0.);
+        list.offset = (list.offset - scrolled).clamp(0., hidden);
+        style.top = Val::Px(-list.offset);
+    }
+}
+
+fn game_info(game: &PgnGame) -> String {
+    let player = |color: &str| match game.header(&format!("{color}Elo")) {
+        Some(elo) if !elo.is_empty() && elo != "?" => {
+            format!("{} ({elo})", header(game, color))
+        }
+        _ => header(game, color).to_string(),
+    };
+    let mut info = format!(
+        "{}\n{}\n{}, {}",
+        player("White"),
+        player("Black"),
+        header(game, "Event"),
+        header(game, "Date")
+    );
+    if let Some(opening) = game.header("Opening") {
+        info.push_str(&format!("\n{opening}"));
+    }
+    info.push_str(&format!("\nResult {}", game.result));
+    info
+}
+
+fn move_text(replay: &Replay) -> String {
+    let speed = format!("Autoplay: a move every {}s", AUTOPLAY_SECS[replay.speed]);
+    let Some(ply) = replay.ply.checked_sub(1) else {
+        return format!("Start ({}/{})\n{speed}", 0, replay.plies());
+    };
+    let chess = &replay.positions[ply];
+    let number = if chess.white_turn {
+        format!("{}.", chess.fullmove_number)
+    } else {
+        format!("{}...", chess.fullmove_number)
+    };
+    format!(
+        "{number} {} ({}/{})\n{speed}",
+        replay.san[ply],
+        replay.ply,
+        replay.plies()
+    )
+}
+
+// Shows the position the replay has got to, with its comment
+#[allow(clippy::too_many_arguments)]
+pub fn show_replay(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    database: Res<GameDatabase>,
+    replay: Res<Replay>,
+    mut shown: Local<Option<(Option<usize>, usize)>>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut q_texts: Query<(&mut Text, ReplayTexts)>,
+) {
+    if *shown != Some((replay.game, replay.ply)) {
+        *shown = Some((replay.game, replay.ply));
+        let mut chess = q_chess.single_mut();
+        *chess = replay
+            .positions
+            .get(replay.ply)
+            .unwrap_or(&replay.original)
+            .clone();
+        sync_board(
+            &mut commands,
+            &asset_server,
+            &chess,
+            &mut q_board.single_mut(),
+            &q_piece,
+            &q_overlays,
+        );
+    }
+
+    let game = replay.game.map(|idx| &database.0[idx]);
+    for (mut text, (info, move_label, comment, play)) in q_texts.iter_mut() {
+        let value = if info.is_some() {
+            game.map_or("Pick a game from the list".to_string(), game_info)
+        } else if move_label.is_some() {
+            game.map_or(String::new(), |_| move_text(&replay))
+        } else if comment.is_some() {
+            game.and_then(|game| game.comments.get(&replay.ply))
+                .cloned()
+                .unwrap_or_default()
+        } else if play.is_some() {
+            if replay.autoplay { "Pause" } else { "Play" }.to_string()
+        } else {
+            continue;
+        };
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+}
@@ -1,27 +1,64 @@
 use super::constants::*;
-use bevy::prelude::*;
+#[cfg(feature = "bevy")]
+use bevy::prelude::Component;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Position(pub u8, pub u8);
 
-// #[derive(Debug, Clone)]
-// enum CastlingStatus {
-//     Available,
-//     QueenSide,
-//     KingSide,
-// }
+// Castling rights are stored as bit flags in `Chess::castling_rights`
+pub const WHITE_KING_SIDE: u8 = 1;
+pub const WHITE_QUEEN_SIDE: u8 = 2;
+pub const BLACK_KING_SIDE: u8 = 4;
+pub const BLACK_QUEEN_SIDE: u8 = 8;
+
 // Last move stores position in
 // idx format. idx for board in 64
 #[derive(Clone, Debug)]
 pub struct LastMove(pub u8, pub u8);
 
-#[derive(Component, Debug, Clone)]
+// A move in idx format. `promotion` is the index into `Chess::pieces` of the
+// piece a pawn is promoted to, a queen when not given.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub from: u8,
+    pub to: u8,
+    pub promotion: Option<u8>,
+}
+
+// What happened on the board as a result of a successful move
+#[derive(Clone, Debug, Default)]
+pub struct MoveOutcome {
+    // Index into `Chess::pieces` of the captured piece and the idx of the
+    // square it was taken on, which differs from the destination en passant
+    pub captured: Option<u8>,
+    pub capture_square: Option<u8>,
+    pub castled: bool,
+    // Index into `Chess::pieces` of the piece the pawn was promoted to
+    pub promoted: Option<u8>,
+    pub check: bool,
+    pub checkmate: bool,
+    pub stalemate: bool,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "bevy", derive(Component))]
 pub struct Chess {
     pub pieces: [u64; 12],
     pub white_turn: bool,
     pub last_move: Option<LastMove>,
-    // pub castling_status: [CastlingStatus; 2],
-    // pub possible_enpassant: u8,
+    pub castling_rights: u8,
+    // Square idx a pawn may capture onto en passant
+    pub en_passant: Option<u8>,
+    // Plies since the last capture or pawn move, for the fifty-move rule
+    pub halfmove_clock: u16,
+    // Starts at 1 and goes up after each black move
+    pub fullmove_number: u16,
+}
+
+impl Default for Chess {
+    fn default() -> Self {
+        Chess::new()
+    }
 }
 
 impl Chess {
@@ -42,7 +79,14 @@ impl Chess {
                 1152921504606846976,
             ],
             white_turn: true,
-            last_move: None, // possible_enpassant: 0,
+            last_move: None,
+            en_passant: None,
+            castling_rights: WHITE_KING_SIDE
+                | WHITE_QUEEN_SIDE
+                | BLACK_KING_SIDE
+                | BLACK_QUEEN_SIDE,
+            halfmove_clock: 0,
+            fullmove_number: 1,
         }
     }
 
@@ -86,7 +130,11 @@ impl Chess {
 
         let pawn_attack = left_attack | right_attack;
 
-        let valid_attacks = pawn_attack & opponent_pieces;
+        let en_passant = match self.en_passant {
+            Some(idx) if for_piece == self.white_turn => 1 << idx,
+            _ => 0,
+        };
+        let valid_attacks = pawn_attack & (opponent_pieces | en_passant);
         let valid_moves = one_move_forward | two_moves_forward;
         valid_attacks | valid_moves
     }
@@ -110,7 +158,7 @@ impl Chess {
                 break;
             }
             moves.push(right_pointer << 1);
-            right_pointer = right_pointer << 1;
+            right_pointer <<= 1;
         }
 
         // Parse left
@@ -124,7 +172,7 @@ impl Chess {
                 break;
             }
             moves.push(left_pointer >> 1);
-            left_pointer = left_pointer >> 1;
+            left_pointer >>= 1;
         }
 
         // Parse top
@@ -138,7 +186,7 @@ impl Chess {
                 break;
             }
             moves.push(top_pointer << 8);
-            top_pointer = top_pointer << 8;
+            top_pointer <<= 8;
         }
 
         // Parse bottom
@@ -152,9 +200,9 @@ impl Chess {
                 break;
             }
             moves.push(bottom_pointer >> 8);
-            bottom_pointer = bottom_pointer >> 8;
+            bottom_pointer >>= 8;
         }
-        moves.iter().fold(0, |acc, x| (acc | x))
+        moves.iter().fold(0, |acc, x| acc | x)
     }
 
     fn possible_bishop_moves(&self, bishop_position: &Position, for_piece: bool) -> u64 {
@@ -175,7 +223,7 @@ impl Chess {
                 break;
             }
             moves.push(nw << 9);
-            nw = nw << 9;
+            nw <<= 9;
         }
         // parse north east
         let mut ne = bl;
@@ -188,7 +236,7 @@ impl Chess {
                 break;
             }
             moves.push(ne << 7);
-            ne = ne << 7;
+            ne <<= 7;
         }
         // parse south west
         let mut sw = bl;
@@ -201,7 +249,7 @@ impl Chess {
                 break;
             }
             moves.push(sw >> 7);
-            sw = sw >> 7;
+            sw >>= 7;
         }
         // parse south east
         let mut se = bl;
@@ -214,9 +262,9 @@ impl Chess {
                 break;
             }
             moves.push(se >> 9);
-            se = se >> 9;
+            se >>= 9;
         }
-        moves.iter().fold(0, |acc, x| (acc | x))
+        moves.iter().fold(0, |acc, x| acc | x)
     }
 
     fn possible_knight_moves_by_location(&self, nl: u64, own_pieces: u64) -> u64 {
@@ -266,8 +314,95 @@ impl Chess {
         (north | south | east | west | ne | nw | se | sw) & !own_pieces
     }
 
+    // Castling destinations for the king of the side to move. The king may
+    // not castle out of, through or into check.
+    fn possible_castling_moves(&self, king_position: &Position) -> u64 {
+        let (king_idx, rook_piece, king_side, queen_side) = if self.white_turn {
+            (4, 1, WHITE_KING_SIDE, WHITE_QUEEN_SIDE)
+        } else {
+            (60, 7, BLACK_KING_SIDE, BLACK_QUEEN_SIDE)
+        };
+        if Chess::position_to_index(king_position) != king_idx {
+            return 0;
+        }
+        let all_pieces = self.get_all_pieces();
+        let rooks = self.pieces[rook_piece];
+        let mut moves = 0;
+        if self.castling_rights & king_side > 0
+            && rooks & (1 << (king_idx + 3)) > 0
+            && all_pieces & (0b11 << (king_idx + 1)) == 0
+            && (king_idx..king_idx + 3).all(|idx| !self.is_square_attacked(idx, !self.white_turn))
+        {
+            moves |= 1 << (king_idx + 2);
+        }
+        if self.castling_rights & queen_side > 0
+            && rooks & (1 << (king_idx - 4)) > 0
+            && all_pieces & (0b111 << (king_idx - 3)) == 0
+            && (king_idx - 2..king_idx + 1)
+                .all(|idx| !self.is_square_attacked(idx, !self.white_turn))
+        {
+            moves |= 1 << (king_idx - 2);
+        }
+        moves
+    }
+
+    // Returns the rook's (from, to) squares when moving `from` -> `to` is a
+    // castling move of the king.
+    pub fn castling_rook_move(&self, from: &Position, to: &Position) -> Option<LastMove> {
+        let piece_idx = self.get_piece(from)?;
+        if (piece_idx != 5 && piece_idx != 11) || from.0.abs_diff(to.0) != 2 {
+            return None;
+        }
+        let from_idx = Chess::position_to_index(from);
+        if to.0 > from.0 {
+            Some(LastMove(from_idx + 3, from_idx + 1))
+        } else {
+            Some(LastMove(from_idx - 4, from_idx - 1))
+        }
+    }
+
+    // Whether the square at `idx` is attacked by any piece of `by_white` color
+    pub fn is_square_attacked(&self, idx: u8, by_white: bool) -> bool {
+        self.attackers_of(idx, by_white) > 0
+    }
+
+    // Bitboard of the pieces of `by_white` color attacking the square at `idx`
+    pub fn attackers_of(&self, idx: u8, by_white: bool) -> u64 {
+        let position = Chess::index_to_position(idx);
+        let location: u64 = 1 << idx;
+        let offset = if by_white { 0 } else { 6 };
+        let pawn_attackers = if by_white {
+            (location >> 9 & *CLEAR_FILE.get("FILE1").unwrap())
+                | (location >> 7 & *CLEAR_FILE.get("FILE8").unwrap())
+        } else {
+            (location << 9 & *CLEAR_FILE.get("FILE8").unwrap())
+                | (location << 7 & *CLEAR_FILE.get("FILE1").unwrap())
+        };
+        let straight = self.pieces[offset + 1] | self.pieces[offset + 4];
+        let diagonal = self.pieces[offset + 3] | self.pieces[offset + 4];
+
+        (pawn_attackers & self.pieces[offset])
+            | (self.possible_knight_moves_by_location(location, 0) & self.pieces[offset + 2])
+            | (self.possible_king_moves(&position, !by_white) & self.pieces[offset + 5])
+            | (self.possible_rook_moves(&position, !by_white) & straight)
+            | (self.possible_bishop_moves(&position, !by_white) & diagonal)
+    }
+
+    // Bitboard of the opponent pieces giving check to the side to move
+    pub fn checkers(&self) -> u64 {
+        let king_piece = if self.white_turn {
+            self.pieces[5]
+        } else {
+            self.pieces[11]
+        };
+        if king_piece == 0 {
+            return 0;
+        }
+        self.attackers_of(king_piece.trailing_zeros() as u8, !self.white_turn)
+    }
+
     // returns the index of the piece occupying the desired position
-    fn get_piece(&self, position: &Position) -> Option<u8> {
+    pub fn get_piece(&self, position: &Position) -> Option<u8> {
         let pos_idx = Chess::position_to_index(position);
         if let Some(found) = self
             .pieces
@@ -277,17 +412,17 @@ impl Chess {
         {
             return Some(found.0 as u8);
         }
-        return None;
+        None
     }
 
     fn get_positions(piece: u64) -> Vec<Position> {
         let mut all_positions = Vec::new();
-        for i in 0..(64 as u64) {
+        for i in 0..64_u64 {
             if (1 << i) & piece > 0 {
                 all_positions.push(Chess::index_to_position(i as u8))
             }
         }
-        return all_positions;
+        all_positions
     }
 
     pub fn king_saving_move(&self, piece_idx: u8, position: &Position) -> u64 {
@@ -302,10 +437,10 @@ impl Chess {
             new_chess.dry_move(position, &new_position);
             if !new_chess.is_in_check() {
                 let idx = Chess::position_to_index(&new_position);
-                saving_moves = saving_moves | (1 << idx);
+                saving_moves |= 1 << idx;
             }
         }
-        return saving_moves;
+        saving_moves
     }
 
     pub fn get_possible_moves(&self, position: &Position) -> u64 {
@@ -316,6 +451,9 @@ impl Chess {
             }
             let mut location =
                 self.get_possible_moves_by_piece_idx(piece_idx, position, self.white_turn);
+            if piece_idx == 5 || piece_idx == 11 {
+                location |= self.possible_castling_moves(position);
+            }
             // prune all the move which causes check when applied
             // Check if the move is causing further check
             let new_positions = Chess::get_positions(location);
@@ -324,7 +462,7 @@ impl Chess {
                 new_chess.dry_move(position, new_position);
                 if new_chess.is_in_check() {
                     let prune_idx = Chess::position_to_index(new_position);
-                    location = location & !(1 << prune_idx);
+                    location &= !(1 << prune_idx);
                 }
             }
             return location;
@@ -376,10 +514,10 @@ impl Chess {
             if n & 1 > 0 {
                 indices.push(counter)
             }
-            n = n >> 1;
-            counter = counter + 1;
+            n >>= 1;
+            counter += 1;
         }
-        return indices;
+        indices
     }
 
     pub fn contains_piece(&self, pos: &Position) -> bool {
@@ -404,17 +542,17 @@ impl Chess {
 
                 (from, moves)
             })
-            .filter(|vm| vm.1.len() > 0)
+            .filter(|vm| !vm.1.is_empty())
             .collect::<Vec<(Position, Vec<Position>)>>();
         for vm in all_possible.iter() {
             let from = vm.0.clone();
             for to in vm.1.iter() {
                 let mut new_chess = self.clone();
-                new_chess.move_piece(&from, &to);
+                new_chess.move_piece(&from, to);
                 all_pos.push(new_chess);
             }
         }
-        return all_pos;
+        all_pos
     }
 
     pub fn dry_move(&mut self, from: &Position, to: &Position) -> bool {
@@ -430,8 +568,16 @@ impl Chess {
                 }
 
                 // if any piece is in destination, delete the piece
-                self.pieces[to_piece_idx as usize] =
-                    self.pieces[to_piece_idx as usize] & !(1 << to_idx);
+                self.pieces[to_piece_idx as usize] &= !(1 << to_idx);
+            }
+            // a pawn moving diagonally onto the en passant square captures the
+            // pawn beside it
+            if (from_piece_idx == 0 || from_piece_idx == 6)
+                && from.0 != to.0
+                && self.en_passant == Some(to_idx as u8)
+            {
+                let captured_idx = Chess::position_to_index(&Position(to.0, from.1));
+                self.pieces[6 - from_piece_idx as usize] &= !(1 << captured_idx);
             }
             // move current piece to destination
             self.pieces[from_piece_idx as usize] =
@@ -443,70 +589,156 @@ impl Chess {
     }
 
     pub fn move_piece(&mut self, from: &Position, to: &Position) -> bool {
-        // println!("Moving {:?} -> {:?}", from, to);
+        self.apply_move(from, to, None).is_some()
+    }
+
+    // Applies the move if it is legal and reports its outcome, including the
+    // check and game over state it leads to.
+    pub fn make_move_with_promotion(
+        &mut self,
+        from: &Position,
+        to: &Position,
+        promotion: Option<u8>,
+    ) -> Option<MoveOutcome> {
+        let mut outcome = self.apply_move(from, to, promotion)?;
+        // Check if move created any check
+        outcome.check = self.is_in_check();
+        let no_moves_left = self.is_checkmate();
+        outcome.checkmate = outcome.check && no_moves_left;
+        outcome.stalemate = !outcome.check && no_moves_left;
+        Some(outcome)
+    }
+
+    pub fn play(&mut self, mv: &Move) -> Option<MoveOutcome> {
+        self.make_move_with_promotion(
+            &Chess::index_to_position(mv.from),
+            &Chess::index_to_position(mv.to),
+            mv.promotion,
+        )
+    }
 
+    // Every legal move of the side to move, with each promotion choice
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let pawns = self.pieces[if self.white_turn { 0 } else { 6 }];
+        for from in Chess::indices_of_set_bits(self.get_color_pieces(self.white_turn)) {
+            let position = Chess::index_to_position(from);
+            for to in Chess::indices_of_set_bits(self.get_possible_moves(&position)) {
+                if pawns & (1 << from) > 0 && (to / 8 == 0 || to / 8 == 7) {
+                    let queen = if self.white_turn { 4 } else { 10 };
+                    for promotion in [queen, queen - 3, queen - 1, queen - 2] {
+                        moves.push(Move {
+                            from,
+                            to,
+                            promotion: Some(promotion),
+                        });
+                    }
+                } else {
+                    moves.push(Move {
+                        from,
+                        to,
+                        promotion: None,
+                    });
+                }
+            }
+        }
+        moves
+    }
+
+    fn apply_move(
+        &mut self,
+        from: &Position,
+        to: &Position,
+        promotion: Option<u8>,
+    ) -> Option<MoveOutcome> {
         if !self.is_move_valid(from, to) {
-            return false;
+            return None;
         }
         // Check if the move is causing further check
         let mut new_chess = self.clone();
         new_chess.dry_move(from, to);
         if new_chess.is_in_check() {
-            return false;
+            return None;
         }
 
-        let move_successful = self.dry_move(from, to);
-        // Check if move created any check
-        if move_successful {
-            self.white_turn = !self.white_turn;
-            self.last_move = Some(LastMove(
-                Chess::position_to_index(from),
-                Chess::position_to_index(to),
-            ));
+        let to_idx = Chess::position_to_index(to);
+        let pawn_piece = if self.white_turn { 0 } else { 6 };
+        let is_pawn = self.pieces[pawn_piece] & (1 << Chess::position_to_index(from)) > 0;
+        let mut outcome = MoveOutcome {
+            captured: self.get_piece(to),
+            capture_square: self.get_piece(to).map(|_| to_idx),
+            ..Default::default()
+        };
+        if is_pawn && from.0 != to.0 && outcome.captured.is_none() {
+            let captured_idx = Chess::position_to_index(&Position(to.0, from.1));
+            outcome.captured = Some(6 - pawn_piece as u8);
+            outcome.capture_square = Some(captured_idx);
         }
-        return move_successful;
-    }
-
-    pub fn get_piece_indices(for_piece: bool) -> [u8; 6] {
-        if for_piece {
-            return [0, 1, 2, 3, 4, 5];
+        let rook_move = self.castling_rook_move(from, to);
+        if !self.dry_move(from, to) {
+            return None;
         }
-        return [6, 7, 8, 9, 10, 11];
-    }
-
-    pub fn get_king_position_idx(&self) -> u8 {
-        if self.white_turn {
-            5
+        if let Some(LastMove(rook_from, rook_to)) = rook_move {
+            let rook_piece = if self.white_turn { 1 } else { 7 };
+            self.pieces[rook_piece] = self.pieces[rook_piece] & !(1 << rook_from) | (1 << rook_to);
+            outcome.castled = true;
+        }
+        // Pawns reaching the last rank are promoted, to a queen unless told
+        // otherwise
+        if is_pawn && (to.1 == 8 || to.1 == 1) {
+            let promoted = promotion
+                .filter(|piece| (pawn_piece + 1..pawn_piece + 5).contains(&(*piece as usize)))
+                .unwrap_or(pawn_piece as u8 + 4);
+            self.pieces[pawn_piece] &= !(1 << to_idx);
+            self.pieces[promoted as usize] |= 1 << to_idx;
+            outcome.promoted = Some(promoted);
+        }
+        // A double pawn push allows an en passant capture on the skipped square
+        self.en_passant = if is_pawn && from.1.abs_diff(to.1) == 2 {
+            Some((Chess::position_to_index(from) + to_idx) / 2)
         } else {
-            11
+            None
+        };
+        self.update_castling_rights(from, to);
+        if is_pawn || outcome.captured.is_some() {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+        if !self.white_turn {
+            self.fullmove_number += 1;
         }
+        self.white_turn = !self.white_turn;
+        self.last_move = Some(LastMove(Chess::position_to_index(from), to_idx));
+        Some(outcome)
     }
 
-    pub fn opponent_all_valid_moves(&self) -> u64 {
-        let indices = Chess::get_piece_indices(!self.white_turn);
+    // Moving the king or a rook, or capturing a rook on its home square,
+    // forfeits the matching castling rights.
+    fn update_castling_rights(&mut self, from: &Position, to: &Position) {
+        for idx in [Chess::position_to_index(from), Chess::position_to_index(to)] {
+            self.castling_rights &= match idx {
+                0 => !WHITE_QUEEN_SIDE,
+                4 => !(WHITE_KING_SIDE | WHITE_QUEEN_SIDE),
+                7 => !WHITE_KING_SIDE,
+                56 => !BLACK_QUEEN_SIDE,
+                60 => !(BLACK_KING_SIDE | BLACK_QUEEN_SIDE),
+                63 => !BLACK_KING_SIDE,
+                _ => !0,
+            };
+        }
+    }
 
-        let mut valid_moves = 0 as u64;
-        for i in indices {
-            // check if king is attacked by opponent pieces
-            let positions = Chess::get_positions(self.pieces[i as usize]);
-            for position in positions.iter() {
-                let vm = self.get_possible_moves_by_piece_idx(i, position, !self.white_turn);
-                valid_moves = valid_moves | vm;
-            }
+    pub fn get_piece_indices(for_piece: bool) -> [u8; 6] {
+        if for_piece {
+            return [0, 1, 2, 3, 4, 5];
         }
-        return valid_moves;
+        [6, 7, 8, 9, 10, 11]
     }
 
     pub fn is_in_check(&self) -> bool {
-        let king_piece = if self.white_turn {
-            self.pieces[5]
-        } else {
-            self.pieces[11]
-        };
         // Check if king is being attacked by any other opponent piece
-        //
-        let opponent_valid_moves = self.opponent_all_valid_moves();
-        (king_piece & opponent_valid_moves) > 0
+        self.checkers() > 0
     }
 
     pub fn is_checkmate(&self) -> bool {
@@ -521,7 +753,7 @@ impl Chess {
                 }
             }
         }
-        return true;
+        true
     }
 
     pub fn is_valid_selection(&self, position: &Position) -> bool {
@@ -0,0 +1,111 @@
+// Reading and writing positions in Forsyth-Edwards Notation
+use super::chess::*;
+use super::notation::{parse_square, square_name};
+
+// Letters of the pieces in `Chess::pieces` order, white's in upper case
+const FEN_PIECES: &str = "PRNBQKprnbqk";
+const FEN_CASTLING: [(char, u8); 4] = [
+    ('K', WHITE_KING_SIDE),
+    ('Q', WHITE_QUEEN_SIDE),
+    ('k', BLACK_KING_SIDE),
+    ('q', BLACK_QUEEN_SIDE),
+];
+
+impl Chess {
+    // Parses a FEN string, the move counters being optional
+    pub fn from_fen(fen: &str) -> Option<Chess> {
+        let mut fields = fen.split_whitespace();
+        let mut pieces = [0u64; 12];
+        let ranks = fields.next()?.split('/').collect::<Vec<&str>>();
+        if ranks.len() != 8 {
+            return None;
+        }
+        // Ranks are listed from the 8th down to the 1st
+        for (row, rank) in ranks.iter().enumerate() {
+            let rank_start = (7 - row as u8) * 8;
+            let mut file = 0u8;
+            for c in rank.chars() {
+                if let Some(empty) = c.to_digit(10) {
+                    file += empty as u8;
+                } else {
+                    let piece = FEN_PIECES.find(c)?;
+                    if file >= 8 {
+                        return None;
+                    }
+                    pieces[piece] |= 1 << (rank_start + file);
+                    file += 1;
+                }
+            }
+            if file != 8 {
+                return None;
+            }
+        }
+        let white_turn = match fields.next()? {
+            "w" => true,
+            "b" => false,
+            _ => return None,
+        };
+        let mut castling_rights = 0;
+        let castling = fields.next()?;
+        if castling != "-" {
+            for c in castling.chars() {
+                castling_rights |= FEN_CASTLING.iter().find(|(flag, _)| *flag == c)?.1;
+            }
+        }
+        let en_passant = match fields.next()? {
+            "-" => None,
+            square => Some(parse_square(square)?),
+        };
+        let halfmove_clock = fields.next().map_or(Some(0), |n| n.parse().ok())?;
+        let fullmove_number = fields.next().map_or(Some(1), |n| n.parse().ok())?;
+
+        Some(Chess {
+            pieces,
+            white_turn,
+            last_move: None,
+            castling_rights,
+            en_passant,
+            halfmove_clock,
+            fullmove_number,
+        })
+    }
+
+    pub fn to_fen(&self) -> String {
+        let mut ranks = Vec::new();
+        for rank in (0..8u8).rev() {
+            let mut row = String::new();
+            let mut empty = 0;
+            for file in 0..8u8 {
+                let idx = rank * 8 + file;
+                match self.pieces.iter().position(|p| p & (1 << idx) > 0) {
+                    Some(piece) => {
+                        if empty > 0 {
+                            row.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        row.push(FEN_PIECES.as_bytes()[piece] as char);
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty > 0 {
+                row.push_str(&empty.to_string());
+            }
+            ranks.push(row);
+        }
+        let castling = FEN_CASTLING
+            .iter()
+            .filter(|(_, right)| self.castling_rights & right > 0)
+            .map(|(flag, _)| *flag)
+            .collect::<String>();
+        format!(
+            "{} {} {} {} {} {}",
+            ranks.join("/"),
+            if self.white_turn { "w" } else { "b" },
+            if castling.is_empty() { "-" } else { &castling },
+            self.en_passant.map_or("-".to_string(), square_name),
+            self.halfmove_clock,
+            self.fullmove_number
+        )
+    }
+}
@@ -1,3 +1,10 @@
-pub mod constants;
 pub mod chess;
+pub mod constants;
+pub mod fen;
+pub mod notation;
+pub mod pgn;
+pub mod puzzle;
+pub mod setup;
+#[cfg(feature = "bevy")]
 pub mod system;
+pub mod zobrist;
@@ -0,0 +1,147 @@
+// Reading and writing moves in UCI (`e2e4`, `e7e8q`) and standard algebraic
+// notation (`Nf3`, `exd5`, `O-O`, `e8=Q+`).
+use super::chess::*;
+
+const FILES: &str = "abcdefgh";
+// Letters of the pieces by their index into `Chess::pieces`, modulo 6
+const PIECE_LETTERS: [char; 6] = ['P', 'R', 'N', 'B', 'Q', 'K'];
+
+pub fn square_name(idx: u8) -> String {
+    let position = Chess::index_to_position(idx);
+    format!(
+        "{}{}",
+        FILES.as_bytes()[position.0 as usize - 1] as char,
+        position.1
+    )
+}
+
+pub fn parse_square(name: &str) -> Option<u8> {
+    let mut chars = name.chars();
+    let file = FILES.find(chars.next()?)? as u8 + 1;
+    let rank = chars.next()?.to_digit(10)? as u8;
+    if chars.next().is_some() || !(1..=8).contains(&rank) {
+        return None;
+    }
+    Some(Chess::position_to_index(&Position(file, rank)))
+}
+
+impl Move {
+    pub fn to_uci(self) -> String {
+        let mut uci = format!("{}{}", square_name(self.from), square_name(self.to));
+        if let Some(promotion) = self.promotion {
+            uci.push(PIECE_LETTERS[promotion as usize % 6].to_ascii_lowercase());
+        }
+        uci
+    }
+}
+
+impl Chess {
+    // Parses a move in UCI notation, returning it only if it is legal
+    pub fn parse_uci(&self, uci: &str) -> Option<Move> {
+        let from = parse_square(uci.get(0..2)?)?;
+        let to = parse_square(uci.get(2..4)?)?;
+        let promotion = match uci.get(4..) {
+            None | Some("") => None,
+            Some(letter) => {
+                let kind = PIECE_LETTERS
+                    .iter()
+                    .position(|piece| piece.to_ascii_lowercase().to_string() == letter)?;
+                Some(kind as u8 + if self.white_turn { 0 } else { 6 })
+            }
+        };
+        self.legal_moves().into_iter().find(|mv| {
+            mv.from == from
+                && mv.to == to
+                && (mv.promotion == promotion || (promotion.is_none() && mv.promotion.is_some()))
+        })
+    }
+
+    // Parses a move in standard algebraic notation. Check markers, the
+    // capture sign and the promotion `=` are optional.
+    pub fn parse_san(&self, san: &str) -> Option<Move> {
+        let normalize = |text: &str| {
+            text.replace('0', "O")
+                .chars()
+                .filter(|c| !matches!(c, '+' | '#' | '!' | '?' | 'x' | '=' | ' '))
+                .collect::<String>()
+        };
+        let san = normalize(san);
+        if san.is_empty() {
+            return None;
+        }
+        self.legal_moves()
+            .into_iter()
+            .find(|mv| normalize(&self.san_without_suffix(mv)) == san)
+    }
+
+    // Accepts either notation
+    pub fn parse_move(&self, text: &str) -> Option<Move> {
+        self.parse_uci(text.trim())
+            .or_else(|| self.parse_san(text.trim()))
+    }
+
+    pub fn to_san(&self, mv: &Move) -> String {
+        let mut san = self.san_without_suffix(mv);
+        let mut after = self.clone();
+        if let Some(outcome) = after.play(mv) {
+            if outcome.checkmate {
+                san.push('#');
+            } else if outcome.check {
+                san.push('+');
+            }
+        }
+        san
+    }
+
+    fn san_without_suffix(&self, mv: &Move) -> String {
+        let from = Chess::index_to_position(mv.from);
+        let to = Chess::index_to_position(mv.to);
+        let Some(piece) = self.get_piece(&from) else {
+            return mv.to_uci();
+        };
+        let kind = piece as usize % 6;
+        if kind == 5 && from.0.abs_diff(to.0) == 2 {
+            return if to.0 > from.0 { "O-O" } else { "O-O-O" }.to_string();
+        }
+        let is_capture = self.contains_piece(&to) || (kind == 0 && from.0 != to.0);
+        let mut san = String::new();
+        if kind == 0 {
+            if is_capture {
+                san.push(FILES.as_bytes()[from.0 as usize - 1] as char);
+            }
+        } else {
+            san.push(PIECE_LETTERS[kind]);
+            // Disambiguate between pieces of the same kind reaching the square
+            let others = self
+                .legal_moves()
+                .into_iter()
+                .filter(|other| {
+                    other.to == mv.to
+                        && other.from != mv.from
+                        && self.get_piece(&Chess::index_to_position(other.from)) == Some(piece)
+                })
+                .map(|other| Chess::index_to_position(other.from))
+                .collect::<Vec<_>>();
+            if !others.is_empty() {
+                let name = square_name(mv.from);
+                if others.iter().all(|other| other.0 != from.0) {
+                    san.push_str(&name[0..1]);
+                } else if others.iter().all(|other| other.1 != from.1) {
+                    san.push_str(&name[1..2]);
+                } else {
+                    san.push_str(&name);
+                }
+            }
+        }
+        if is_capture {
+            san.push('x');
+        }
+        san.push_str(&square_name(mv.to));
+        if kind == 0 && (to.1 == 8 || to.1 == 1) {
+            let promotion = mv.promotion.unwrap_or(4) as usize % 6;
+            san.push('=');
+            san.push(PIECE_LETTERS[promotion]);
+        }
+        san
+    }
+}
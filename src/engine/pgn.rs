@@ -0,0 +1,268 @@
+// Reading and writing games in Portable Game Notation
+use std::collections::BTreeMap;
+
+use super::chess::{Chess, Move};
+
+const RESULTS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+// Headers `PgnGame::matches` looks for its text in
+const SEARCHED_HEADERS: [&str; 6] = ["White", "Black", "Event", "Opening", "Variation", "ECO"];
+
+#[derive(Clone, Debug, Default)]
+pub struct PgnGame {
+    pub headers: Vec<(String, String)>,
+    // Moves in standard algebraic notation
+    pub moves: Vec<String>,
+    // Comments keyed by the number of moves played before them, so the
+    // comment at 0 comes before the first move
+    pub comments: BTreeMap<usize, String>,
+    // `1-0`, `0-1`, `1/2-1/2` or `*` for a game in progress
+    pub result: String,
+}
+
+impl PgnGame {
+    // Reads every game in a PGN file. Variations, NAGs and move numbers are
+    // skipped, so only the main line is kept, with its comments.
+    pub fn parse_all(text: &str) -> Vec<PgnGame> {
+        let mut games = Vec::new();
+        let mut game = PgnGame::default();
+        // Whether the current game's movetext has started
+        let mut in_moves = false;
+        let mut variation_depth = 0u32;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' => {
+                    let comment = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+                    if variation_depth == 0 {
+                        game.add_comment(comment.trim());
+                    }
+                }
+                ';' => {
+                    let comment = chars
+                        .by_ref()
+                        .take_while(|c| *c != '\n')
+                        .collect::<String>();
+                    if variation_depth == 0 {
+                        game.add_comment(comment.trim());
+                    }
+                }
+                '(' => variation_depth += 1,
+                ')' => variation_depth = variation_depth.saturating_sub(1),
+                '[' if variation_depth == 0 => {
+                    if in_moves {
+                        games.push(std::mem::take(&mut game).finished());
+                        in_moves = false;
+                    }
+                    let tag = read_tag(&mut chars);
+                    if let Some((key, value)) = tag {
+                        game.headers.push((key, value));
+                    }
+                }
+                c if c.is_whitespace() => {}
+                c => {
+                    let mut token = c.to_string();
+                    while let Some(next) = chars.next_if(|next| {
+                        !next.is_whitespace() && !matches!(next, '{' | '(' | ')' | ';' | '[')
+                    }) {
+                        token.push(next);
+                    }
+                    in_moves = true;
+                    if variation_depth > 0 || token.starts_with('$') {
+                        continue;
+                    }
+                    if RESULTS.contains(&token.as_str()) {
+                        game.result = token;
+                        continue;
+                    }
+                    // Move numbers, also when written against the move. Castling
+                    // written with zeros has no dot, so it is left alone.
+                    let san = match token.split_once('.') {
+                        Some((number, rest))
+                            if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) =>
+                        {
+                            rest.trim_start_matches('.')
+                        }
+                        _ => token.as_str(),
+                    };
+                    if !san.is_empty() {
+                        game.moves.push(san.to_string());
+                    }
+                }
+            }
+        }
+        if in_moves || !game.headers.is_empty() {
+            games.push(game.finished());
+        }
+        games
+    }
+
+    fn add_comment(&mut self, comment: &str) {
+        if comment.is_empty() {
+            return;
+        }
+        self.comments
+            .entry(self.moves.len())
+            .and_modify(|existing| {
+                existing.push(' ');
+                existing.push_str(comment);
+            })
+            .or_insert_with(|| comment.to_string());
+    }
+
+    // Takes the result from the header when the movetext doesn't end in one
+    fn finished(mut self) -> PgnGame {
+        if self.result.is_empty() {
+            self.result = self.header("Result").unwrap_or("*").to_string();
+        }
+        self
+    }
+
+    // Whether the game has the result asked for and, unless `text` is empty,
+    // players, an event or an opening containing it, ignoring case
+    pub fn matches(&self, text: &str, result: ResultFilter) -> bool {
+        let text = text.to_lowercase();
+        result.matches(&self.result)
+            && (text.is_empty()
+                || SEARCHED_HEADERS.iter().any(|key| {
+                    self.header(key)
+                        .is_some_and(|value| value.to_lowercase().contains(&text))
+                }))
+    }
+
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // The position the game starts from: the standard one, unless set up
+    // with a FEN header
+    pub fn start_position(&self) -> Option<Chess> {
+        match self.header("FEN") {
+            Some(fen) => Chess::from_fen(fen),
+            None => Some(Chess::new()),
+        }
+    }
+
+    // Plays the game out, stopping at the first illegal move. Returns every
+    // position reached, one more than the moves, and the moves.
+    pub fn replay(&self) -> Option<(Vec<Chess>, Vec<Move>)> {
+        let mut chess = self.start_position()?;
+        let mut positions = vec![chess.clone()];
+        let mut moves = Vec::new();
+        for san in &self.moves {
+            let Some(mv) = chess.parse_san(san) else {
+                break;
+            };
+            chess.play(&mv);
+            positions.push(chess.clone());
+            moves.push(mv);
+        }
+        Some((positions, moves))
+    }
+
+    pub fn to_pgn(&self) -> String {
+        let mut pgn = String::new();
+        for (key, value) in &self.headers {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            pgn.push_str(&format!("[{key} \"{value}\"]\n"));
+        }
+        if !self.headers.iter().any(|(key, _)| key == "Result") {
+            pgn.push_str(&format!("[Result \"{}\"]\n", self.result));
+        }
+        pgn.push('\n');
+
+        let mut tokens = Vec::new();
+        if let Some(comment) = self.comments.get(&0) {
+            tokens.push(format!("{{ {comment} }}"));
+        }
+        for (idx, san) in self.moves.iter().enumerate() {
+            if idx % 2 == 0 {
+                tokens.push(format!("{}.", idx / 2 + 1));
+            } else if self.comments.contains_key(&idx) {
+                // Black's move needs its number again after a comment
+                tokens.push(format!("{}...", idx / 2 + 1));
+            }
+            tokens.push(san.clone());
+            if let Some(comment) = self.comments.get(&(idx + 1)) {
+                tokens.push(format!("{{ {comment} }}"));
+            }
+        }
+        tokens.push(self.result.clone());
+
+        // Wrap movetext lines at 80 columns
+        let mut line_len = 0;
+        for token in tokens {
+            if line_len > 0 && line_len + token.len() + 1 > 80 {
+                pgn.push('\n');
+                line_len = 0;
+            } else if line_len > 0 {
+                pgn.push(' ');
+                line_len += 1;
+            }
+            line_len += token.len();
+            pgn.push_str(&token);
+        }
+        pgn.push('\n');
+        pgn
+    }
+}
+
+// Reads a `[Key "Value"]` tag after its opening bracket
+fn read_tag(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<(String, String)> {
+    let mut key = String::new();
+    let mut value = String::new();
+    let mut in_value = false;
+    while let Some(c) = chars.next() {
+        match c {
+            ']' if !in_value => break,
+            '"' => in_value = !in_value,
+            '\\' if in_value => value.extend(chars.next()),
+            c if in_value => value.push(c),
+            c if !c.is_whitespace() => key.push(c),
+            _ => {}
+        }
+    }
+    (!key.is_empty()).then_some((key, value))
+}
+
+// The results a game list is narrowed down to
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResultFilter {
+    #[default]
+    All,
+    WhiteWins,
+    BlackWins,
+    Draws,
+}
+
+impl ResultFilter {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResultFilter::All => "Any result",
+            ResultFilter::WhiteWins => "White won",
+            ResultFilter::BlackWins => "Black won",
+            ResultFilter::Draws => "Draws",
+        }
+    }
+
+    pub fn next(&self) -> ResultFilter {
+        match self {
+            ResultFilter::All => ResultFilter::WhiteWins,
+            ResultFilter::WhiteWins => ResultFilter::BlackWins,
+            ResultFilter::BlackWins => ResultFilter::Draws,
+            ResultFilter::Draws => ResultFilter::All,
+        }
+    }
+
+    pub fn matches(&self, result: &str) -> bool {
+        match self {
+            ResultFilter::All => true,
+            ResultFilter::WhiteWins => result == "1-0",
+            ResultFilter::BlackWins => result == "0-1",
+            ResultFilter::Draws => result == "1/2-1/2",
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// Tactics puzzles in the format of the Lichess puzzle database, one per CSV
+// line: `PuzzleId,FEN,Moves,Rating,RatingDeviation,Popularity,NbPlays,
+// Themes,GameUrl,OpeningTags`. The FEN is the position before the opponent's
+// move that sets the puzzle, the first of `Moves`, and the solver plays every
+// other move from the second on.
+use super::chess::{Chess, Move};
+
+// How far one puzzle moves the solver's rating, as Elo's K factor
+const RATING_K: f32 = 32.;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub id: String,
+    pub fen: String,
+    // The solution in UCI notation, starting with the opponent's move
+    pub moves: Vec<String>,
+    pub rating: u32,
+    pub themes: Vec<String>,
+}
+
+// How a move the solver played was judged
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Attempt {
+    // The solution's move, with more of the solution to come
+    Correct,
+    // The solution's last move, or any move that mates
+    Solved,
+    Wrong,
+}
+
+impl Puzzle {
+    // Parses a line of the CSV, None for the header and malformed lines
+    pub fn from_csv_line(line: &str) -> Option<Puzzle> {
+        let fields = line.trim().split(',').collect::<Vec<_>>();
+        let moves = fields
+            .get(2)?
+            .split_whitespace()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        if moves.len() < 2 {
+            return None;
+        }
+        Some(Puzzle {
+            id: fields.first()?.to_string(),
+            fen: fields.get(1)?.to_string(),
+            moves,
+            rating: fields.get(3)?.parse().ok()?,
+            themes: fields.get(7).map_or(Vec::new(), |themes| {
+                themes.split_whitespace().map(str::to_string).collect()
+            }),
+        })
+    }
+
+    pub fn parse_csv(text: &str) -> Vec<Puzzle> {
+        text.lines().filter_map(Puzzle::from_csv_line).collect()
+    }
+
+    // The few puzzles shipped in `assets/puzzles.csv`
+    pub fn bundled() -> Vec<Puzzle> {
+        Puzzle::parse_csv(include_str!("../../assets/puzzles.csv"))
+    }
+
+    // Whether the solver is White, who moves after the opponent's first move
+    pub fn solver_is_white(&self) -> bool {
+        self.fen.split_whitespace().nth(1) != Some("w")
+    }
+
+    // Judges `played` from `chess`, reached after the first `ply` moves of
+    // the solution. When the solution doesn't say what a pawn promotes to,
+    // any promotion matches, as in `Chess::parse_uci`.
+    pub fn attempt(&self, chess: &Chess, ply: usize, played: &Move) -> Attempt {
+        let mut after = chess.clone();
+        let Some(outcome) = after.play(played) else {
+            return Attempt::Wrong;
+        };
+        if outcome.checkmate {
+            return Attempt::Solved;
+        }
+        let Some((uci, expected)) = self
+            .moves
+            .get(ply)
+            .and_then(|uci| Some((uci, chess.parse_uci(uci)?)))
+        else {
+            return Attempt::Wrong;
+        };
+        if (played.from, played.to) != (expected.from, expected.to)
+            || (uci.len() > 4 && played.promotion != expected.promotion)
+        {
+            Attempt::Wrong
+        } else if ply + 1 >= self.moves.len() {
+            Attempt::Solved
+        } else {
+            Attempt::Correct
+        }
+    }
+}
+
+// The solver's rating after a puzzle rated `puzzle`, on the Elo scale
+pub fn update_rating(rating: i32, puzzle: u32, solved: bool) -> i32 {
+    let expected = 1. / (1. + 10f32.powf((puzzle as f32 - rating as f32) / 400.));
+    let score = if solved { 1. } else { 0. };
+    rating + (RATING_K * (score - expected)).round() as i32
+}
@@ -0,0 +1,128 @@
+// Positions set up by hand: placing and removing pieces, the castling rights
+// and en passant squares the placement allows, and the checks a position has
+// to pass before it can be played from
+use super::chess::*;
+
+// The first and eighth ranks, where no pawn can stand
+const BACK_RANKS: u64 = 0xFF | 0xFF << 56;
+
+// The king's and rook's squares for each castling right
+const CASTLING_SQUARES: [(u8, u8, u8); 4] = [
+    (4, 7, WHITE_KING_SIDE),
+    (4, 0, WHITE_QUEEN_SIDE),
+    (60, 63, BLACK_KING_SIDE),
+    (60, 56, BLACK_QUEEN_SIDE),
+];
+
+// Why a position set up by hand can't be played from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetupError {
+    // The side doesn't have exactly one king
+    KingCount { white: bool },
+    PawnOnBackRank,
+    // The side not to move is in check, so its king could be taken
+    OpponentInCheck,
+}
+
+impl SetupError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            SetupError::KingCount { white: true } => "White needs exactly one king",
+            SetupError::KingCount { white: false } => "Black needs exactly one king",
+            SetupError::PawnOnBackRank => "No pawns on the first or last rank",
+            SetupError::OpponentInCheck => "The side not to move is in check",
+        }
+    }
+}
+
+impl Chess {
+    // A board without pieces, White to move
+    pub fn empty() -> Chess {
+        Chess {
+            pieces: [0; 12],
+            castling_rights: 0,
+            ..Chess::new()
+        }
+    }
+
+    // Puts `piece`, an index into `Chess::pieces`, on the square at `idx`, or
+    // clears the square with None. Castling rights and the en passant square
+    // the change rules out are dropped.
+    pub fn set_piece(&mut self, idx: u8, piece: Option<u8>) {
+        for pieces in self.pieces.iter_mut() {
+            *pieces &= !(1 << idx);
+        }
+        if let Some(piece) = piece {
+            self.pieces[piece as usize] |= 1 << idx;
+        }
+        self.last_move = None;
+        self.castling_rights &= self.possible_castling_rights();
+        if self
+            .en_passant
+            .is_some_and(|square| !self.en_passant_squares().contains(&square))
+        {
+            self.en_passant = None;
+        }
+    }
+
+    // Gives the move to `white`, which rules out capturing en passant
+    pub fn set_turn(&mut self, white: bool) {
+        self.white_turn = white;
+        self.en_passant = None;
+    }
+
+    // Castling rights the placement allows, those whose king and rook are on
+    // their starting squares
+    pub fn possible_castling_rights(&self) -> u8 {
+        CASTLING_SQUARES
+            .iter()
+            .filter(|(king, rook, right)| {
+                let offset = if right & (WHITE_KING_SIDE | WHITE_QUEEN_SIDE) > 0 {
+                    0
+                } else {
+                    6
+                };
+                self.pieces[offset + 5] & 1 << king > 0 && self.pieces[offset + 1] & 1 << rook > 0
+            })
+            .fold(0, |rights, (_, _, right)| rights | right)
+    }
+
+    // Squares the side to move could capture onto en passant: those an
+    // opponent pawn could have just crossed with a double step
+    pub fn en_passant_squares(&self) -> Vec<u8> {
+        let occupied = self.pieces.iter().fold(0, |all, pieces| all | pieces);
+        let (squares, pawns) = if self.white_turn {
+            (40u8..48, self.pieces[6])
+        } else {
+            (16u8..24, self.pieces[0])
+        };
+        squares
+            .filter(|idx| {
+                let (pawn, start) = if self.white_turn {
+                    (idx - 8, idx + 8)
+                } else {
+                    (idx + 8, idx - 8)
+                };
+                pawns & 1 << pawn > 0 && occupied & (1 << idx | 1 << start) == 0
+            })
+            .collect()
+    }
+
+    // Whether the position can be played from, with the first problem found
+    // when it can't
+    pub fn validate_setup(&self) -> Result<(), SetupError> {
+        for (white, king) in [(true, 5), (false, 11)] {
+            if self.pieces[king].count_ones() != 1 {
+                return Err(SetupError::KingCount { white });
+            }
+        }
+        if (self.pieces[0] | self.pieces[6]) & BACK_RANKS > 0 {
+            return Err(SetupError::PawnOnBackRank);
+        }
+        let king = self.pieces[if self.white_turn { 11 } else { 5 }].trailing_zeros() as u8;
+        if self.is_square_attacked(king, self.white_turn) {
+            return Err(SetupError::OpponentInCheck);
+        }
+        Ok(())
+    }
+}
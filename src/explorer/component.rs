@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use chess::search::explorer::OpeningIndex;
+
+use crate::engine::chess::Move;
+
+// Opening tree of the database indexed into the file in CHESS_EXPLORER, empty
+// when it isn't set
+#[derive(Resource, Debug, Default)]
+pub struct Explorer(pub OpeningIndex);
+
+impl Explorer {
+    pub fn from_env() -> Explorer {
+        let Ok(path) = std::env::var("CHESS_EXPLORER") else {
+            return Explorer::default();
+        };
+        match std::fs::read(&path) {
+            Ok(bytes) => {
+                let index = OpeningIndex::from_bytes(&bytes);
+                info!("Opening explorer with {} moves from {path}", index.len());
+                Explorer(index)
+            }
+            Err(err) => {
+                warn!("Can't read the opening explorer from {path}: {err}");
+                Explorer::default()
+            }
+        }
+    }
+}
+
+// The explorer's moves, listed for the position with `hash`
+#[derive(Component, Debug, Default)]
+pub struct ExplorerList {
+    pub hash: Option<u64>,
+}
+
+// Plays its move on the board when clicked
+#[derive(Component, Debug)]
+pub struct ExplorerMoveButton {
+    pub mv: Move,
+}
+
+// How many games reached the position
+#[derive(Component, Debug)]
+pub struct ExplorerSummaryText;
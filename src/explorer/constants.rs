@@ -0,0 +1,21 @@
+use bevy::color::*;
+
+// Moves listed, the most played first
+pub const EXPLORER_MOVES: usize = 8;
+pub const ROW_HEIGHT: f32 = 22.;
+pub const SAN_WIDTH: f32 = 56.;
+pub const GAMES_WIDTH: f32 = 64.;
+pub const BAR_WIDTH: f32 = 168.;
+// Segments of the results bar, for white wins, draws and black wins
+pub const RESULT_COLORS: [Srgba; 3] = [
+    Srgba::new(0.93, 0.93, 0.93, 1.),
+    Srgba::new(0.55, 0.55, 0.58, 1.),
+    Srgba::new(0.15, 0.15, 0.17, 1.),
+];
+pub const RESULT_TEXT_COLORS: [Srgba; 3] = [
+    Srgba::new(0.1, 0.1, 0.1, 1.),
+    Srgba::new(0.1, 0.1, 0.1, 1.),
+    Srgba::new(0.93, 0.93, 0.93, 1.),
+];
+// Narrower segments leave their percentage out
+pub const MIN_LABELLED_PERCENT: f32 = 12.;
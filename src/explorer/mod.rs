@@ -0,0 +1,37 @@
+mod component;
+mod constants;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::component::GameState;
+use crate::chessboard::system::{animations_idle, handle_board_event, handle_game_command};
+use component::*;
+use system::*;
+
+// Opening explorer on the analysis board: the moves played from the position
+// on the board in a local game database, with how their games ended. The
+// database is indexed ahead of time with the `opening_index` tool.
+pub struct ExplorerPlugin;
+
+impl Plugin for ExplorerPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Explorer::from_env())
+            .add_systems(
+                OnEnter(GameState::Analysis),
+                spawn_explorer.run_if(has_explorer),
+            )
+            .add_systems(
+                Update,
+                (
+                    handle_explorer_buttons
+                        .before(handle_board_event)
+                        .run_if(animations_idle),
+                    show_explorer
+                        .after(handle_board_event)
+                        .after(handle_game_command),
+                )
+                    .run_if(in_state(GameState::Analysis).and_then(has_explorer)),
+            );
+    }
+}
@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+use chess::search::explorer::ExplorerMove;
+
+use super::component::*;
+use super::constants::*;
+use crate::chessboard::component::{BoardEvent, GameState};
+use crate::engine::chess::Chess;
+use crate::menu::button_text_style;
+use crate::menu::constants::*;
+
+pub fn has_explorer(explorer: Res<Explorer>) -> bool {
+    !explorer.0.is_empty()
+}
+
+// Panel in the bottom left corner, under the engine's lines
+pub fn spawn_explorer(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(20.),
+                    bottom: Val::Px(20.),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.),
+                    padding: UiRect::all(Val::Px(12.)),
+                    ..default()
+                },
+                background_color: PANEL_BACKGROUND.into(),
+                ..default()
+            },
+            StateScoped(GameState::Analysis),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Opening explorer",
+                button_text_style(&asset_server, 20.),
+            ));
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 16.)),
+                ExplorerSummaryText,
+            ));
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(2.),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ExplorerList::default(),
+            ));
+        });
+}
+
+// A move with its number of games and a bar of how they ended
+fn spawn_row(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    chess: &Chess,
+    explored: &ExplorerMove,
+) {
+    let text = |value: String, width: f32| {
+        TextBundle::from_section(value, button_text_style(asset_server, 16.)).with_style(Style {
+            width: Val::Px(width),
+            ..default()
+        })
+    };
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    height: Val::Px(ROW_HEIGHT),
+                    column_gap: Val::Px(6.),
+                    padding: UiRect::horizontal(Val::Px(4.)),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: BUTTON_NORMAL.into(),
+                ..default()
+            },
+            ExplorerMoveButton { mv: explored.mv },
+        ))
+        .with_children(|parent| {
+            parent.spawn(text(chess.to_san(&explored.mv), SAN_WIDTH));
+            parent.spawn(text(explored.games().to_string(), GAMES_WIDTH));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(BAR_WIDTH),
+                        height: Val::Px(ROW_HEIGHT - 6.),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (idx, percent) in explored.percentages().into_iter().enumerate() {
+                        parent
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(percent),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    overflow: Overflow::clip(),
+                                    ..default()
+                                },
+                                background_color: RESULT_COLORS[idx].into(),
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                if percent >= MIN_LABELLED_PERCENT {
+                                    parent.spawn(TextBundle::from_section(
+                                        format!("{percent:.0}%"),
+                                        TextStyle {
+                                            font: asset_server.load("fonts/Gantari.ttf"),
+                                            font_size: 13.,
+                                            color: RESULT_TEXT_COLORS[idx].into(),
+                                        },
+                                    ));
+                                }
+                            });
+                    }
+                });
+        });
+}
+
+// Lists the moves played from the position on the board whenever it changes
+pub fn show_explorer(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    explorer: Res<Explorer>,
+    q_chess: Query<&Chess>,
+    mut q_list: Query<(Entity, &mut ExplorerList)>,
+    mut q_summary: Query<&mut Text, With<ExplorerSummaryText>>,
+) {
+    let chess = q_chess.single();
+    let hash = chess.hash();
+    for (entity, mut list) in q_list.iter_mut() {
+        if list.hash == Some(hash) {
+            continue;
+        }
+        list.hash = Some(hash);
+        let moves = explorer.0.moves(chess);
+        let games = moves.iter().map(ExplorerMove::games).sum::<u32>();
+        for mut summary in q_summary.iter_mut() {
+            summary.sections[0].value = match games {
+                0 => "No games from this position".to_string(),
+                1 => "1 game".to_string(),
+                games => format!("{games} games"),
+            };
+        }
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for explored in moves.iter().take(EXPLORER_MOVES) {
+                    spawn_row(parent, &asset_server, chess, explored);
+                }
+            });
+    }
+}
+
+// Clicking a move plays it on the board
+pub fn handle_explorer_buttons(
+    mut q_buttons: Query<
+        (&Interaction, &ExplorerMoveButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut ev_board: EventWriter<BoardEvent>,
+) {
+    for (interaction, button, mut background) in q_buttons.iter_mut() {
+        *background = match interaction {
+            Interaction::Pressed => BUTTON_PRESSED.into(),
+            Interaction::Hovered => BUTTON_HOVERED.into(),
+            Interaction::None => BUTTON_NORMAL.into(),
+        };
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let from = Chess::index_to_position(button.mv.from);
+        let to = Chess::index_to_position(button.mv.to);
+        ev_board.send(match button.mv.promotion {
+            Some(piece) => BoardEvent::PromotePiece(from, to, piece),
+            None => BoardEvent::MovePiece(from, to),
+        });
+    }
+}
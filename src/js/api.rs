@@ -0,0 +1,58 @@
+// Functions exported to the page hosting the web build, see `index.html`
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+
+use super::component::*;
+use crate::engine::chess::Chess;
+
+// Starts a new game, from the standard position or the given FEN
+#[wasm_bindgen(js_name = newGame)]
+pub fn new_game(fen: Option<String>) -> Result<(), JsError> {
+    if let Some(fen) = &fen {
+        Chess::from_fen(fen).ok_or_else(|| JsError::new(&format!("Invalid FEN: {fen}")))?;
+    }
+    BRIDGE.with_borrow_mut(|bridge| bridge.commands.push_back(JsCommand::NewGame { fen }));
+    Ok(())
+}
+
+#[wasm_bindgen(js_name = getFen)]
+pub fn get_fen() -> String {
+    BRIDGE.with_borrow(|bridge| bridge.fen.clone())
+}
+
+#[wasm_bindgen(js_name = getPgn)]
+pub fn get_pgn() -> String {
+    BRIDGE.with_borrow(|bridge| bridge.pgn.clone())
+}
+
+// Plays a move in SAN (`Nf3`) or UCI (`g1f3`) for the side to move, which has
+// to be played from this board rather than by the bot or an online opponent
+#[wasm_bindgen(js_name = playMove)]
+pub fn play_move(text: &str) -> Result<(), JsError> {
+    BRIDGE.with_borrow_mut(|bridge| {
+        if bridge.game_over {
+            return Err(JsError::new("The game is over"));
+        }
+        if !bridge.human_to_move {
+            return Err(JsError::new("It is not the player's turn"));
+        }
+        bridge
+            .chess
+            .parse_move(text)
+            .ok_or_else(|| JsError::new(&format!("{text} is not a legal move")))?;
+        bridge.commands.push_back(JsCommand::Move(text.to_string()));
+        Ok(())
+    })
+}
+
+// Calls `callback({ san, uci, fen })` after every move
+#[wasm_bindgen(js_name = onMove)]
+pub fn on_move(callback: Function) {
+    BRIDGE.with_borrow_mut(|bridge| bridge.on_move = Some(callback));
+}
+
+// Calls `callback({ result, reason, pgn })` when the game ends
+#[wasm_bindgen(js_name = onGameOver)]
+pub fn on_game_over(callback: Function) {
+    BRIDGE.with_borrow_mut(|bridge| bridge.on_game_over = Some(callback));
+}
@@ -0,0 +1,32 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use js_sys::Function;
+
+use crate::engine::chess::Chess;
+
+// A request from the page, applied on the next frames
+pub enum JsCommand {
+    NewGame { fen: Option<String> },
+    // SAN or UCI, checked again against the position when it is played
+    Move(String),
+}
+
+// What the page can see of the game and the callbacks it registered. The
+// exported functions run outside of Bevy, so they go through this instead of
+// the world.
+#[derive(Default)]
+pub struct JsBridge {
+    pub commands: VecDeque<JsCommand>,
+    pub chess: Chess,
+    pub human_to_move: bool,
+    pub game_over: bool,
+    pub fen: String,
+    pub pgn: String,
+    pub on_move: Option<Function>,
+    pub on_game_over: Option<Function>,
+}
+
+thread_local! {
+    pub static BRIDGE: RefCell<JsBridge> = RefCell::default();
+}
@@ -0,0 +1,28 @@
+// Lets the page hosting the web build drive the game from JavaScript
+mod api;
+mod component;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::system::{animations_idle, handle_board_event, handle_game_command};
+use system::*;
+
+pub struct JsApiPlugin;
+
+impl Plugin for JsApiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                apply_js_commands
+                    .before(handle_game_command)
+                    .before(handle_board_event)
+                    .run_if(animations_idle),
+                publish_game
+                    .after(handle_board_event)
+                    .after(handle_game_command),
+            ),
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use js_sys::{Function, JSON};
+use serde_json::json;
+use wasm_bindgen::JsValue;
+
+use super::component::*;
+use crate::chessboard::component::{
+    Annotations, Board, BoardEvent, GameCommand, GameState, MoveHistory, Players,
+};
+use crate::chessboard::system::game_pgn;
+use crate::engine::chess::Chess;
+
+// Plays the page's requests on the board, one per frame so each sees the
+// position left by the one before
+pub fn apply_js_commands(
+    state: Res<State<GameState>>,
+    q_chess: Query<&Chess>,
+    q_board: Query<&Board>,
+    mut ev_command: EventWriter<GameCommand>,
+    mut ev_board: EventWriter<BoardEvent>,
+) {
+    let Some(command) = BRIDGE.with_borrow_mut(|bridge| bridge.commands.pop_front()) else {
+        return;
+    };
+    match command {
+        JsCommand::NewGame { fen: Some(fen) } => {
+            ev_command.send(GameCommand::LoadGame {
+                fen,
+                moves: Vec::new(),
+            });
+        }
+        JsCommand::NewGame { fen: None } => {
+            ev_command.send(GameCommand::NewGame);
+        }
+        JsCommand::Move(text) => {
+            let playing = *state.get() == GameState::Playing && !q_board.single().game_over;
+            match q_chess.single().parse_move(&text).filter(|_| playing) {
+                Some(mv) => {
+                    let from = Chess::index_to_position(mv.from);
+                    let to = Chess::index_to_position(mv.to);
+                    ev_board.send(match mv.promotion {
+                        Some(piece) => BoardEvent::PromotePiece(from, to, piece),
+                        None => BoardEvent::MovePiece(from, to),
+                    });
+                }
+                None => warn!("Dropped the move {text} from the page, it can't be played now"),
+            }
+        }
+    }
+}
+
+// Keeps the game as the page sees it up to date, and tells the page about
+// new moves and the end of the game
+pub fn publish_game(
+    history: Res<MoveHistory>,
+    annotations: Res<Annotations>,
+    players: Res<Players>,
+    q_chess: Query<&Chess>,
+    q_board: Query<&Board>,
+    mut published_plies: Local<usize>,
+    mut was_over: Local<bool>,
+) {
+    let chess = q_chess.single();
+    let board = q_board.single();
+    let pgn = game_pgn(&history, &annotations, board, &players, chess).to_pgn();
+    let (on_move, on_game_over) = BRIDGE.with_borrow_mut(|bridge| {
+        bridge.chess = chess.clone();
+        bridge.human_to_move = players.is_human(chess.white_turn);
+        bridge.game_over = board.game_over;
+        bridge.fen = chess.to_fen();
+        bridge.pgn = pgn.clone();
+        (bridge.on_move.clone(), bridge.on_game_over.clone())
+    });
+
+    // A new game or an undo takes the count back without telling the page
+    if history.moves.len() > *published_plies {
+        if let Some(callback) = &on_move {
+            for ply in *published_plies..history.moves.len() {
+                let fen = history.positions.get(ply + 1).unwrap_or(chess).to_fen();
+                call(
+                    callback,
+                    json!({
+                        "san": history.san[ply],
+                        "uci": history.moves[ply].to_uci(),
+                        "fen": fen,
+                    }),
+                );
+            }
+        }
+    }
+    *published_plies = history.moves.len();
+
+    if board.game_over && !*was_over {
+        if let Some(callback) = &on_game_over {
+            call(
+                callback,
+                json!({
+                    "result": board.result,
+                    "reason": board.reason,
+                    "pgn": pgn,
+                }),
+            );
+        }
+    }
+    *was_over = board.game_over;
+}
+
+fn call(callback: &Function, value: serde_json::Value) {
+    let argument = JSON::parse(&value.to_string()).unwrap_or(JsValue::NULL);
+    if let Err(err) = callback.call1(&JsValue::NULL, &argument) {
+        warn!("Page callback failed: {err:?}");
+    }
+}
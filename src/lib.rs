@@ -0,0 +1,8 @@
+// Rules engine, bot search and networking shared by the game, the relay
+// server and other tools. Bevy is only needed for the `bevy` feature, which
+// makes `Chess` a component and adds the system spawning it.
+pub mod engine;
+pub mod net;
+pub mod search;
+
+pub use engine::chess::{Chess, Move, MoveOutcome};
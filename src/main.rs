@@ -1,32 +1,74 @@
+mod analysis;
 mod bot;
 mod camera;
 mod chessboard;
-mod engine;
+mod database;
+mod explorer;
+#[cfg(target_arch = "wasm32")]
+mod js;
+mod menu;
+mod online;
+mod puzzle;
+mod review;
+mod save;
+mod setup;
+mod sound;
+mod storage;
 
 use bevy::ecs::schedule::ExecutorKind;
 use bevy::prelude::*;
 use bevy::window::*;
+use chess::engine;
 
+use analysis::AnalysisPlugin;
 use bot::BotPlugin;
 use camera::CameraPlugin;
 use chessboard::ChessBoardPlugin;
+use database::DatabasePlugin;
+use explorer::ExplorerPlugin;
+#[cfg(target_arch = "wasm32")]
+use js::JsApiPlugin;
+use menu::MenuPlugin;
+use online::OnlinePlugin;
+use puzzle::PuzzlePlugin;
+use review::ReviewPlugin;
+use save::SavePlugin;
+use setup::SetupPlugin;
+use sound::SoundPlugin;
 
 fn main() {
-    App::new()
-        .add_plugins((
-            DefaultPlugins.set(WindowPlugin {
-                primary_window: Some(Window {
-                    resolution: WindowResolution::new(1280., 720.).with_scale_factor_override(1.),
-                    ..default()
-                }),
+    // The bot's search worker loads this module too, and only needs its exports
+    #[cfg(target_arch = "wasm32")]
+    if web_sys::window().is_none() {
+        return;
+    }
+    let mut app = App::new();
+    app.add_plugins((
+        DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                resolution: WindowResolution::new(1280., 720.).with_scale_factor_override(1.),
                 ..default()
             }),
-            CameraPlugin,
-            ChessBoardPlugin,
-            BotPlugin,
-        ))
-        .edit_schedule(Update, |schedule| {
-            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
-        })
-        .run();
+            ..default()
+        }),
+        CameraPlugin,
+        ChessBoardPlugin,
+        BotPlugin,
+        MenuPlugin,
+        SavePlugin,
+        OnlinePlugin,
+        SoundPlugin,
+        AnalysisPlugin,
+        ExplorerPlugin,
+        ReviewPlugin,
+        SetupPlugin,
+        PuzzlePlugin,
+        DatabasePlugin,
+    ))
+    .edit_schedule(Update, |schedule| {
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    });
+    #[cfg(target_arch = "wasm32")]
+    app.add_plugins(JsApiPlugin);
+    app.run();
 }
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+// What a menu button does when pressed
+#[derive(Component, Debug, Clone, Copy)]
+pub enum MenuButton {
+    NewGame,
+    ResumeGame,
+    HostOnline,
+    JoinOnline,
+    HostLan,
+    JoinLan,
+    WatchOnline,
+    WatchLan,
+    Resign,
+    OfferDraw,
+    Rematch,
+    ExportPgn,
+    Analysis,
+    TakeBack,
+    FlipBoard,
+    Review,
+    // Leaves the review for the game over screen
+    Back,
+    Hint,
+    Setup,
+    Puzzles,
+    NextPuzzle,
+    Database,
+}
+
+impl MenuButton {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MenuButton::NewGame => "New Game",
+            MenuButton::ResumeGame => "Resume last game",
+            MenuButton::HostOnline => "Host online game",
+            MenuButton::JoinOnline => "Join online game",
+            MenuButton::HostLan => "Host LAN game",
+            MenuButton::JoinLan => "Join LAN game",
+            MenuButton::WatchOnline => "Watch online game",
+            MenuButton::WatchLan => "Watch LAN game",
+            MenuButton::Resign => "Resign",
+            MenuButton::OfferDraw => "Offer Draw",
+            MenuButton::Rematch => "Rematch",
+            MenuButton::ExportPgn => "Export PGN",
+            MenuButton::Analysis => "Analysis board",
+            MenuButton::TakeBack => "Take back",
+            MenuButton::FlipBoard => "Flip board",
+            MenuButton::Review => "Review game",
+            MenuButton::Back => "Back",
+            MenuButton::Hint => "Hint",
+            MenuButton::Setup => "Set up position",
+            MenuButton::Puzzles => "Puzzles",
+            MenuButton::NextPuzzle => "Next puzzle",
+            MenuButton::Database => "Game database",
+        }
+    }
+}
+
+// Shows the state of a pending draw offer under the game controls
+#[derive(Component, Debug)]
+pub struct DrawOfferText;
+
+// Code of the online room or address of the LAN game to join or watch, typed
+// in the menu
+#[derive(Resource, Debug, Default)]
+pub struct JoinInput {
+    pub text: String,
+}
+
+#[derive(Component, Debug)]
+pub struct JoinInputText;
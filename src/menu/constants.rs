@@ -0,0 +1,11 @@
+use bevy::color::*;
+
+pub const PANEL_BACKGROUND: Srgba = Srgba::new(0.08, 0.08, 0.1, 0.85);
+pub const MENU_BACKGROUND: Srgba = Srgba::new(0.05, 0.05, 0.07, 0.92);
+pub const BUTTON_NORMAL: Srgba = Srgba::new(0.23, 0.24, 0.3, 1.);
+pub const BUTTON_HOVERED: Srgba = Srgba::new(0.32, 0.34, 0.42, 1.);
+pub const BUTTON_PRESSED: Srgba = Srgba::new(0.376, 0.922, 0.212, 1.);
+pub const TEXT_COLOR: Srgba = Srgba::new(0.909_803_9, 0.929_411_77, 0.976_470_6, 1.);
+
+pub const BUTTON_WIDTH: f32 = 180.;
+pub const BUTTON_HEIGHT: f32 = 44.;
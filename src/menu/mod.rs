@@ -0,0 +1,33 @@
+mod component;
+pub mod constants;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::component::GameState;
+use crate::chessboard::system::handle_game_command;
+use component::JoinInput;
+pub use component::MenuButton;
+use system::*;
+pub use system::{button_text_style, side_panel, spawn_button};
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<JoinInput>()
+            .add_systems(OnEnter(GameState::Menu), spawn_menu)
+            .add_systems(OnEnter(GameState::Playing), spawn_game_controls)
+            .add_systems(OnEnter(GameState::GameOver), spawn_game_over)
+            .add_systems(OnEnter(GameState::Analysis), spawn_analysis_controls)
+            .add_systems(
+                Update,
+                (
+                    handle_menu_buttons,
+                    type_join_input.run_if(in_state(GameState::Menu)),
+                    update_game_state.after(handle_game_command),
+                    show_draw_answer.run_if(in_state(GameState::Playing)),
+                ),
+            );
+    }
+}
@@ -0,0 +1,418 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+
+use super::component::*;
+use super::constants::*;
+use crate::chessboard::component::{
+    Board, GameCommand, GameEvent, GameState, MoveHistory, PlayerKind, Players,
+};
+use crate::online::OnlineCommand;
+use crate::puzzle::NextPuzzle;
+use crate::save::LastGame;
+
+const ROOM_CODE_LEN: usize = 5;
+// Long enough for an IPv4 address and port
+const JOIN_INPUT_LEN: usize = 21;
+
+pub fn button_text_style(asset_server: &AssetServer, font_size: f32) -> TextStyle {
+    TextStyle {
+        font: asset_server.load("fonts/Gantari.ttf"),
+        color: TEXT_COLOR.into(),
+        font_size,
+    }
+}
+
+pub fn spawn_button(parent: &mut ChildBuilder, asset_server: &AssetServer, button: MenuButton) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(BUTTON_WIDTH),
+                    height: Val::Px(BUTTON_HEIGHT),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: BUTTON_NORMAL.into(),
+                ..default()
+            },
+            button,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                button.label(),
+                button_text_style(asset_server, 24.),
+            ));
+        });
+}
+
+// Lays buttons out side by side
+fn spawn_button_row(parent: &mut ChildBuilder, asset_server: &AssetServer, buttons: &[MenuButton]) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                column_gap: Val::Px(16.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for button in buttons {
+                spawn_button(parent, asset_server, *button);
+            }
+        });
+}
+
+// Column of controls pinned to the top right corner of the window
+pub fn side_panel() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.),
+            top: Val::Px(20.),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(8.),
+            padding: UiRect::all(Val::Px(12.)),
+            ..default()
+        },
+        background_color: PANEL_BACKGROUND.into(),
+        ..default()
+    }
+}
+
+pub fn spawn_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    last_game: Res<LastGame>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(16.),
+                    ..default()
+                },
+                background_color: MENU_BACKGROUND.into(),
+                ..default()
+            },
+            StateScoped(GameState::Menu),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Yet another chess",
+                TextStyle {
+                    font: asset_server.load("fonts/ProtestGuerrilla-Regular.ttf"),
+                    color: TEXT_COLOR.into(),
+                    font_size: 72.,
+                },
+            ));
+            if last_game.0.is_some() {
+                spawn_button(parent, &asset_server, MenuButton::ResumeGame);
+            }
+            spawn_button(parent, &asset_server, MenuButton::NewGame);
+            spawn_button(parent, &asset_server, MenuButton::Analysis);
+            spawn_button(parent, &asset_server, MenuButton::Setup);
+            spawn_button(parent, &asset_server, MenuButton::Puzzles);
+            spawn_button(parent, &asset_server, MenuButton::Database);
+            spawn_button(parent, &asset_server, MenuButton::HostOnline);
+            spawn_button(parent, &asset_server, MenuButton::HostLan);
+            parent.spawn((
+                TextBundle::from_section(join_label(""), button_text_style(&asset_server, 20.)),
+                JoinInputText,
+            ));
+            spawn_button_row(
+                parent,
+                &asset_server,
+                &[MenuButton::JoinOnline, MenuButton::WatchOnline],
+            );
+            spawn_button_row(
+                parent,
+                &asset_server,
+                &[MenuButton::JoinLan, MenuButton::WatchLan],
+            );
+        });
+}
+
+fn join_label(text: &str) -> String {
+    format!("Room code or address: {text}_")
+}
+
+// Types the room code or LAN address to join or watch
+pub fn type_join_input(
+    mut ev_keys: EventReader<KeyboardInput>,
+    mut join_input: ResMut<JoinInput>,
+    mut q_text: Query<&mut Text, With<JoinInputText>>,
+) {
+    for ev in ev_keys.read() {
+        if ev.state != ButtonState::Pressed {
+            continue;
+        }
+        match &ev.logical_key {
+            Key::Backspace => {
+                join_input.text.pop();
+            }
+            Key::Character(text) => {
+                for c in text
+                    .chars()
+                    .filter(|c| c.is_ascii_alphanumeric() || *c == '.' || *c == ':')
+                {
+                    if join_input.text.len() < JOIN_INPUT_LEN {
+                        join_input.text.push(c);
+                    }
+                }
+            }
+            _ => continue,
+        }
+        for mut text in q_text.iter_mut() {
+            text.sections[0].value = join_label(&join_input.text);
+        }
+    }
+}
+
+pub fn spawn_game_controls(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    players: Res<Players>,
+) {
+    commands
+        .spawn((side_panel(), StateScoped(GameState::Playing)))
+        .with_children(|parent| {
+            spawn_button(parent, &asset_server, MenuButton::NewGame);
+            if !players.is_spectating() {
+                spawn_button(parent, &asset_server, MenuButton::Resign);
+            }
+            // The bot's hints aren't given in games against other people
+            if !players.is_online() {
+                spawn_button(parent, &asset_server, MenuButton::Hint);
+            }
+            // Only the bot answers draw offers
+            if players.black == PlayerKind::Bot {
+                spawn_button(parent, &asset_server, MenuButton::OfferDraw);
+            }
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 20.)),
+                DrawOfferText,
+            ));
+        });
+}
+
+// Summarizes the finished game with actions to play again or save it
+pub fn spawn_game_over(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    q_board: Query<&Board>,
+    history: Res<MoveHistory>,
+) {
+    let board = q_board.single();
+    let result = board.result.clone().unwrap_or_else(|| "*".to_string());
+    let winner = match result.as_str() {
+        "1-0" => "White won",
+        "0-1" => "Black won",
+        _ => "Draw",
+    };
+    let reason = board.reason.clone().unwrap_or_default();
+    let moves = history.san.len().div_ceil(2);
+
+    commands
+        .spawn((side_panel(), StateScoped(GameState::GameOver)))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Game over",
+                button_text_style(&asset_server, 32.),
+            ));
+            for line in [
+                format!("{} ({})", winner, result),
+                reason,
+                format!("{} moves", moves),
+            ] {
+                parent.spawn(TextBundle::from_section(
+                    line,
+                    button_text_style(&asset_server, 20.),
+                ));
+            }
+            spawn_button(parent, &asset_server, MenuButton::Rematch);
+            if !history.moves.is_empty() {
+                spawn_button(parent, &asset_server, MenuButton::Review);
+            }
+            spawn_button(parent, &asset_server, MenuButton::Analysis);
+            spawn_button(parent, &asset_server, MenuButton::ExportPgn);
+        });
+}
+
+// Moves are taken back and the board turned by hand while analysing, and a
+// new game leaves analysis
+pub fn spawn_analysis_controls(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((side_panel(), StateScoped(GameState::Analysis)))
+        .with_children(|parent| {
+            spawn_button(parent, &asset_server, MenuButton::TakeBack);
+            spawn_button(parent, &asset_server, MenuButton::FlipBoard);
+            spawn_button(parent, &asset_server, MenuButton::Setup);
+            spawn_button(parent, &asset_server, MenuButton::ExportPgn);
+            spawn_button(parent, &asset_server, MenuButton::NewGame);
+        });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_menu_buttons(
+    mut q_buttons: Query<(&Interaction, &MenuButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut q_draw_text: Query<&mut Text, With<DrawOfferText>>,
+    mut ev_command: EventWriter<GameCommand>,
+    mut ev_online: EventWriter<OnlineCommand>,
+    mut ev_puzzle: EventWriter<NextPuzzle>,
+    mut next_state: ResMut<NextState<GameState>>,
+    players: Res<Players>,
+    join_input: Res<JoinInput>,
+) {
+    let is_white = players.local_is_white();
+    for (interaction, button, mut background) in q_buttons.iter_mut() {
+        *background = match interaction {
+            Interaction::Pressed => BUTTON_PRESSED.into(),
+            Interaction::Hovered => BUTTON_HOVERED.into(),
+            Interaction::None => BUTTON_NORMAL.into(),
+        };
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        ev_command.send(match button {
+            MenuButton::HostOnline => {
+                ev_online.send(OnlineCommand::Host);
+                continue;
+            }
+            MenuButton::JoinOnline => {
+                if join_input.text.len() == ROOM_CODE_LEN {
+                    ev_online.send(OnlineCommand::Join {
+                        code: join_input.text.to_uppercase(),
+                    });
+                }
+                continue;
+            }
+            MenuButton::WatchOnline => {
+                if join_input.text.len() == ROOM_CODE_LEN {
+                    ev_online.send(OnlineCommand::Watch {
+                        code: join_input.text.to_uppercase(),
+                    });
+                }
+                continue;
+            }
+            MenuButton::WatchLan => {
+                if !join_input.text.is_empty() {
+                    ev_online.send(OnlineCommand::WatchLan {
+                        address: join_input.text.clone(),
+                    });
+                }
+                continue;
+            }
+            MenuButton::HostLan => {
+                ev_online.send(OnlineCommand::HostLan);
+                continue;
+            }
+            MenuButton::JoinLan => {
+                if !join_input.text.is_empty() {
+                    ev_online.send(OnlineCommand::JoinLan {
+                        address: join_input.text.clone(),
+                    });
+                }
+                continue;
+            }
+            MenuButton::NewGame | MenuButton::Rematch => GameCommand::NewGame,
+            MenuButton::ResumeGame => GameCommand::ResumeGame,
+            MenuButton::Resign => GameCommand::Resign { is_white },
+            MenuButton::OfferDraw => {
+                for mut text in q_draw_text.iter_mut() {
+                    text.sections[0].value = "Draw offered..".to_string();
+                }
+                GameCommand::OfferDraw { is_white }
+            }
+            MenuButton::ExportPgn => GameCommand::ExportPgn,
+            // Picks up from the position on the board, the start position
+            // from the menu
+            MenuButton::Analysis => {
+                next_state.set(GameState::Analysis);
+                continue;
+            }
+            MenuButton::Review => {
+                next_state.set(GameState::Review);
+                continue;
+            }
+            MenuButton::Back => {
+                next_state.set(GameState::GameOver);
+                continue;
+            }
+            // Starts from the position on the board
+            MenuButton::Setup => {
+                next_state.set(GameState::Setup);
+                continue;
+            }
+            MenuButton::Puzzles => {
+                next_state.set(GameState::Puzzle);
+                continue;
+            }
+            MenuButton::Database => {
+                next_state.set(GameState::Database);
+                continue;
+            }
+            MenuButton::NextPuzzle => {
+                ev_puzzle.send(NextPuzzle);
+                continue;
+            }
+            MenuButton::TakeBack => GameCommand::Undo,
+            MenuButton::FlipBoard => GameCommand::FlipBoard,
+            MenuButton::Hint => GameCommand::Hint,
+        });
+    }
+}
+
+pub fn show_draw_answer(
+    mut ev_game: EventReader<GameEvent>,
+    mut q_draw_text: Query<&mut Text, With<DrawOfferText>>,
+) {
+    for ev in ev_game.read() {
+        let value = match ev {
+            GameEvent::DrawDeclined => "Draw declined",
+            // The offer lapses once a move is played
+            GameEvent::Moved(_) => "",
+            _ => continue,
+        };
+        for mut text in q_draw_text.iter_mut() {
+            text.sections[0].value = value.to_string();
+        }
+    }
+}
+
+// Follows the board: a new or loaded game starts play, also from analysis, and
+// the game ends or resumes (after an undo) with the board's game over flag.
+// Puzzles are loaded as games, so only a new game leaves them.
+pub fn update_game_state(
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    q_board: Query<&Board>,
+    mut ev_command: EventReader<GameCommand>,
+) {
+    let game_over = q_board.single().game_over;
+    let commands = ev_command.read().collect::<Vec<_>>();
+    let new_game = commands
+        .iter()
+        .any(|command| matches!(command, GameCommand::NewGame | GameCommand::LoadGame { .. }));
+    match state.get() {
+        GameState::Menu if new_game => next_state.set(GameState::Playing),
+        GameState::Playing if game_over => next_state.set(GameState::GameOver),
+        GameState::GameOver if !game_over => next_state.set(GameState::Playing),
+        GameState::Analysis | GameState::Review if new_game => next_state.set(GameState::Playing),
+        GameState::Puzzle
+            if commands
+                .iter()
+                .any(|command| matches!(command, GameCommand::NewGame)) =>
+        {
+            next_state.set(GameState::Playing)
+        }
+        _ => {}
+    }
+}
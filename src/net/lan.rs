@@ -0,0 +1,554 @@
+// Direct play between two instances on a local network. One hosts and the
+// other joins; the host picks the colors and time control and sends them in
+// its greeting. Messages are lines of JSON over TCP.
+//
+// Each side keeps its own copy of the game: moves from the peer are checked
+// with it before being passed on, and both sides compare position hashes
+// after every move. When they disagree the host's game wins and is sent over.
+//
+// Others can connect to the host to watch: they get the same greeting, answer
+// it with `Watch`, and are sent the game so far and every move after it.
+//
+// Towards the app a peer looks like the relay server, taking `ClientMessage`s
+// and answering with `ServerMessage`s.
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::protocol::*;
+use crate::engine::chess::Chess;
+
+pub const DEFAULT_PORT: u16 = 9002;
+const PROTOCOL_VERSION: u32 = 1;
+// How long reads wait before the queued outgoing messages are sent
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum PeerMessage {
+    // The host's greeting, with the color the joining player gets
+    Hello {
+        version: u32,
+        color: Color,
+        time_control: TimeControl,
+        fen: String,
+        moves: Vec<String>,
+    },
+    Ready {
+        version: u32,
+    },
+    Watch {
+        version: u32,
+    },
+    // `hash` is the sender's position hash after the move
+    Move {
+        seq: usize,
+        uci: String,
+        hash: u64,
+    },
+    Ack {
+        seq: usize,
+        hash: u64,
+    },
+    SyncRequest,
+    Sync {
+        fen: String,
+        moves: Vec<String>,
+    },
+    Resign,
+    // Tells spectators who resigned
+    Resigned {
+        color: Color,
+    },
+}
+
+struct Game {
+    start: Chess,
+    chess: Chess,
+    moves: Vec<String>,
+    color: Color,
+    is_host: bool,
+}
+
+impl Game {
+    fn new(start: Chess, moves: &[String], color: Color, is_host: bool) -> Option<Game> {
+        let mut game = Game {
+            chess: start.clone(),
+            start,
+            moves: Vec::new(),
+            color,
+            is_host,
+        };
+        for uci in moves {
+            game.play(game.moves.len() + 1, uci)?;
+        }
+        Some(game)
+    }
+
+    // Plays the move if it is the next one and legal
+    fn play(&mut self, seq: usize, uci: &str) -> Option<()> {
+        if seq != self.moves.len() + 1 {
+            return None;
+        }
+        let mv = self.chess.parse_uci(uci)?;
+        self.chess.play(&mv);
+        self.moves.push(uci.to_string());
+        Some(())
+    }
+
+    fn is_turn_of(&self, color: Color) -> bool {
+        self.chess.white_turn == color.is_white()
+    }
+
+    fn sync(&self) -> ServerMessage {
+        ServerMessage::Sync {
+            fen: self.start.to_fen(),
+            moves: self.moves.clone(),
+        }
+    }
+}
+
+struct Peer {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    // Bytes of a line still being received
+    buffer: Vec<u8>,
+}
+
+impl Peer {
+    fn new(stream: TcpStream) -> io::Result<Peer> {
+        stream.set_read_timeout(Some(POLL_INTERVAL))?;
+        Ok(Peer {
+            writer: stream.try_clone()?,
+            reader: BufReader::new(stream),
+            buffer: Vec::new(),
+        })
+    }
+
+    fn send(&mut self, message: &PeerMessage) -> io::Result<()> {
+        let line = serde_json::to_string(message).map_err(io::Error::other)?;
+        writeln!(self.writer, "{line}")
+    }
+
+    // A complete message if one has arrived, `None` when the wait timed out
+    fn receive(&mut self) -> io::Result<Option<PeerMessage>> {
+        match self.reader.read_until(b'\n', &mut self.buffer) {
+            Ok(0) => Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(_) if self.buffer.ends_with(b"\n") => {
+                let message = serde_json::from_slice(&self.buffer).map_err(io::Error::other);
+                self.buffer.clear();
+                message.map(Some)
+            }
+            Ok(_) => Ok(None),
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn receive_within(&mut self, timeout: Duration) -> io::Result<PeerMessage> {
+        let deadline = Instant::now() + timeout;
+        while Instant::now() < deadline {
+            if let Some(message) = self.receive()? {
+                return Ok(message);
+            }
+        }
+        Err(io::ErrorKind::TimedOut.into())
+    }
+}
+
+// Greets a new connection with the game so far and waits for its answer
+fn greet(stream: TcpStream, hello: &PeerMessage) -> io::Result<(Peer, PeerMessage)> {
+    stream.set_nonblocking(false)?;
+    let mut peer = Peer::new(stream)?;
+    peer.send(hello)?;
+    let answer = peer.receive_within(HANDSHAKE_TIMEOUT)?;
+    Ok((peer, answer))
+}
+
+// A connection which has answered the host's greeting
+struct Greeted {
+    peer: Peer,
+    answer: PeerMessage,
+    address: SocketAddr,
+    // Moves in the game it was greeted with
+    plies: usize,
+}
+
+// The host's listener, kept open for spectators once the game has started.
+// Connections are greeted on their own threads so that a slow or silent one
+// doesn't hold up the game.
+struct Spectators {
+    listener: TcpListener,
+    time_control: TimeControl,
+    peers: Vec<Peer>,
+    greeter: Sender<Greeted>,
+    greeted: Receiver<Greeted>,
+}
+
+impl Spectators {
+    fn new(listener: TcpListener, time_control: TimeControl) -> Spectators {
+        let (greeter, greeted) = channel();
+        Spectators {
+            listener,
+            time_control,
+            peers: Vec::new(),
+            greeter,
+            greeted,
+        }
+    }
+
+    // Greets new connections and takes in the spectators among those which
+    // have answered. A player ready to join is returned when `want_player`
+    // is set; anyone else is turned away.
+    fn accept(&mut self, game: &Game, want_player: bool) -> io::Result<Option<(Peer, SocketAddr)>> {
+        loop {
+            let (stream, address) = match self.listener.accept() {
+                Ok(connection) => connection,
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                Err(err) => return Err(err),
+            };
+            let hello = PeerMessage::Hello {
+                version: PROTOCOL_VERSION,
+                color: game.color.opponent(),
+                time_control: self.time_control,
+                fen: game.start.to_fen(),
+                moves: game.moves.clone(),
+            };
+            let plies = game.moves.len();
+            let greeter = self.greeter.clone();
+            thread::spawn(move || match greet(stream, &hello) {
+                Ok((peer, answer)) => {
+                    let _ = greeter.send(Greeted {
+                        peer,
+                        answer,
+                        address,
+                        plies,
+                    });
+                }
+                Err(err) => log::warn!("Connection from {address} failed: {err}"),
+            });
+        }
+        let mut player = None;
+        for mut greeted in self.greeted.try_iter() {
+            match greeted.answer {
+                PeerMessage::Ready {
+                    version: PROTOCOL_VERSION,
+                } if want_player && player.is_none() => {
+                    player = Some((greeted.peer, greeted.address));
+                }
+                PeerMessage::Watch {
+                    version: PROTOCOL_VERSION,
+                } => {
+                    // Sends the moves played while it was being greeted
+                    let mut chess = game.start.clone();
+                    let caught_up = game.moves.iter().enumerate().all(|(idx, uci)| {
+                        if let Some(mv) = chess.parse_uci(uci) {
+                            chess.play(&mv);
+                        }
+                        idx < greeted.plies
+                            || greeted
+                                .peer
+                                .send(&PeerMessage::Move {
+                                    seq: idx + 1,
+                                    uci: uci.clone(),
+                                    hash: chess.hash(),
+                                })
+                                .is_ok()
+                    });
+                    if caught_up {
+                        self.peers.push(greeted.peer);
+                    }
+                }
+                _ => log::warn!("Turned away {}, who can't join", greeted.address),
+            }
+        }
+        Ok(player)
+    }
+
+    // Spectators who have gone are dropped
+    fn send(&mut self, message: &PeerMessage) {
+        self.peers.retain_mut(|peer| peer.send(message).is_ok());
+    }
+}
+
+fn joined(
+    deliver: &mut impl FnMut(ServerMessage),
+    game: &Game,
+    address: String,
+    time_control: TimeControl,
+) {
+    deliver(ServerMessage::Joined {
+        code: address,
+        color: game.color,
+        token: String::new(),
+    });
+    deliver(ServerMessage::TimeControl { time_control });
+    deliver(game.sync());
+    deliver(ServerMessage::OpponentJoined);
+}
+
+// Waits for a player to join, giving up once `outgoing` is dropped
+pub fn host(
+    listener: TcpListener,
+    color: Color,
+    time_control: TimeControl,
+    outgoing: &Receiver<ClientMessage>,
+    mut deliver: impl FnMut(ServerMessage),
+) -> io::Result<()> {
+    listener.set_nonblocking(true)?;
+    let mut game = Game::new(Chess::new(), &[], color, true).expect("the start position is valid");
+    let mut spectators = Spectators::new(listener, time_control);
+    let (mut peer, address) = loop {
+        if let Some(player) = spectators.accept(&game, true)? {
+            break player;
+        }
+        if let Err(TryRecvError::Disconnected) = outgoing.try_recv() {
+            return Ok(());
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    joined(&mut deliver, &game, address.to_string(), time_control);
+    run(
+        &mut peer,
+        &mut game,
+        Some(&mut spectators),
+        outgoing,
+        &mut deliver,
+    )
+}
+
+pub fn join(
+    address: &str,
+    outgoing: &Receiver<ClientMessage>,
+    mut deliver: impl FnMut(ServerMessage),
+) -> io::Result<()> {
+    let socket_address = address
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| io::Error::other("unknown address"))?;
+    let stream = TcpStream::connect_timeout(&socket_address, HANDSHAKE_TIMEOUT)?;
+    let mut peer = Peer::new(stream)?;
+    let PeerMessage::Hello {
+        version: PROTOCOL_VERSION,
+        color,
+        time_control,
+        fen,
+        moves,
+    } = peer.receive_within(HANDSHAKE_TIMEOUT)?
+    else {
+        return Err(io::Error::other("the host is not compatible"));
+    };
+    let mut game = Chess::from_fen(&fen)
+        .and_then(|start| Game::new(start, &moves, color, false))
+        .ok_or_else(|| io::Error::other("the host sent an invalid game"))?;
+    peer.send(&PeerMessage::Ready {
+        version: PROTOCOL_VERSION,
+    })?;
+    joined(&mut deliver, &game, address.to_string(), time_control);
+    run(&mut peer, &mut game, None, outgoing, &mut deliver)
+}
+
+// Follows a game hosted on the local network without taking part
+pub fn watch(
+    address: &str,
+    outgoing: &Receiver<ClientMessage>,
+    mut deliver: impl FnMut(ServerMessage),
+) -> io::Result<()> {
+    let socket_address = address
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| io::Error::other("unknown address"))?;
+    let stream = TcpStream::connect_timeout(&socket_address, HANDSHAKE_TIMEOUT)?;
+    let mut peer = Peer::new(stream)?;
+    let PeerMessage::Hello {
+        version: PROTOCOL_VERSION,
+        color,
+        time_control,
+        fen,
+        moves,
+    } = peer.receive_within(HANDSHAKE_TIMEOUT)?
+    else {
+        return Err(io::Error::other("the host is not compatible"));
+    };
+    let invalid_game = || io::Error::other("the host sent an invalid game");
+    let mut game = Chess::from_fen(&fen)
+        .and_then(|start| Game::new(start, &moves, color, false))
+        .ok_or_else(invalid_game)?;
+    peer.send(&PeerMessage::Watch {
+        version: PROTOCOL_VERSION,
+    })?;
+    deliver(ServerMessage::Watching {
+        code: address.to_string(),
+    });
+    deliver(ServerMessage::TimeControl { time_control });
+    deliver(game.sync());
+    loop {
+        match peer.receive()? {
+            Some(PeerMessage::Move { seq, uci, .. }) => {
+                game.play(seq, &uci).ok_or_else(invalid_game)?;
+                deliver(ServerMessage::Moved { seq, uci });
+            }
+            Some(PeerMessage::Sync { fen, moves }) => {
+                game = Chess::from_fen(&fen)
+                    .and_then(|start| Game::new(start, &moves, color, false))
+                    .ok_or_else(invalid_game)?;
+                deliver(game.sync());
+            }
+            Some(PeerMessage::Resigned { color }) => deliver(ServerMessage::Resigned { color }),
+            _ => {}
+        }
+        if let Err(TryRecvError::Disconnected) = outgoing.try_recv() {
+            return Ok(());
+        }
+    }
+}
+
+fn run(
+    peer: &mut Peer,
+    game: &mut Game,
+    mut spectators: Option<&mut Spectators>,
+    outgoing: &Receiver<ClientMessage>,
+    deliver: &mut impl FnMut(ServerMessage),
+) -> io::Result<()> {
+    loop {
+        if let Some(spectators) = spectators.as_deref_mut() {
+            spectators.accept(game, false)?;
+        }
+        if let Some(message) = peer.receive()? {
+            handle_peer_message(peer, game, spectators.as_deref_mut(), message, deliver)?;
+        }
+        loop {
+            match outgoing.try_recv() {
+                Ok(ClientMessage::Move { seq, uci }) => {
+                    let played = game.is_turn_of(game.color).then(|| game.play(seq, &uci));
+                    if played.flatten().is_some() {
+                        let message = PeerMessage::Move {
+                            seq,
+                            uci: uci.clone(),
+                            hash: game.chess.hash(),
+                        };
+                        peer.send(&message)?;
+                        if let Some(spectators) = spectators.as_deref_mut() {
+                            spectators.send(&message);
+                        }
+                        deliver(ServerMessage::Moved { seq, uci });
+                    } else {
+                        deliver(ServerMessage::Rejected {
+                            seq,
+                            reason: "Illegal move".to_string(),
+                        });
+                        deliver(game.sync());
+                    }
+                }
+                Ok(ClientMessage::Resign) => {
+                    peer.send(&PeerMessage::Resign)?;
+                    if let Some(spectators) = spectators.as_deref_mut() {
+                        spectators.send(&PeerMessage::Resigned { color: game.color });
+                    }
+                }
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return Ok(()),
+            }
+        }
+    }
+}
+
+fn handle_peer_message(
+    peer: &mut Peer,
+    game: &mut Game,
+    mut spectators: Option<&mut Spectators>,
+    message: PeerMessage,
+    deliver: &mut impl FnMut(ServerMessage),
+) -> io::Result<()> {
+    match message {
+        PeerMessage::Move { seq, uci, hash } => {
+            let opponent = game.color.opponent();
+            let played = game.is_turn_of(opponent).then(|| game.play(seq, &uci));
+            if played.flatten().is_none() {
+                return resync(peer, game, spectators, deliver);
+            }
+            deliver(ServerMessage::Moved {
+                seq,
+                uci: uci.clone(),
+            });
+            let own = game.chess.hash();
+            peer.send(&PeerMessage::Ack { seq, hash: own })?;
+            if own != hash {
+                return resync(peer, game, spectators, deliver);
+            }
+            if let Some(spectators) = spectators {
+                spectators.send(&PeerMessage::Move { seq, uci, hash });
+            }
+        }
+        PeerMessage::Ack { seq, hash } if seq == game.moves.len() && hash != game.chess.hash() => {
+            return resync(peer, game, spectators, deliver);
+        }
+        PeerMessage::SyncRequest if game.is_host => {
+            let sync = PeerMessage::Sync {
+                fen: game.start.to_fen(),
+                moves: game.moves.clone(),
+            };
+            peer.send(&sync)?;
+            if let Some(spectators) = spectators.as_deref_mut() {
+                spectators.send(&sync);
+            }
+            deliver(game.sync());
+        }
+        PeerMessage::Sync { fen, moves } if !game.is_host => {
+            let synced = Chess::from_fen(&fen)
+                .and_then(|start| Game::new(start, &moves, game.color, false))
+                .ok_or_else(|| io::Error::other("the host sent an invalid game"))?;
+            *game = synced;
+            deliver(game.sync());
+        }
+        PeerMessage::Resign => {
+            let color = game.color.opponent();
+            if let Some(spectators) = spectators {
+                spectators.send(&PeerMessage::Resigned { color });
+            }
+            deliver(ServerMessage::Resigned { color });
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+// The games have drifted apart: the host sends its game over, a joining
+// player asks for it
+fn resync(
+    peer: &mut Peer,
+    game: &Game,
+    spectators: Option<&mut Spectators>,
+    deliver: &mut impl FnMut(ServerMessage),
+) -> io::Result<()> {
+    deliver(ServerMessage::Error {
+        message: "Out of step with the other player, resynchronising".to_string(),
+    });
+    if game.is_host {
+        let sync = PeerMessage::Sync {
+            fen: game.start.to_fen(),
+            moves: game.moves.clone(),
+        };
+        peer.send(&sync)?;
+        if let Some(spectators) = spectators {
+            spectators.send(&sync);
+        }
+        deliver(game.sync());
+        Ok(())
+    } else {
+        peer.send(&PeerMessage::SyncRequest)
+    }
+}
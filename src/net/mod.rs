@@ -0,0 +1,5 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod lan;
+pub mod protocol;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod relay;
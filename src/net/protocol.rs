@@ -0,0 +1,104 @@
+// Messages exchanged with the relay server, sent as JSON text frames. Moves
+// are in UCI notation and numbered by ply, the first move having `seq` 1.
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Color {
+    White,
+    Black,
+}
+
+impl Color {
+    pub fn is_white(self) -> bool {
+        self == Color::White
+    }
+
+    pub fn opponent(self) -> Color {
+        match self {
+            Color::White => Color::Black,
+            Color::Black => Color::White,
+        }
+    }
+}
+
+// Starting time and the time added after each move, in seconds
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct TimeControl {
+    pub initial_secs: f32,
+    pub increment_secs: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    // Opens a room and takes white in it
+    CreateRoom,
+    JoinRoom { code: String },
+    // Takes back a seat after a lost connection, with the token given on joining
+    Rejoin { code: String, token: String },
+    // Follows the game in a room without taking part
+    Watch { code: String },
+    Move { seq: usize, uci: String },
+    Resign,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Joined {
+        code: String,
+        color: Color,
+        token: String,
+    },
+    // Sent to spectators instead of `Joined`
+    Watching {
+        code: String,
+    },
+    // The whole game so far, sent on joining and whenever a client is out of step
+    Sync {
+        fen: String,
+        moves: Vec<String>,
+    },
+    OpponentJoined,
+    OpponentLeft,
+    // A move accepted by the server, echoed to the player who made it
+    Moved {
+        seq: usize,
+        uci: String,
+    },
+    Rejected {
+        seq: usize,
+        reason: String,
+    },
+    Resigned {
+        color: Color,
+    },
+    // Clocks agreed on with a LAN host; the relay leaves clocks to the players
+    TimeControl {
+        time_control: TimeControl,
+    },
+    Error {
+        message: String,
+    },
+}
+
+impl ClientMessage {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(text: &str) -> Option<ClientMessage> {
+        serde_json::from_str(text).ok()
+    }
+}
+
+impl ServerMessage {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(text: &str) -> Option<ServerMessage> {
+        serde_json::from_str(text).ok()
+    }
+}
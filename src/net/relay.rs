@@ -0,0 +1,345 @@
+// Reference relay server: pairs two players in a room by code and passes
+// their moves on, checking each one with the rules engine first. Anyone else
+// with the code can watch the game. Every connection runs on its own thread.
+use std::collections::HashMap;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use tungstenite::{accept, Message, WebSocket};
+
+use super::protocol::*;
+use crate::engine::chess::Chess;
+
+// Letters and digits that are hard to mix up when read out
+const ROOM_CODE_CHARS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const ROOM_CODE_LEN: usize = 5;
+const TOKEN_LEN: usize = 16;
+// How long a connection waits for a message before sending the queued ones
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+struct Seat {
+    token: String,
+    // Gone while the player is disconnected
+    sender: Option<Sender<ServerMessage>>,
+}
+
+struct Room {
+    start: Chess,
+    chess: Chess,
+    moves: Vec<String>,
+    // White's seat, then black's
+    seats: [Option<Seat>; 2],
+    spectators: Vec<Sender<ServerMessage>>,
+    // Set once a player resigns, after which no more moves are relayed
+    finished: bool,
+}
+
+impl Room {
+    fn new() -> Room {
+        Room {
+            start: Chess::new(),
+            chess: Chess::new(),
+            moves: Vec::new(),
+            seats: [None, None],
+            spectators: Vec::new(),
+            finished: false,
+        }
+    }
+
+    fn sync(&self) -> ServerMessage {
+        ServerMessage::Sync {
+            fen: self.start.to_fen(),
+            moves: self.moves.clone(),
+        }
+    }
+
+    fn send(&self, color: Color, message: ServerMessage) {
+        if let Some(sender) = self.seats[seat_index(color)]
+            .as_ref()
+            .and_then(|seat| seat.sender.as_ref())
+        {
+            let _ = sender.send(message);
+        }
+    }
+
+    // Spectators whose connection has closed are dropped
+    fn send_spectators(&mut self, message: ServerMessage) {
+        self.spectators
+            .retain(|sender| sender.send(message.clone()).is_ok());
+    }
+
+    fn is_abandoned(&self) -> bool {
+        self.seats
+            .iter()
+            .flatten()
+            .all(|seat| seat.sender.is_none())
+    }
+}
+
+fn seat_index(color: Color) -> usize {
+    if color.is_white() {
+        0
+    } else {
+        1
+    }
+}
+
+fn random_code() -> String {
+    let mut rng = thread_rng();
+    (0..ROOM_CODE_LEN)
+        .map(|_| ROOM_CODE_CHARS[rng.gen_range(0..ROOM_CODE_CHARS.len())] as char)
+        .collect()
+}
+
+fn random_token() -> String {
+    thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(TOKEN_LEN)
+        .map(char::from)
+        .collect()
+}
+
+// The room code and color of a connected player
+type Membership = Option<(String, Color)>;
+
+#[derive(Default)]
+pub struct Relay {
+    rooms: Mutex<HashMap<String, Room>>,
+}
+
+impl Relay {
+    fn handle(
+        &self,
+        member: &mut Membership,
+        message: ClientMessage,
+        sender: &Sender<ServerMessage>,
+    ) {
+        let mut rooms = self.rooms.lock().unwrap();
+        let reply = |message: ServerMessage| {
+            let _ = sender.send(message);
+        };
+        let error = |message: &str| {
+            reply(ServerMessage::Error {
+                message: message.to_string(),
+            })
+        };
+        match message {
+            ClientMessage::CreateRoom => {
+                Relay::leave(&mut rooms, member);
+                let code = loop {
+                    let code = random_code();
+                    if !rooms.contains_key(&code) {
+                        break code;
+                    }
+                };
+                let token = random_token();
+                let mut room = Room::new();
+                room.seats[0] = Some(Seat {
+                    token: token.clone(),
+                    sender: Some(sender.clone()),
+                });
+                reply(ServerMessage::Joined {
+                    code: code.clone(),
+                    color: Color::White,
+                    token,
+                });
+                reply(room.sync());
+                rooms.insert(code.clone(), room);
+                *member = Some((code, Color::White));
+            }
+            ClientMessage::JoinRoom { code } => {
+                Relay::leave(&mut rooms, member);
+                let code = code.trim().to_uppercase();
+                let Some(room) = rooms.get_mut(&code) else {
+                    return error("There is no room with that code");
+                };
+                if room.seats[1].is_some() {
+                    return error("The room is full");
+                }
+                let token = random_token();
+                room.seats[1] = Some(Seat {
+                    token: token.clone(),
+                    sender: Some(sender.clone()),
+                });
+                reply(ServerMessage::Joined {
+                    code: code.clone(),
+                    color: Color::Black,
+                    token,
+                });
+                reply(room.sync());
+                room.send(Color::White, ServerMessage::OpponentJoined);
+                *member = Some((code, Color::Black));
+            }
+            ClientMessage::Rejoin { code, token } => {
+                Relay::leave(&mut rooms, member);
+                let code = code.trim().to_uppercase();
+                let Some(room) = rooms.get_mut(&code) else {
+                    return error("The room has closed");
+                };
+                let Some(color) = [Color::White, Color::Black].into_iter().find(|color| {
+                    room.seats[seat_index(*color)]
+                        .as_ref()
+                        .is_some_and(|seat| seat.token == token)
+                }) else {
+                    return error("Could not take back the seat");
+                };
+                if let Some(seat) = room.seats[seat_index(color)].as_mut() {
+                    seat.sender = Some(sender.clone());
+                }
+                reply(ServerMessage::Joined {
+                    code: code.clone(),
+                    color,
+                    token,
+                });
+                reply(room.sync());
+                room.send(color.opponent(), ServerMessage::OpponentJoined);
+                *member = Some((code, color));
+            }
+            ClientMessage::Watch { code } => {
+                Relay::leave(&mut rooms, member);
+                let code = code.trim().to_uppercase();
+                let Some(room) = rooms.get_mut(&code) else {
+                    return error("There is no room with that code");
+                };
+                room.spectators.push(sender.clone());
+                reply(ServerMessage::Watching { code });
+                reply(room.sync());
+            }
+            ClientMessage::Move { seq, uci } => {
+                let Some((code, color)) = member.as_ref() else {
+                    return error("Join a room first");
+                };
+                let Some(room) = rooms.get_mut(code) else {
+                    return error("The room has closed");
+                };
+                let rejection = if room.finished {
+                    Some("The game is over")
+                } else if room.chess.white_turn != color.is_white() {
+                    Some("Not your turn")
+                } else if seq != room.moves.len() + 1 {
+                    Some("Out of sequence")
+                } else if let Some(mv) = room.chess.parse_uci(&uci) {
+                    room.chess.play(&mv);
+                    room.moves.push(uci.clone());
+                    None
+                } else {
+                    Some("Illegal move")
+                };
+                match rejection {
+                    Some(reason) => {
+                        reply(ServerMessage::Rejected {
+                            seq,
+                            reason: reason.to_string(),
+                        });
+                        reply(room.sync());
+                    }
+                    None => {
+                        let moved = ServerMessage::Moved { seq, uci };
+                        for color in [Color::White, Color::Black] {
+                            room.send(color, moved.clone());
+                        }
+                        room.send_spectators(moved);
+                    }
+                }
+            }
+            ClientMessage::Resign => {
+                if let Some((code, color)) = member.as_ref() {
+                    if let Some(room) = rooms.get_mut(code).filter(|room| !room.finished) {
+                        room.finished = true;
+                        let resigned = ServerMessage::Resigned { color: *color };
+                        room.send(color.opponent(), resigned.clone());
+                        room.send_spectators(resigned);
+                    }
+                }
+            }
+        }
+    }
+
+    // Frees the player's seat for a rejoin, closing the room once both are gone
+    fn leave(rooms: &mut HashMap<String, Room>, member: &mut Membership) {
+        let Some((code, color)) = member.take() else {
+            return;
+        };
+        let Some(room) = rooms.get_mut(&code) else {
+            return;
+        };
+        if let Some(seat) = room.seats[seat_index(color)].as_mut() {
+            seat.sender = None;
+        }
+        room.send(color.opponent(), ServerMessage::OpponentLeft);
+        if room.is_abandoned() {
+            rooms.remove(&code);
+        }
+    }
+
+    fn disconnect(&self, member: &mut Membership) {
+        Relay::leave(&mut self.rooms.lock().unwrap(), member);
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn run_connection(
+    relay: &Relay,
+    socket: &mut WebSocket<TcpStream>,
+    member: &mut Membership,
+    sender: &Sender<ServerMessage>,
+    receiver: &Receiver<ServerMessage>,
+) -> tungstenite::Result<()> {
+    loop {
+        match socket.read() {
+            Ok(Message::Text(text)) => match ClientMessage::from_json(&text) {
+                Some(message) => relay.handle(member, message, sender),
+                None => {
+                    let _ = sender.send(ServerMessage::Error {
+                        message: "Unreadable message".to_string(),
+                    });
+                }
+            },
+            Ok(Message::Close(_)) => return Ok(()),
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(err))
+                if matches!(
+                    err.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) => {}
+            Err(err) => return Err(err),
+        }
+        for message in receiver.try_iter() {
+            socket.send(Message::Text(message.to_json()))?;
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn handle_connection(relay: &Relay, stream: TcpStream) -> tungstenite::Result<()> {
+    let mut socket = accept(stream).map_err(|err| io::Error::other(err.to_string()))?;
+    socket.get_ref().set_read_timeout(Some(POLL_INTERVAL))?;
+    let (sender, receiver) = channel();
+    let mut member = None;
+    let result = run_connection(relay, &mut socket, &mut member, &sender, &receiver);
+    relay.disconnect(&mut member);
+    result
+}
+
+// Serves clients from the listener until it fails
+pub fn serve(listener: TcpListener) -> io::Result<()> {
+    let relay = Arc::new(Relay::default());
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let relay = relay.clone();
+        thread::spawn(move || match handle_connection(&relay, stream) {
+            Ok(())
+            | Err(tungstenite::Error::ConnectionClosed)
+            | Err(tungstenite::Error::Protocol(
+                tungstenite::error::ProtocolError::ResetWithoutClosingHandshake,
+            )) => {}
+            Err(err) => eprintln!("Connection failed: {err}"),
+        });
+    }
+    Ok(())
+}
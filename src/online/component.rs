@@ -0,0 +1,68 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use chess::net::protocol::{ClientMessage, Color, ServerMessage};
+
+use super::connection::Connection;
+
+#[derive(Event, Debug)]
+pub enum OnlineCommand {
+    // Opens a room on the relay server, playing white
+    Host,
+    Join { code: String },
+    // Plays directly with another instance on the local network
+    HostLan,
+    JoinLan { address: String },
+    // Follows a game without playing in it
+    Watch { code: String },
+    WatchLan { address: String },
+    Leave,
+}
+
+// The online game being played, if any
+#[derive(Resource, Debug, Default)]
+pub struct OnlineGame {
+    pub code: Option<String>,
+    // None while spectating
+    pub color: Option<Color>,
+    pub spectating: bool,
+    // Lets the seat be taken back after a lost connection
+    pub token: Option<String>,
+    // Number of plies of the move history already sent or received
+    pub sent_plies: usize,
+    // Whether the game from the server has been set up on the board
+    pub loaded: bool,
+    pub opponent_connected: bool,
+    pub status: String,
+    // Counts down to the next reconnection attempt while disconnected
+    pub reconnect: Option<Timer>,
+}
+
+impl OnlineGame {
+    // LAN games go by the host's address rather than a room code
+    pub fn is_lan(&self) -> bool {
+        self.code.as_ref().is_some_and(|code| code.contains(':'))
+    }
+
+    // How to get the game from the relay server again: by taking back the
+    // seat, or watching once more
+    pub fn rejoin(&self) -> Option<ClientMessage> {
+        let code = self.code.clone().filter(|_| !self.is_lan())?;
+        if self.spectating {
+            return Some(ClientMessage::Watch { code });
+        }
+        let token = self.token.clone()?;
+        Some(ClientMessage::Rejoin { code, token })
+    }
+}
+
+// The socket lives on the main thread, as the browser's can't be shared
+#[derive(Default)]
+pub struct OnlineClient {
+    pub connection: Option<Connection>,
+    // Messages waiting for the board to be ready for them
+    pub inbox: VecDeque<ServerMessage>,
+}
+
+#[derive(Component, Debug)]
+pub struct OnlineStatusText;
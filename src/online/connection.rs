@@ -0,0 +1,300 @@
+// A WebSocket connection to the relay server. Natively it runs on its own
+// thread, on the web it uses the browser's WebSocket; either way messages are
+// queued and picked up by polling every frame.
+use chess::net::protocol::{ClientMessage, ServerMessage};
+
+pub enum ConnectionEvent {
+    Message(ServerMessage),
+    Closed,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::*;
+#[cfg(target_arch = "wasm32")]
+pub use web::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::io;
+    use std::net::{TcpListener, UdpSocket};
+    use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+    use std::thread;
+    use std::time::Duration;
+
+    use chess::net::lan;
+    use chess::net::protocol::{Color, TimeControl};
+    use tungstenite::{connect, stream::MaybeTlsStream, Message};
+
+    use super::*;
+
+    // How long the connection waits for a message before sending queued ones
+    const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+    pub fn relay_url() -> String {
+        std::env::var("CHESS_RELAY").unwrap_or_else(|_| "ws://127.0.0.1:9001".to_string())
+    }
+
+    pub struct Connection {
+        outgoing: Sender<ClientMessage>,
+        incoming: Receiver<ConnectionEvent>,
+    }
+
+    impl Connection {
+        // Runs `connect` on its own thread, which ends with the connection
+        fn spawn(
+            connect: impl FnOnce(&Receiver<ClientMessage>, &Sender<ConnectionEvent>) -> Result<(), String>
+                + Send
+                + 'static,
+        ) -> Connection {
+            let (outgoing, outgoing_rx) = channel();
+            let (incoming_tx, incoming) = channel();
+            thread::spawn(move || {
+                if let Err(err) = connect(&outgoing_rx, &incoming_tx) {
+                    bevy::log::warn!("Connection lost: {err}");
+                }
+                let _ = incoming_tx.send(ConnectionEvent::Closed);
+            });
+            Connection { outgoing, incoming }
+        }
+
+        pub fn open(url: &str) -> Connection {
+            let url = url.to_string();
+            Connection::spawn(move |outgoing, incoming| {
+                run(&url, outgoing, incoming).map_err(|err| err.to_string())
+            })
+        }
+
+        // Waits for a player on the local network, returning the address
+        // they can join with
+        pub fn host_lan(
+            color: Color,
+            time_control: TimeControl,
+        ) -> io::Result<(Connection, String)> {
+            let listener = TcpListener::bind(("0.0.0.0", lan::DEFAULT_PORT))?;
+            let address = format!("{}:{}", local_ip(), lan::DEFAULT_PORT);
+            let connection = Connection::spawn(move |outgoing, incoming| {
+                lan::host(listener, color, time_control, outgoing, |message| {
+                    let _ = incoming.send(ConnectionEvent::Message(message));
+                })
+                .map_err(|err| err.to_string())
+            });
+            Ok((connection, address))
+        }
+
+        pub fn join_lan(address: &str) -> io::Result<Connection> {
+            let address = with_default_port(address);
+            Ok(Connection::spawn(move |outgoing, incoming| {
+                lan::join(&address, outgoing, |message| {
+                    let _ = incoming.send(ConnectionEvent::Message(message));
+                })
+                .map_err(|err| err.to_string())
+            }))
+        }
+
+        pub fn watch_lan(address: &str) -> io::Result<Connection> {
+            let address = with_default_port(address);
+            Ok(Connection::spawn(move |outgoing, incoming| {
+                lan::watch(&address, outgoing, |message| {
+                    let _ = incoming.send(ConnectionEvent::Message(message));
+                })
+                .map_err(|err| err.to_string())
+            }))
+        }
+
+        pub fn send(&self, message: ClientMessage) {
+            let _ = self.outgoing.send(message);
+        }
+
+        pub fn poll(&self) -> Vec<ConnectionEvent> {
+            self.incoming.try_iter().collect()
+        }
+    }
+
+    fn with_default_port(address: &str) -> String {
+        match address.contains(':') {
+            true => address.to_string(),
+            false => format!("{address}:{}", lan::DEFAULT_PORT),
+        }
+    }
+
+    // The address other machines reach this one at, found by asking the OS
+    // which interface it would route through; nothing is sent
+    fn local_ip() -> String {
+        UdpSocket::bind("0.0.0.0:0")
+            .and_then(|socket| {
+                socket.connect("192.168.0.1:9")?;
+                socket.local_addr()
+            })
+            .map(|address| address.ip().to_string())
+            .unwrap_or_else(|_| "127.0.0.1".to_string())
+    }
+
+    // Ends quietly once the `Connection` is dropped
+    #[allow(clippy::result_large_err)]
+    fn run(
+        url: &str,
+        outgoing: &Receiver<ClientMessage>,
+        incoming: &Sender<ConnectionEvent>,
+    ) -> tungstenite::Result<()> {
+        let (mut socket, _) = connect(url)?;
+        if let MaybeTlsStream::Plain(stream) = socket.get_ref() {
+            stream.set_read_timeout(Some(POLL_INTERVAL))?;
+        }
+        loop {
+            match socket.read() {
+                Ok(Message::Text(text)) => {
+                    if let Some(message) = ServerMessage::from_json(&text) {
+                        let _ = incoming.send(ConnectionEvent::Message(message));
+                    }
+                }
+                Ok(Message::Close(_)) => return Ok(()),
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(err))
+                    if matches!(
+                        err.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    ) => {}
+                Err(err) => return Err(err),
+            }
+            loop {
+                match outgoing.try_recv() {
+                    Ok(message) => socket.send(Message::Text(message.to_json()))?,
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        let _ = socket.close(None);
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+    use web_sys::{MessageEvent, WebSocket};
+
+    use chess::net::protocol::{Color, TimeControl};
+
+    use super::*;
+
+    // The relay is expected on the host serving the page
+    pub fn relay_url() -> String {
+        let host = web_sys::window()
+            .and_then(|window| window.location().hostname().ok())
+            .filter(|host| !host.is_empty())
+            .unwrap_or_else(|| "127.0.0.1".to_string());
+        format!("ws://{host}:9001")
+    }
+
+    pub struct Connection {
+        socket: Option<WebSocket>,
+        events: Rc<RefCell<VecDeque<ConnectionEvent>>>,
+        // Messages sent before the socket opened
+        pending: Rc<RefCell<Vec<String>>>,
+        _on_message: Closure<dyn FnMut(MessageEvent)>,
+        _on_open: Closure<dyn FnMut()>,
+        _on_close: Closure<dyn FnMut()>,
+    }
+
+    impl Connection {
+        pub fn open(url: &str) -> Connection {
+            let events = Rc::new(RefCell::new(VecDeque::new()));
+            let pending = Rc::new(RefCell::new(Vec::<String>::new()));
+            let socket = WebSocket::new(url).ok();
+
+            let on_message = {
+                let events = events.clone();
+                Closure::<dyn FnMut(MessageEvent)>::new(move |ev: MessageEvent| {
+                    if let Some(message) = ev
+                        .data()
+                        .as_string()
+                        .and_then(|text| ServerMessage::from_json(&text))
+                    {
+                        events
+                            .borrow_mut()
+                            .push_back(ConnectionEvent::Message(message));
+                    }
+                })
+            };
+            let on_open = {
+                let socket = socket.clone();
+                let pending = pending.clone();
+                Closure::<dyn FnMut()>::new(move || {
+                    if let Some(socket) = &socket {
+                        for text in pending.borrow_mut().drain(..) {
+                            let _ = socket.send_with_str(&text);
+                        }
+                    }
+                })
+            };
+            let on_close = {
+                let events = events.clone();
+                Closure::<dyn FnMut()>::new(move || {
+                    events.borrow_mut().push_back(ConnectionEvent::Closed);
+                })
+            };
+            match &socket {
+                Some(socket) => {
+                    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+                    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+                    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+                }
+                None => events.borrow_mut().push_back(ConnectionEvent::Closed),
+            }
+            Connection {
+                socket,
+                events,
+                pending,
+                _on_message: on_message,
+                _on_open: on_open,
+                _on_close: on_close,
+            }
+        }
+
+        // Browsers can't open plain TCP connections
+        pub fn host_lan(_: Color, _: TimeControl) -> std::io::Result<(Connection, String)> {
+            Err(std::io::Error::other("LAN games need the desktop version"))
+        }
+
+        pub fn join_lan(_: &str) -> std::io::Result<Connection> {
+            Err(std::io::Error::other("LAN games need the desktop version"))
+        }
+
+        pub fn watch_lan(_: &str) -> std::io::Result<Connection> {
+            Err(std::io::Error::other("LAN games need the desktop version"))
+        }
+
+        pub fn send(&self, message: ClientMessage) {
+            let text = message.to_json();
+            match &self.socket {
+                Some(socket) if socket.ready_state() == WebSocket::OPEN => {
+                    let _ = socket.send_with_str(&text);
+                }
+                Some(_) => self.pending.borrow_mut().push(text),
+                None => {}
+            }
+        }
+
+        pub fn poll(&self) -> Vec<ConnectionEvent> {
+            self.events.borrow_mut().drain(..).collect()
+        }
+    }
+
+    impl Drop for Connection {
+        fn drop(&mut self) {
+            if let Some(socket) = &self.socket {
+                socket.set_onmessage(None);
+                socket.set_onopen(None);
+                socket.set_onclose(None);
+                let _ = socket.close();
+            }
+        }
+    }
+}
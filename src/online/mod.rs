@@ -0,0 +1,39 @@
+mod component;
+mod connection;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::system::{animations_idle, handle_board_event, handle_game_command};
+pub use component::OnlineCommand;
+use component::*;
+use system::*;
+
+pub struct OnlinePlugin;
+
+impl Plugin for OnlinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnlineCommand>()
+            .init_resource::<OnlineGame>()
+            .insert_non_send_resource(OnlineClient::default())
+            .add_systems(Startup, spawn_online_status)
+            .add_systems(
+                Update,
+                (
+                    leave_for_local_game,
+                    handle_online_command.after(leave_for_local_game),
+                    receive_online_messages.after(handle_online_command),
+                    reconnect,
+                    apply_online_messages
+                        .after(receive_online_messages)
+                        .before(handle_game_command)
+                        .before(handle_board_event)
+                        .run_if(animations_idle),
+                    send_local_moves
+                        .after(handle_board_event)
+                        .after(handle_game_command),
+                    update_online_status.run_if(resource_changed::<OnlineGame>),
+                ),
+            );
+    }
+}
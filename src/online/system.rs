@@ -0,0 +1,340 @@
+use bevy::prelude::*;
+use chess::net::protocol::{ClientMessage, Color, ServerMessage, TimeControl};
+
+use super::component::*;
+use super::connection::*;
+use crate::chessboard::component::{
+    Board, BoardEvent, Clock, GameCommand, MoveHistory, PlayerKind, Players,
+};
+use crate::engine::chess::Chess;
+
+const RECONNECT_SECS: f32 = 2.;
+
+pub fn spawn_online_status(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: asset_server.load("fonts/Gantari.ttf"),
+                font_size: 20.,
+                color: bevy::color::Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.),
+            top: Val::Px(20.),
+            ..default()
+        }),
+        OnlineStatusText,
+    ));
+}
+
+pub fn handle_online_command(
+    mut ev_online: EventReader<OnlineCommand>,
+    mut client: NonSendMut<OnlineClient>,
+    mut online: ResMut<OnlineGame>,
+    mut players: ResMut<Players>,
+    clock: Res<Clock>,
+) {
+    for ev in ev_online.read() {
+        *online = OnlineGame::default();
+        client.inbox.clear();
+        client.connection = None;
+        online.status = "Connecting..".to_string();
+        let request = match ev {
+            OnlineCommand::Host => ClientMessage::CreateRoom,
+            OnlineCommand::Join { code } => ClientMessage::JoinRoom { code: code.clone() },
+            OnlineCommand::HostLan => {
+                // The host plays white, with the usual clock
+                let time_control = TimeControl {
+                    initial_secs: Clock::default().remaining[0],
+                    increment_secs: clock.increment,
+                };
+                match Connection::host_lan(Color::White, time_control) {
+                    Ok((connection, address)) => {
+                        client.connection = Some(connection);
+                        online.status = format!("Waiting for a player to join {address}");
+                    }
+                    Err(err) => online.status = format!("Could not host: {err}"),
+                }
+                continue;
+            }
+            OnlineCommand::JoinLan { address } => {
+                match Connection::join_lan(address) {
+                    Ok(connection) => client.connection = Some(connection),
+                    Err(err) => online.status = format!("Could not join: {err}"),
+                }
+                continue;
+            }
+            OnlineCommand::Watch { code } => ClientMessage::Watch { code: code.clone() },
+            OnlineCommand::WatchLan { address } => {
+                match Connection::watch_lan(address) {
+                    Ok(connection) => client.connection = Some(connection),
+                    Err(err) => online.status = format!("Could not watch: {err}"),
+                }
+                continue;
+            }
+            OnlineCommand::Leave => {
+                *players = Players::default();
+                online.status.clear();
+                continue;
+            }
+        };
+        let connection = Connection::open(&relay_url());
+        connection.send(request);
+        client.connection = Some(connection);
+    }
+}
+
+// Starting or resuming a local game leaves the online one
+pub fn leave_for_local_game(
+    mut ev_command: EventReader<GameCommand>,
+    online: Res<OnlineGame>,
+    mut ev_online: EventWriter<OnlineCommand>,
+) {
+    for ev in ev_command.read() {
+        if online.code.is_some() && matches!(ev, GameCommand::NewGame | GameCommand::ResumeGame) {
+            ev_online.send(OnlineCommand::Leave);
+        }
+    }
+}
+
+pub fn receive_online_messages(
+    mut client: NonSendMut<OnlineClient>,
+    mut online: ResMut<OnlineGame>,
+) {
+    let Some(connection) = &client.connection else {
+        return;
+    };
+    for event in connection.poll() {
+        match event {
+            ConnectionEvent::Message(message) => client.inbox.push_back(message),
+            ConnectionEvent::Closed => {
+                client.connection = None;
+                // Only games on the relay server can be picked up again
+                if online.rejoin().is_some() {
+                    online.reconnect = Some(Timer::from_seconds(RECONNECT_SECS, TimerMode::Once));
+                    online.status = "Connection lost".to_string();
+                } else if online.code.is_some() {
+                    online.opponent_connected = false;
+                    online.status = "Disconnected".to_string();
+                } else {
+                    online.status = "Could not connect".to_string();
+                }
+                break;
+            }
+        }
+    }
+}
+
+// Takes the seat back, or goes on watching, once the connection has been
+// lost, and gets the game from the server again
+pub fn reconnect(
+    time: Res<Time>,
+    mut client: NonSendMut<OnlineClient>,
+    mut online: ResMut<OnlineGame>,
+) {
+    let Some(timer) = online.reconnect.as_mut() else {
+        return;
+    };
+    if !timer.tick(time.delta()).finished() {
+        return;
+    }
+    online.reconnect = None;
+    if let Some(rejoin) = online.rejoin() {
+        let connection = Connection::open(&relay_url());
+        connection.send(rejoin);
+        client.connection = Some(connection);
+    }
+}
+
+// Plays the server's messages on the board. Stops after anything that
+// changes the position, so the next message sees the board up to date.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_online_messages(
+    mut client: NonSendMut<OnlineClient>,
+    mut online: ResMut<OnlineGame>,
+    mut players: ResMut<Players>,
+    mut clock: ResMut<Clock>,
+    history: Res<MoveHistory>,
+    q_chess: Query<&Chess>,
+    q_board: Query<&Board>,
+    mut ev_board: EventWriter<BoardEvent>,
+    mut ev_command: EventWriter<GameCommand>,
+) {
+    let chess = q_chess.single();
+    while let Some(message) = client.inbox.pop_front() {
+        match message {
+            ServerMessage::Joined { code, color, token } => {
+                let (local, remote) = (PlayerKind::Human, PlayerKind::Remote);
+                *players = if color.is_white() {
+                    Players {
+                        white: local,
+                        black: remote,
+                    }
+                } else {
+                    Players {
+                        white: remote,
+                        black: local,
+                    }
+                };
+                online.code = Some(code);
+                online.color = Some(color);
+                online.token = Some(token).filter(|token| !token.is_empty());
+                online.status.clear();
+            }
+            ServerMessage::Watching { code } => {
+                *players = Players {
+                    white: PlayerKind::Remote,
+                    black: PlayerKind::Remote,
+                };
+                online.code = Some(code);
+                online.spectating = true;
+                online.status.clear();
+            }
+            ServerMessage::Sync { fen, moves } => {
+                let start = history.positions.first().unwrap_or(chess).to_fen();
+                let played = history
+                    .moves
+                    .iter()
+                    .map(|mv| mv.to_uci())
+                    .collect::<Vec<String>>();
+                online.sent_plies = moves.len();
+                if online.loaded && start == fen && played == moves {
+                    continue;
+                }
+                let flip = !online.loaded
+                    && q_board.single().flipped != (online.color == Some(Color::Black));
+                online.loaded = true;
+                ev_command.send(GameCommand::LoadGame { fen, moves });
+                if flip {
+                    ev_command.send(GameCommand::FlipBoard);
+                }
+                return;
+            }
+            ServerMessage::OpponentJoined => online.opponent_connected = true,
+            ServerMessage::OpponentLeft => online.opponent_connected = false,
+            ServerMessage::Moved { seq, uci } => {
+                // Our own moves come back as an acknowledgement
+                if seq <= history.moves.len() {
+                    continue;
+                }
+                let mv = (seq == history.moves.len() + 1)
+                    .then(|| chess.parse_uci(&uci))
+                    .flatten();
+                match mv {
+                    Some(mv) => {
+                        let from = Chess::index_to_position(mv.from);
+                        let to = Chess::index_to_position(mv.to);
+                        ev_board.send(match mv.promotion {
+                            Some(piece) => BoardEvent::PromotePiece(from, to, piece),
+                            None => BoardEvent::MovePiece(from, to),
+                        });
+                        online.sent_plies = seq;
+                    }
+                    // Out of step with the server, ask for the whole game
+                    None => request_sync(&client, &online),
+                }
+                return;
+            }
+            ServerMessage::Rejected { reason, .. } => {
+                online.status = format!("Move rejected: {reason}");
+            }
+            ServerMessage::Resigned { color } => {
+                ev_command.send(GameCommand::Resign {
+                    is_white: color.is_white(),
+                });
+            }
+            ServerMessage::TimeControl { time_control } => {
+                *clock = Clock {
+                    remaining: [time_control.initial_secs; 2],
+                    increment: time_control.increment_secs,
+                };
+            }
+            ServerMessage::Error { message } => online.status = message,
+        }
+    }
+}
+
+fn request_sync(client: &OnlineClient, online: &OnlineGame) {
+    if let (Some(connection), Some(rejoin)) = (&client.connection, online.rejoin()) {
+        connection.send(rejoin);
+    }
+}
+
+// Sends the local player's moves and resignation to the server
+pub fn send_local_moves(
+    client: NonSend<OnlineClient>,
+    mut online: ResMut<OnlineGame>,
+    history: Res<MoveHistory>,
+    mut ev_command: EventReader<GameCommand>,
+) {
+    let Some(color) = online.color.filter(|_| online.loaded) else {
+        return;
+    };
+    let Some(connection) = &client.connection else {
+        return;
+    };
+    for ply in online.sent_plies..history.moves.len() {
+        if history.positions[ply].white_turn == color.is_white() {
+            connection.send(ClientMessage::Move {
+                seq: ply + 1,
+                uci: history.moves[ply].to_uci(),
+            });
+        }
+    }
+    if online.sent_plies < history.moves.len() {
+        online.sent_plies = history.moves.len();
+    }
+    for ev in ev_command.read() {
+        if let GameCommand::Resign { is_white } = ev {
+            if *is_white == color.is_white() {
+                connection.send(ClientMessage::Resign);
+            }
+        }
+    }
+}
+
+pub fn update_online_status(
+    online: Res<OnlineGame>,
+    mut q_text: Query<&mut Text, With<OnlineStatusText>>,
+) {
+    let mut lines = Vec::new();
+    if let Some(code) = online.code.as_ref().filter(|_| online.spectating) {
+        let game = if online.is_lan() {
+            "LAN game at"
+        } else {
+            "room"
+        };
+        lines.push(format!("Watching {game} {code}"));
+        if online.reconnect.is_some() || online.status == "Connection lost" {
+            lines.push("Reconnecting..".to_string());
+        }
+    }
+    if let (Some(code), Some(color)) = (&online.code, online.color) {
+        let side = if color.is_white() { "white" } else { "black" };
+        let game = if online.is_lan() {
+            "LAN game with"
+        } else {
+            "Room"
+        };
+        lines.push(format!("{game} {code}, playing {side}"));
+        lines.push(
+            if online.reconnect.is_some() || online.status == "Connection lost" {
+                "Reconnecting.."
+            } else if online.opponent_connected {
+                "Opponent connected"
+            } else {
+                "Waiting for the opponent"
+            }
+            .to_string(),
+        );
+    }
+    if !online.status.is_empty() {
+        lines.push(online.status.clone());
+    }
+    for mut text in q_text.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use chess::engine::puzzle::Puzzle;
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+
+// Puzzles to solve, from the CSV file in CHESS_PUZZLES when set, the few
+// bundled with the game otherwise
+#[derive(Resource, Debug, Default)]
+pub struct PuzzleSet(pub Vec<Puzzle>);
+
+impl PuzzleSet {
+    pub fn from_env() -> PuzzleSet {
+        let Ok(path) = std::env::var("CHESS_PUZZLES") else {
+            return PuzzleSet(Puzzle::bundled());
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                let puzzles = Puzzle::parse_csv(&text);
+                info!("{} puzzles from {path}", puzzles.len());
+                PuzzleSet(puzzles)
+            }
+            Err(err) => {
+                warn!("Can't read puzzles from {path}: {err}");
+                PuzzleSet(Puzzle::bundled())
+            }
+        }
+    }
+
+    // The first puzzle from `start` on rated near `rating`, or the one at
+    // `start` when none is
+    pub fn pick(&self, start: usize, rating: i32) -> Option<usize> {
+        if self.0.is_empty() {
+            return None;
+        }
+        let start = start % self.0.len();
+        (start..self.0.len())
+            .chain(0..start)
+            .find(|idx| self.0[*idx].rating.abs_diff(rating.max(0) as u32) <= RATING_WINDOW)
+            .or(Some(start))
+    }
+}
+
+// The solver's record, kept between sessions
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct PuzzleStats {
+    pub rating: i32,
+    pub streak: u32,
+    pub best_streak: u32,
+    pub solved: u32,
+    pub failed: u32,
+    // Where in the set to look for the next puzzle
+    pub next: usize,
+}
+
+impl Default for PuzzleStats {
+    fn default() -> Self {
+        Self {
+            rating: START_RATING,
+            streak: 0,
+            best_streak: 0,
+            solved: 0,
+            failed: 0,
+            next: 0,
+        }
+    }
+}
+
+// The puzzle on the board and how the solver is getting on with it
+#[derive(Resource, Debug)]
+pub struct ActivePuzzle {
+    pub puzzle: Option<Puzzle>,
+    // Whether the first try solved it, once known. Only the first try counts
+    // for the rating and the streak.
+    pub result: Option<bool>,
+    pub solved: bool,
+    pub rating_change: i32,
+    // Waits before the opponent's moves and before taking back a wrong move
+    pub delay: Timer,
+    pub take_back: bool,
+}
+
+impl Default for ActivePuzzle {
+    fn default() -> Self {
+        Self {
+            puzzle: None,
+            result: None,
+            solved: false,
+            rating_change: 0,
+            delay: Timer::from_seconds(REPLY_SECS, TimerMode::Once),
+            take_back: false,
+        }
+    }
+}
+
+// Moves on to another puzzle
+#[derive(Event, Debug)]
+pub struct NextPuzzle;
+
+// The puzzle and the solver's record beside the board
+#[derive(Component, Debug)]
+pub struct PuzzleText;
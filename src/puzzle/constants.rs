@@ -0,0 +1,7 @@
+// Key the solver's rating and streaks are saved under
+pub const STATS_KEY: &str = "puzzles";
+pub const START_RATING: i32 = 1500;
+// Puzzles rated this close to the solver are picked first
+pub const RATING_WINDOW: u32 = 300;
+// Pause before the opponent's moves, and before a wrong move is taken back
+pub const REPLY_SECS: f32 = 0.6;
@@ -0,0 +1,41 @@
+mod component;
+mod constants;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::component::GameState;
+use crate::chessboard::system::{animations_idle, handle_board_event, handle_game_command};
+use crate::storage;
+pub use component::NextPuzzle;
+use component::*;
+use constants::STATS_KEY;
+use system::*;
+
+// Tactics training: the opponent's moves of a puzzle are played for the
+// solver, who has to find the rest, with a rating and streaks kept from one
+// puzzle to the next
+pub struct PuzzlePlugin;
+
+impl Plugin for PuzzlePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PuzzleSet::from_env())
+            .insert_resource(storage::load_json::<PuzzleStats>(STATS_KEY))
+            .init_resource::<ActivePuzzle>()
+            .add_event::<NextPuzzle>()
+            .add_systems(OnEnter(GameState::Puzzle), start_puzzles)
+            .add_systems(OnExit(GameState::Puzzle), stop_puzzles)
+            .add_systems(
+                Update,
+                (
+                    load_puzzle.before(handle_game_command),
+                    judge_move.after(handle_board_event),
+                    advance_puzzle.after(judge_move).run_if(animations_idle),
+                    show_puzzle.after(judge_move).run_if(
+                        resource_changed::<ActivePuzzle>.or_else(resource_changed::<PuzzleStats>),
+                    ),
+                )
+                    .run_if(in_state(GameState::Puzzle)),
+            );
+    }
+}
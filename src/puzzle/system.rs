@@ -0,0 +1,273 @@
+use bevy::prelude::*;
+use chess::engine::puzzle::{update_rating, Attempt};
+
+use super::component::*;
+use super::constants::*;
+use crate::chessboard::component::{
+    AnyOverlay, Board, BoardEvent, GameCommand, GameEvent, GameState, MoveHistory, Piece,
+    PlayerKind, Players, TextInfo,
+};
+use crate::chessboard::system::{show_game_over, sync_board};
+use crate::engine::chess::Chess;
+use crate::menu::{button_text_style, side_panel, spawn_button, MenuButton};
+use crate::storage;
+
+// Lays out the puzzle beside the board and sets up the first one
+pub fn start_puzzles(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut ev_next: EventWriter<NextPuzzle>,
+) {
+    commands
+        .spawn((side_panel(), StateScoped(GameState::Puzzle)))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Puzzles",
+                button_text_style(&asset_server, 32.),
+            ));
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 20.)),
+                PuzzleText,
+            ));
+            spawn_button(parent, &asset_server, MenuButton::NextPuzzle);
+            spawn_button(parent, &asset_server, MenuButton::FlipBoard);
+            spawn_button(parent, &asset_server, MenuButton::NewGame);
+        });
+    ev_next.send(NextPuzzle);
+}
+
+// Leaving puzzles, games are against the bot again
+pub fn stop_puzzles(mut active: ResMut<ActivePuzzle>, mut players: ResMut<Players>) {
+    *active = ActivePuzzle::default();
+    *players = Players::default();
+}
+
+// Sets up the next puzzle near the solver's rating, seen from the solver's
+// side, with the opponent's first move still to come
+pub fn load_puzzle(
+    mut ev_next: EventReader<NextPuzzle>,
+    mut ev_command: EventWriter<GameCommand>,
+    puzzles: Res<PuzzleSet>,
+    mut stats: ResMut<PuzzleStats>,
+    mut active: ResMut<ActivePuzzle>,
+    mut players: ResMut<Players>,
+    q_board: Query<&Board>,
+) {
+    if ev_next.read().count() == 0 {
+        return;
+    }
+    let Some(idx) = puzzles.pick(stats.next, stats.rating) else {
+        *active = ActivePuzzle::default();
+        return;
+    };
+    let puzzle = puzzles.0[idx].clone();
+    stats.next = idx + 1;
+    storage::save_json(STATS_KEY, &*stats);
+
+    let solver_white = puzzle.solver_is_white();
+    let (solver, opponent) = (PlayerKind::Human, PlayerKind::Bot);
+    *players = if solver_white {
+        Players {
+            white: solver,
+            black: opponent,
+        }
+    } else {
+        Players {
+            white: opponent,
+            black: solver,
+        }
+    };
+    ev_command.send(GameCommand::LoadGame {
+        fen: puzzle.fen.clone(),
+        moves: vec![],
+    });
+    if q_board.single().flipped == solver_white {
+        ev_command.send(GameCommand::FlipBoard);
+    }
+    *active = ActivePuzzle {
+        puzzle: Some(puzzle),
+        ..default()
+    };
+}
+
+// Counts the first try at the puzzle towards the rating and streaks
+fn record_result(active: &mut ActivePuzzle, stats: &mut PuzzleStats, solved: bool) {
+    let Some(puzzle) = &active.puzzle else {
+        return;
+    };
+    if active.result.is_some() {
+        return;
+    }
+    let rating = update_rating(stats.rating, puzzle.rating, solved);
+    active.result = Some(solved);
+    active.rating_change = rating - stats.rating;
+    stats.rating = rating;
+    if solved {
+        stats.solved += 1;
+        stats.streak += 1;
+        stats.best_streak = stats.best_streak.max(stats.streak);
+    } else {
+        stats.failed += 1;
+        stats.streak = 0;
+    }
+    storage::save_json(STATS_KEY, &*stats);
+}
+
+fn rating_text(active: &ActivePuzzle, stats: &PuzzleStats) -> String {
+    format!("Rating {} ({:+})", stats.rating, active.rating_change)
+}
+
+// Judges each of the solver's moves against the solution
+pub fn judge_move(
+    mut ev_game: EventReader<GameEvent>,
+    mut active: ResMut<ActivePuzzle>,
+    mut stats: ResMut<PuzzleStats>,
+    history: Res<MoveHistory>,
+    mut q_board: Query<&mut Board>,
+    mut q_texts: Query<(&TextInfo, &mut Transform, &mut Text)>,
+) {
+    for ev in ev_game.read() {
+        let GameEvent::Moved(_) = ev else {
+            continue;
+        };
+        let Some(puzzle) = active.puzzle.clone() else {
+            continue;
+        };
+        let Some(ply) = history.moves.len().checked_sub(1) else {
+            continue;
+        };
+        let before = &history.positions[ply];
+        if active.solved || before.white_turn != puzzle.solver_is_white() {
+            continue;
+        }
+        active.delay.reset();
+        match puzzle.attempt(before, ply, &history.moves[ply]) {
+            Attempt::Correct => {}
+            Attempt::Solved => {
+                let first_try = active.result.is_none();
+                record_result(&mut active, &mut stats, true);
+                active.solved = true;
+                // Nothing is left to play
+                q_board.single_mut().game_over = true;
+                let message = if first_try {
+                    rating_text(&active, &stats)
+                } else {
+                    "Solved on another try".to_string()
+                };
+                show_game_over(&mut q_texts, "SOLVED", &message);
+            }
+            Attempt::Wrong => {
+                let first_try = active.result.is_none();
+                record_result(&mut active, &mut stats, false);
+                active.take_back = true;
+                let message = if first_try {
+                    format!("{}, try again", rating_text(&active, &stats))
+                } else {
+                    "Try again".to_string()
+                };
+                show_game_over(&mut q_texts, "WRONG", &message);
+            }
+        }
+    }
+}
+
+// After a pause, plays the opponent's next move from the solution or takes
+// back the solver's wrong move
+#[allow(clippy::too_many_arguments)]
+pub fn advance_puzzle(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut active: ResMut<ActivePuzzle>,
+    mut history: ResMut<MoveHistory>,
+    mut ev_board: EventWriter<BoardEvent>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut q_texts: Query<(&TextInfo, &mut Transform, &mut Text)>,
+) {
+    let Some(puzzle) = active.puzzle.clone() else {
+        return;
+    };
+    if active.solved || !active.delay.tick(time.delta()).finished() {
+        return;
+    }
+    let mut chess = q_chess.single_mut();
+    let mut board = q_board.single_mut();
+    if active.take_back {
+        active.take_back = false;
+        if let Some(previous) = history.positions.pop() {
+            history.moves.pop();
+            history.san.pop();
+            *chess = previous;
+        }
+        board.resume_game();
+        for (text_info, mut transform, _) in q_texts.iter_mut() {
+            if text_info.text_type == 1 || text_info.text_type == 2 {
+                transform.scale = Vec3::splat(0.);
+            }
+        }
+        sync_board(
+            &mut commands,
+            &asset_server,
+            &chess,
+            &mut board,
+            &q_piece,
+            &q_overlays,
+        );
+        return;
+    }
+    if board.game_over || chess.white_turn == puzzle.solver_is_white() {
+        return;
+    }
+    let Some(mv) = puzzle
+        .moves
+        .get(history.moves.len())
+        .and_then(|uci| chess.parse_uci(uci))
+    else {
+        return;
+    };
+    let from = Chess::index_to_position(mv.from);
+    let to = Chess::index_to_position(mv.to);
+    ev_board.send(match mv.promotion {
+        Some(piece) => BoardEvent::PromotePiece(from, to, piece),
+        None => BoardEvent::MovePiece(from, to),
+    });
+    active.delay.reset();
+}
+
+pub fn show_puzzle(
+    puzzles: Res<PuzzleSet>,
+    stats: Res<PuzzleStats>,
+    active: Res<ActivePuzzle>,
+    mut q_text: Query<&mut Text, With<PuzzleText>>,
+) {
+    let mut text = match &active.puzzle {
+        None if puzzles.0.is_empty() => "No puzzles found".to_string(),
+        None => String::new(),
+        Some(puzzle) => {
+            let side = if puzzle.solver_is_white() {
+                "White"
+            } else {
+                "Black"
+            };
+            let mut text = format!(
+                "Puzzle {}, rated {}\nFind the best move for {side}",
+                puzzle.id, puzzle.rating
+            );
+            // The themes would give the answer away
+            if active.result.is_some() && !puzzle.themes.is_empty() {
+                text.push_str(&format!("\nThemes: {}", puzzle.themes.join(", ")));
+            }
+            text
+        }
+    };
+    text.push_str(&format!(
+        "\n\nRating {}\nStreak {} (best {})\nSolved {}, failed {}",
+        stats.rating, stats.streak, stats.best_streak, stats.solved, stats.failed
+    ));
+    for mut puzzle_text in q_text.iter_mut() {
+        puzzle_text.sections[0].value = text.clone();
+    }
+}
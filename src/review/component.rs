@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use chess::search::review::MoveReview;
+
+use crate::engine::chess::{Chess, Move};
+
+// The finished game under review, with each move's review as it comes in
+#[derive(Resource, Debug, Default)]
+pub struct GameReview {
+    // Positions before each move, the moves and their SAN
+    pub positions: Vec<Chess>,
+    pub moves: Vec<Move>,
+    pub san: Vec<String>,
+    // The position the game ended in
+    pub final_position: Chess,
+    // Depth the moves are searched to
+    pub depth: u8,
+    pub reviews: Vec<Option<MoveReview>>,
+    // The move shown, on the position it was played from; None shows the
+    // end of the game
+    pub selected: Option<usize>,
+}
+
+impl GameReview {
+    pub fn is_complete(&self) -> bool {
+        self.reviews.iter().all(Option::is_some)
+    }
+
+    // The moves reviewed so far
+    pub fn completed(&self) -> Vec<MoveReview> {
+        self.reviews.iter().flatten().copied().collect()
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct ReviewMoveButton {
+    pub ply: usize,
+}
+
+// The moves, scrolled up by `offset` pixels
+#[derive(Component, Debug, Default)]
+pub struct MoveList {
+    pub offset: f32,
+}
+
+// Accuracy of each side and how far the review has got
+#[derive(Component, Debug)]
+pub struct ReviewSummaryText;
+
+// The move shown against the engine's choice
+#[derive(Component, Debug)]
+pub struct ReviewMoveText;
@@ -0,0 +1,14 @@
+use bevy::color::*;
+
+// Move buttons, tinted by how the move was judged
+pub const MOVE_NORMAL: Srgba = Srgba::new(0.23, 0.24, 0.3, 1.);
+pub const MOVE_INACCURACY: Srgba = Srgba::new(0.55, 0.47, 0.12, 1.);
+pub const MOVE_MISTAKE: Srgba = Srgba::new(0.65, 0.33, 0.1, 1.);
+pub const MOVE_BLUNDER: Srgba = Srgba::new(0.62, 0.13, 0.13, 1.);
+pub const MOVE_SELECTED: Srgba = Srgba::new(0.482_352_94, 0.380_392_16, 1., 1.);
+
+pub const MOVE_BUTTON_WIDTH: f32 = 88.;
+pub const MOVE_BUTTON_HEIGHT: f32 = 24.;
+// Height of the move list before it scrolls
+pub const MOVE_LIST_HEIGHT: f32 = 300.;
+pub const SCROLL_STEP: f32 = 24.;
@@ -0,0 +1,37 @@
+mod component;
+mod constants;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::bot::ReviewWorker;
+use crate::chessboard::component::GameState;
+use component::*;
+use system::*;
+
+// Post-game review: the engine goes over every move of the finished game in
+// the background, and the moves can be stepped through with the engine's
+// choice drawn beside the move played
+pub struct ReviewPlugin;
+
+impl Plugin for ReviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameReview>()
+            .insert_non_send_resource(ReviewWorker::default())
+            .add_systems(OnEnter(GameState::Review), start_review)
+            .add_systems(OnExit(GameState::Review), stop_review)
+            .add_systems(
+                Update,
+                (
+                    collect_reviews,
+                    select_review_move,
+                    scroll_move_list,
+                    show_review
+                        .after(collect_reviews)
+                        .after(select_review_move)
+                        .run_if(resource_changed::<GameReview>),
+                )
+                    .run_if(in_state(GameState::Review)),
+            );
+    }
+}
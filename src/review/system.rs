@@ -0,0 +1,415 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use chess::search::analysis::score_text;
+use chess::search::review::{accuracy, Judgement, MoveReview};
+
+use super::component::*;
+use super::constants::*;
+use crate::bot::component::BotSettings;
+use crate::bot::ReviewWorker;
+use crate::chessboard::component::{
+    Annotation, AnnotationColor, Annotations, AnyOverlay, Board, GameState, MoveHistory, Piece,
+    TextInfo,
+};
+use crate::chessboard::system::sync_board;
+use crate::engine::chess::Chess;
+use crate::menu::{button_text_style, side_panel, spawn_button, MenuButton};
+
+// The move's number and SAN, with the judgement's symbol once reviewed
+fn move_label(review: &GameReview, ply: usize) -> String {
+    let chess = &review.positions[ply];
+    let number = if chess.white_turn {
+        format!("{}. ", chess.fullmove_number)
+    } else if ply == 0 {
+        format!("{}... ", chess.fullmove_number)
+    } else {
+        String::new()
+    };
+    let symbol = review.reviews[ply].map_or("", |reviewed| reviewed.judgement().symbol());
+    format!("{number}{}{symbol}", review.san[ply])
+}
+
+// Reviews the game just finished, unless it was already, and lays out its
+// moves beside the board
+#[allow(clippy::too_many_arguments)]
+pub fn start_review(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    q_chess: Query<&Chess>,
+    history: Res<MoveHistory>,
+    settings: Res<BotSettings>,
+    mut worker: NonSendMut<ReviewWorker>,
+    mut review: ResMut<GameReview>,
+    mut q_texts: Query<(&TextInfo, &mut Transform)>,
+) {
+    let chess = q_chess.single();
+    let same_game = review.moves == history.moves && review.final_position.hash() == chess.hash();
+    if !same_game || !review.is_complete() {
+        *review = GameReview {
+            positions: history.positions.clone(),
+            moves: history.moves.clone(),
+            san: history.san.clone(),
+            final_position: chess.clone(),
+            depth: settings.depth,
+            reviews: vec![None; history.moves.len()],
+            selected: None,
+        };
+        worker.start(review.positions.clone(), review.moves.clone(), review.depth);
+    }
+    review.selected = None;
+    // The game over banner would hide the board
+    for (text_info, mut transform) in q_texts.iter_mut() {
+        if text_info.text_type == 1 || text_info.text_type == 2 {
+            transform.scale = Vec3::splat(0.);
+        }
+    }
+
+    commands
+        .spawn((side_panel(), StateScoped(GameState::Review)))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Game review",
+                button_text_style(&asset_server, 32.),
+            ));
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 18.)),
+                ReviewSummaryText,
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(2. * MOVE_BUTTON_WIDTH + 4.),
+                        max_height: Val::Px(MOVE_LIST_HEIGHT),
+                        overflow: Overflow::clip_y(),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(100.),
+                                    flex_wrap: FlexWrap::Wrap,
+                                    align_content: AlignContent::FlexStart,
+                                    column_gap: Val::Px(4.),
+                                    row_gap: Val::Px(2.),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            MoveList::default(),
+                        ))
+                        .with_children(|parent| {
+                            // Black's moves stay in the right column
+                            if review
+                                .positions
+                                .first()
+                                .is_some_and(|chess| !chess.white_turn)
+                            {
+                                parent.spawn(NodeBundle {
+                                    style: Style {
+                                        width: Val::Px(MOVE_BUTTON_WIDTH),
+                                        height: Val::Px(MOVE_BUTTON_HEIGHT),
+                                        ..default()
+                                    },
+                                    ..default()
+                                });
+                            }
+                            for ply in 0..review.moves.len() {
+                                parent
+                                    .spawn((
+                                        ButtonBundle {
+                                            style: Style {
+                                                width: Val::Px(MOVE_BUTTON_WIDTH),
+                                                height: Val::Px(MOVE_BUTTON_HEIGHT),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..default()
+                                            },
+                                            background_color: MOVE_NORMAL.into(),
+                                            ..default()
+                                        },
+                                        ReviewMoveButton { ply },
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn(TextBundle::from_section(
+                                            move_label(&review, ply),
+                                            button_text_style(&asset_server, 15.),
+                                        ));
+                                    });
+                            }
+                        });
+                });
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 18.)),
+                ReviewMoveText,
+            ));
+            spawn_button(parent, &asset_server, MenuButton::Back);
+        });
+}
+
+// Stops reviewing and puts the end of the game back on the board, unless a
+// new game has already replaced it
+#[allow(clippy::too_many_arguments)]
+pub fn stop_review(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut worker: NonSendMut<ReviewWorker>,
+    review: Res<GameReview>,
+    history: Res<MoveHistory>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut annotations: ResMut<Annotations>,
+    mut q_texts: Query<(&TextInfo, &mut Transform)>,
+) {
+    worker.cancel();
+    annotations.shapes.clear();
+    if history.moves != review.moves {
+        return;
+    }
+    let mut chess = q_chess.single_mut();
+    let mut board = q_board.single_mut();
+    *chess = review.final_position.clone();
+    sync_board(
+        &mut commands,
+        &asset_server,
+        &chess,
+        &mut board,
+        &q_piece,
+        &q_overlays,
+    );
+    if board.game_over {
+        for (text_info, mut transform) in q_texts.iter_mut() {
+            if text_info.text_type == 1 || text_info.text_type == 2 {
+                transform.scale = Vec3::splat(1.);
+            }
+        }
+    }
+}
+
+pub fn collect_reviews(mut worker: NonSendMut<ReviewWorker>, mut review: ResMut<GameReview>) {
+    let reviewed = worker.progress();
+    if reviewed.is_empty() {
+        return;
+    }
+    for (ply, reviewed) in reviewed {
+        if let Some(slot) = review.reviews.get_mut(ply) {
+            *slot = Some(reviewed);
+        }
+    }
+}
+
+// Clicking a move shows it; the left and right arrow keys step through the
+// game, Home and End jump to its start and end, and Escape leaves the review
+pub fn select_review_move(
+    q_buttons: Query<(&Interaction, &ReviewMoveButton), Changed<Interaction>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut review: ResMut<GameReview>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let plies = review.moves.len();
+    let mut selected = review.selected;
+    for (interaction, button) in q_buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            selected = Some(button.ply);
+        }
+    }
+    if keys.just_pressed(KeyCode::ArrowLeft) {
+        selected = match selected {
+            Some(ply) => Some(ply.saturating_sub(1)),
+            None => plies.checked_sub(1),
+        };
+    }
+    if keys.just_pressed(KeyCode::ArrowRight) {
+        selected = selected.and_then(|ply| (ply + 1 < plies).then_some(ply + 1));
+    }
+    if keys.just_pressed(KeyCode::Home) && plies > 0 {
+        selected = Some(0);
+    }
+    if keys.just_pressed(KeyCode::End) {
+        selected = None;
+    }
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::GameOver);
+    }
+    if selected != review.selected {
+        review.selected = selected;
+    }
+}
+
+pub fn scroll_move_list(
+    mut ev_wheel: EventReader<MouseWheel>,
+    mut q_list: Query<(&mut MoveList, &mut Style, &Parent, &Node)>,
+    q_node: Query<&Node>,
+) {
+    let scrolled = ev_wheel
+        .read()
+        .map(|ev| match ev.unit {
+            MouseScrollUnit::Line => ev.y * SCROLL_STEP,
+            MouseScrollUnit::Pixel => ev.y,
+        })
+        .sum::<f32>();
+    if scrolled == 0. {
+        return;
+    }
+    for (mut list, mut style, parent, node) in q_list.iter_mut() {
+        let visible = q_node
+            .get(parent.get())
+            .map_or(0., |parent| parent.size().y);
+        let hidden = (node.size().y - visible).max(0.);
+        list.offset = (list.offset - scrolled).clamp(0., hidden);
+        style.top = Val::Px(-list.offset);
+    }
+}
+
+fn judgement_color(judgement: Judgement) -> Srgba {
+    match judgement {
+        Judgement::Best | Judgement::Good => MOVE_NORMAL,
+        Judgement::Inaccuracy => MOVE_INACCURACY,
+        Judgement::Mistake => MOVE_MISTAKE,
+        Judgement::Blunder => MOVE_BLUNDER,
+    }
+}
+
+// Each side's accuracy and count of inaccuracies, mistakes and blunders
+fn summary_text(review: &GameReview) -> String {
+    let reviews = review.completed();
+    let mut text = String::new();
+    for (white, name) in [(true, "White"), (false, "Black")] {
+        let Some(accuracy) = accuracy(&reviews, white) else {
+            continue;
+        };
+        let count = |judgement: Judgement| {
+            reviews
+                .iter()
+                .filter(|reviewed| reviewed.white == white && reviewed.judgement() == judgement)
+                .count()
+        };
+        text.push_str(&format!(
+            "{name}: {accuracy:.0}% accuracy\n{} ?!  {} ?  {} ??\n",
+            count(Judgement::Inaccuracy),
+            count(Judgement::Mistake),
+            count(Judgement::Blunder)
+        ));
+    }
+    if !review.is_complete() {
+        text.push_str(&format!(
+            "Reviewing.. {}/{} moves",
+            reviews.len(),
+            review.moves.len()
+        ));
+    }
+    text.trim_end().to_string()
+}
+
+// The move shown against the engine's choice
+fn move_text(review: &GameReview, ply: usize, reviewed: Option<&MoveReview>) -> String {
+    let label = move_label(review, ply);
+    let Some(reviewed) = reviewed else {
+        return format!("{label}\nReviewing..");
+    };
+    let best = review.positions[ply].to_san(&reviewed.best);
+    format!(
+        "{label} {}\nPlayed {}\nBest {best} {}",
+        reviewed.judgement().label().to_lowercase(),
+        score_text(reviewed.score, review.depth),
+        score_text(reviewed.best_score, review.depth)
+    )
+}
+
+// Puts the position of the move shown on the board, with the move played
+// drawn in the colour of its judgement and the engine's choice in green
+#[allow(clippy::too_many_arguments)]
+pub fn show_review(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    review: Res<GameReview>,
+    mut shown: Local<Option<Option<usize>>>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut annotations: ResMut<Annotations>,
+    mut q_buttons: Query<(&ReviewMoveButton, &mut BackgroundColor, &Children)>,
+    mut q_labels: Query<&mut Text, (Without<ReviewSummaryText>, Without<ReviewMoveText>)>,
+    mut q_summary: Query<&mut Text, (With<ReviewSummaryText>, Without<ReviewMoveText>)>,
+    mut q_move_text: Query<&mut Text, (With<ReviewMoveText>, Without<ReviewSummaryText>)>,
+) {
+    if *shown != Some(review.selected) {
+        *shown = Some(review.selected);
+        let mut chess = q_chess.single_mut();
+        let mut board = q_board.single_mut();
+        *chess = review
+            .selected
+            .and_then(|ply| review.positions.get(ply))
+            .unwrap_or(&review.final_position)
+            .clone();
+        sync_board(
+            &mut commands,
+            &asset_server,
+            &chess,
+            &mut board,
+            &q_piece,
+            &q_overlays,
+        );
+    }
+
+    let mut shapes = Vec::new();
+    if let Some(ply) = review.selected {
+        let played = review.moves[ply];
+        let reviewed = review.reviews[ply];
+        let color = match reviewed.map(|reviewed| reviewed.judgement()) {
+            Some(Judgement::Inaccuracy) => AnnotationColor::Yellow,
+            Some(Judgement::Mistake | Judgement::Blunder) => AnnotationColor::Red,
+            _ => AnnotationColor::Blue,
+        };
+        shapes.push(Annotation::Arrow {
+            from: played.from,
+            to: played.to,
+            color,
+        });
+        if let Some(best) = reviewed
+            .map(|reviewed| reviewed.best)
+            .filter(|best| (best.from, best.to) != (played.from, played.to))
+        {
+            shapes.push(Annotation::Arrow {
+                from: best.from,
+                to: best.to,
+                color: AnnotationColor::Green,
+            });
+        }
+    }
+    if annotations.shapes != shapes {
+        annotations.shapes = shapes;
+    }
+
+    for (button, mut background, children) in q_buttons.iter_mut() {
+        *background = if review.selected == Some(button.ply) {
+            MOVE_SELECTED.into()
+        } else {
+            review.reviews[button.ply]
+                .map_or(MOVE_NORMAL, |reviewed| {
+                    judgement_color(reviewed.judgement())
+                })
+                .into()
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = q_labels.get_mut(*child) {
+                text.sections[0].value = move_label(&review, button.ply);
+            }
+        }
+    }
+    for mut text in q_summary.iter_mut() {
+        text.sections[0].value = summary_text(&review);
+    }
+    for mut text in q_move_text.iter_mut() {
+        text.sections[0].value = match review.selected {
+            Some(ply) => move_text(&review, ply, review.reviews[ply].as_ref()),
+            None => "End of the game.\nPick a move or use\nthe arrow keys".to_string(),
+        };
+    }
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::bot::component::BotSettings;
+use crate::chessboard::component::{Clock, Players};
+
+// Everything needed to pick a game up where it was left
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGame {
+    // Position the game started from
+    pub fen: String,
+    // Moves played since, in UCI notation
+    pub moves: Vec<String>,
+    pub clock: Clock,
+    pub players: Players,
+    pub bot: BotSettings,
+}
+
+// The unfinished game found in storage, if any
+#[derive(Resource, Debug, Default)]
+pub struct LastGame(pub Option<SavedGame>);
@@ -0,0 +1,31 @@
+mod component;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::component::GameState;
+use crate::chessboard::system::{handle_board_event, handle_game_command};
+use crate::storage;
+pub use component::LastGame;
+use system::*;
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LastGame(storage::load_json("game")))
+            .add_systems(
+                Update,
+                (
+                    resume_last_game.before(handle_game_command),
+                    // Analysis and puzzles leave the saved game alone
+                    autosave_game
+                        .after(handle_board_event)
+                        .after(handle_game_command)
+                        .run_if(not(
+                            in_state(GameState::Analysis).or_else(in_state(GameState::Puzzle))
+                        )),
+                ),
+            );
+    }
+}
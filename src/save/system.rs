@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use super::component::*;
+use crate::bot::component::BotSettings;
+use crate::chessboard::component::{Board, Clock, GameCommand, GameEvent, MoveHistory, Players};
+use crate::engine::chess::Chess;
+use crate::storage;
+
+// Saves the game after every move, take back and loaded game, and forgets
+// it once it is over
+#[allow(clippy::too_many_arguments)]
+pub fn autosave_game(
+    mut ev_game: EventReader<GameEvent>,
+    mut ev_command: EventReader<GameCommand>,
+    mut last_game: ResMut<LastGame>,
+    q_chess: Query<&Chess>,
+    q_board: Query<&Board>,
+    history: Res<MoveHistory>,
+    clock: Res<Clock>,
+    players: Res<Players>,
+    bot: Res<BotSettings>,
+) {
+    let mut changed = false;
+    for ev in ev_game.read() {
+        changed |= matches!(
+            ev,
+            GameEvent::Moved(_) | GameEvent::TimeOut | GameEvent::Resigned | GameEvent::DrawAgreed
+        );
+    }
+    for command in ev_command.read() {
+        changed |= matches!(command, GameCommand::Undo | GameCommand::LoadGame { .. });
+    }
+    // Online games live on the relay server
+    if !changed || players.is_online() {
+        return;
+    }
+    if q_board.single().game_over {
+        storage::remove("game");
+        last_game.0 = None;
+        return;
+    }
+    let start = history.positions.first().unwrap_or(q_chess.single());
+    let saved = SavedGame {
+        fen: start.to_fen(),
+        moves: history.moves.iter().map(|mv| mv.to_uci()).collect(),
+        clock: clock.clone(),
+        players: players.clone(),
+        bot: bot.clone(),
+    };
+    storage::save_json("game", &saved);
+    last_game.0 = Some(saved);
+}
+
+pub fn resume_last_game(
+    mut ev_command: ParamSet<(EventReader<GameCommand>, EventWriter<GameCommand>)>,
+    last_game: Res<LastGame>,
+    mut clock: ResMut<Clock>,
+    mut players: ResMut<Players>,
+    mut bot: ResMut<BotSettings>,
+) {
+    let resume = ev_command
+        .p0()
+        .read()
+        .any(|command| matches!(command, GameCommand::ResumeGame));
+    let Some(saved) = last_game.0.as_ref().filter(|_| resume) else {
+        return;
+    };
+    *clock = saved.clock.clone();
+    *players = saved.players.clone();
+    // The thread count stays the one for this machine
+    *bot = BotSettings {
+        threads: bot.threads,
+        ..saved.bot.clone()
+    };
+    ev_command.p1().send(GameCommand::LoadGame {
+        fen: saved.fen.clone(),
+        moves: saved.moves.clone(),
+    });
+}
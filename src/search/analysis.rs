@@ -0,0 +1,104 @@
+// Analysis of a position for study rather than play. Every move is searched
+// to an exact score, one ply deeper at a time, and after each iteration the
+// best few are reported with the line the search expects to follow each of
+// them (MultiPV). Scores favour White, as in the bot's search.
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use super::minimax::{Search, MATE_SCORE, TABLEBASE_WIN};
+use super::syzygy::Tablebase;
+use crate::engine::chess::{Chess, Move};
+
+// Moves shown of each line, the first included
+const LINE_LENGTH: usize = 8;
+
+// Scores within this of a mate or tablebase win are ones, found that many
+// plies from the end of the search
+const MAX_PLIES: i32 = u8::MAX as i32;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Line {
+    pub moves: Vec<Move>,
+    pub score: i32,
+}
+
+// How far the analysis has got, with the lines of the deepest iteration
+// completed, best first for the side to move
+#[derive(Clone, Debug, Default)]
+pub struct Analysis {
+    pub depth: u8,
+    pub nodes: u64,
+    pub lines: Vec<Line>,
+}
+
+impl Analysis {
+    // The score of the best line, None before the first iteration or when
+    // the game is over
+    pub fn score(&self) -> Option<i32> {
+        self.lines.first().map(|line| line.score)
+    }
+
+    // A score of this analysis as shown to the player, see `score_text`
+    pub fn score_text(&self, score: i32) -> String {
+        score_text(score, self.depth)
+    }
+}
+
+// A score from a search to `depth` as shown to the player: pawns with a
+// sign, `#3` or `#-3` for White or Black mating in that many moves, and
+// `TB win` or `TB loss` for tablebase results
+pub fn score_text(score: i32, depth: u8) -> String {
+    let sign = if score < 0 { "-" } else { "" };
+    if score.abs() >= MATE_SCORE {
+        let plies = depth as i32 - (score.abs() - MATE_SCORE);
+        format!("#{sign}{}", (plies + 1).max(1) / 2)
+    } else if score.abs() >= TABLEBASE_WIN - MAX_PLIES {
+        if score > 0 { "TB win" } else { "TB loss" }.to_string()
+    } else {
+        format!("{:+.1}", score as f32 / 10.)
+    }
+}
+
+// Analyses `chess` one ply deeper at a time up to `depth`, reporting the best
+// `lines` moves after each iteration, and returns the last report. Stops
+// early once `stop` is set. `history` holds the hashes of the game's
+// positions before this one, so repeating them scores as a draw.
+pub fn analyse(
+    chess: &Chess,
+    history: &[u64],
+    depth: u8,
+    lines: usize,
+    tablebase: Option<Arc<Tablebase>>,
+    stop: &AtomicBool,
+    report: &mut dyn FnMut(&Analysis),
+) -> Analysis {
+    let mut ignore = |_: &_| {};
+    let mut search = Search::new(stop, &mut ignore)
+        .with_tablebase(tablebase)
+        .with_history(history.to_vec())
+        .deterministic();
+    let mut analysis = Analysis::default();
+    for iteration in 1..=depth {
+        let Some(scores) = search.score_moves(chess, iteration) else {
+            break;
+        };
+        if scores.is_empty() {
+            break;
+        }
+        analysis.depth = iteration;
+        analysis.nodes = search.progress().nodes;
+        analysis.lines = scores
+            .into_iter()
+            .take(lines)
+            .map(|(mv, score)| {
+                let mut after = chess.clone();
+                after.play(&mv);
+                let mut moves = vec![mv];
+                moves.extend(search.principal_variation(&after, LINE_LENGTH - 1));
+                Line { moves, score }
+            })
+            .collect();
+        report(&analysis);
+    }
+    analysis
+}
@@ -0,0 +1,137 @@
+// Opening books in the Polyglot format: 16-byte big-endian entries of the
+// position's Polyglot hash, a move, a weight and four bytes of learning data,
+// sorted by hash. Moves pack the destination file and rank in bits 0-5, the
+// origin in 6-11 and the promotion piece (1 knight to 4 queen) in 12-14;
+// castling is written as the king taking its own rook.
+use rand::{thread_rng, Rng};
+
+use crate::engine::chess::{Chess, Move};
+use crate::engine::notation::square_name;
+
+// Promotion pieces in Polyglot's order, as UCI letters
+const PROMOTIONS: [&str; 5] = ["", "n", "b", "r", "q"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BookEntry {
+    pub key: u64,
+    pub mv: u16,
+    pub weight: u16,
+}
+
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
+#[derive(Clone, Debug, Default)]
+pub struct OpeningBook {
+    entries: Vec<BookEntry>,
+}
+
+impl OpeningBook {
+    // Reads a book file, ignoring a truncated entry at the end
+    pub fn from_bytes(bytes: &[u8]) -> OpeningBook {
+        OpeningBook::from_entries(
+            bytes
+                .chunks_exact(16)
+                .map(|chunk| BookEntry {
+                    key: u64::from_be_bytes(chunk[0..8].try_into().unwrap_or_default()),
+                    mv: u16::from_be_bytes([chunk[8], chunk[9]]),
+                    weight: u16::from_be_bytes([chunk[10], chunk[11]]),
+                })
+                .collect(),
+        )
+    }
+
+    pub fn from_entries(mut entries: Vec<BookEntry>) -> OpeningBook {
+        entries.sort_by_key(|entry| entry.key);
+        OpeningBook { entries }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.entries.len() * 16);
+        for entry in &self.entries {
+            bytes.extend_from_slice(&entry.key.to_be_bytes());
+            bytes.extend_from_slice(&entry.mv.to_be_bytes());
+            bytes.extend_from_slice(&entry.weight.to_be_bytes());
+            bytes.extend_from_slice(&[0; 4]);
+        }
+        bytes
+    }
+
+    // The small book shipped in `assets/book.bin`, covering the main lines
+    // of the common openings
+    pub fn bundled() -> OpeningBook {
+        OpeningBook::from_bytes(include_bytes!("../../assets/book.bin"))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // The book's legal moves in the position with their weights. Entries
+    // whose move isn't legal, as from a hash collision, are left out.
+    pub fn moves(&self, chess: &Chess) -> Vec<(Move, u16)> {
+        let key = chess.hash();
+        let start = self.entries.partition_point(|entry| entry.key < key);
+        self.entries[start..]
+            .iter()
+            .take_while(|entry| entry.key == key)
+            .filter_map(|entry| Some((decode_move(chess, entry.mv)?, entry.weight)))
+            .collect()
+    }
+
+    // One of the book's moves, picked at random in proportion to the weights
+    pub fn pick(&self, chess: &Chess) -> Option<Move> {
+        let moves = self.moves(chess);
+        let total = moves.iter().map(|(_, weight)| *weight as u32).sum::<u32>();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = thread_rng().gen_range(0..total);
+        moves.into_iter().find_map(|(mv, weight)| {
+            if roll < weight as u32 {
+                Some(mv)
+            } else {
+                roll -= weight as u32;
+                None
+            }
+        })
+    }
+}
+
+pub fn encode_move(chess: &Chess, mv: &Move) -> u16 {
+    let mut to = mv.to;
+    if is_king(chess, mv.from) && mv.from.abs_diff(mv.to) == 2 {
+        // castling: to the rook's corner on the king's side of the move
+        to = if mv.to > mv.from {
+            mv.from + 3
+        } else {
+            mv.from - 4
+        };
+    }
+    let promotion = mv.promotion.map_or(0, |piece| match piece % 6 {
+        2 => 1,
+        3 => 2,
+        1 => 3,
+        _ => 4,
+    });
+    promotion << 12 | (mv.from as u16) << 6 | to as u16
+}
+
+// The legal move `mv` stands for in the position, if there is one
+pub fn decode_move(chess: &Chess, mv: u16) -> Option<Move> {
+    let from = (mv >> 6 & 63) as u8;
+    let mut to = (mv & 63) as u8;
+    if is_king(chess, from) && from % 8 == 4 && from / 8 == to / 8 {
+        // the king taking its own rook is castling
+        to = match to % 8 {
+            7 => from + 2,
+            0 => from - 2,
+            _ => to,
+        };
+    }
+    let promotion = PROMOTIONS.get((mv >> 12 & 7) as usize)?;
+    let uci = format!("{}{}{promotion}", square_name(from), square_name(to));
+    chess.parse_uci(&uci)
+}
+
+fn is_king(chess: &Chess, idx: u8) -> bool {
+    (chess.pieces[5] | chess.pieces[11]) & 1 << idx > 0
+}
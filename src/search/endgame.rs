@@ -0,0 +1,206 @@
+// Endgames the material count alone can't play. Against a bare king the
+// winning side is steered to drive the king to the edge, or for bishop and
+// knight to a corner the bishop covers, and to bring its own king up; known
+// draws score as draws whatever the material says. Squares are (file, rank)
+// from 0 to 7, a1 being (0, 0).
+use crate::engine::chess::Chess;
+
+// Added for the side mating a bare king, so it heads for these endgames and
+// keeps its pieces out of the lone king's reach
+const KNOWN_WIN: i32 = 1000;
+
+// Indices into `Chess::pieces` of each side's pieces
+const PAWN: usize = 0;
+const ROOK: usize = 1;
+const KNIGHT: usize = 2;
+const BISHOP: usize = 3;
+const QUEEN: usize = 4;
+const KING: usize = 5;
+
+const LIGHT_SQUARES: u64 = 0x55aa_55aa_55aa_55aa;
+const A_FILE: u64 = 0x0101_0101_0101_0101;
+const H_FILE: u64 = A_FILE << 7;
+
+// The evaluation in White's favour, `material` being the plain count, when
+// the position is one of the endgames known here
+pub fn evaluate(chess: &Chess, material: i32) -> Option<i32> {
+    if is_draw(chess) {
+        return Some(0);
+    }
+    for white in [true, false] {
+        if let Some(bonus) = mating_bonus(chess, white) {
+            return Some(material + if white { bonus } else { -bonus });
+        }
+    }
+    None
+}
+
+fn side(chess: &Chess, white: bool) -> &[u64] {
+    if white {
+        &chess.pieces[..6]
+    } else {
+        &chess.pieces[6..]
+    }
+}
+
+fn square(bits: u64) -> (i32, i32) {
+    let idx = bits.trailing_zeros() as i32;
+    (idx % 8, idx / 8)
+}
+
+fn distance(a: (i32, i32), b: (i32, i32)) -> i32 {
+    (a.0 - b.0).abs().max((a.1 - b.1).abs())
+}
+
+// Steps from the centre, 0 on the four middle squares to 6 in a corner
+fn centre_distance((file, rank): (i32, i32)) -> i32 {
+    (3 - file.min(7 - file)) + (3 - rank.min(7 - rank))
+}
+
+// Whether the side with `white` has a bare king against it and enough to
+// mate it, and if so whether that is bishop and knight, which need a corner
+// the bishop covers
+fn mating_material(chess: &Chess, white: bool) -> Option<bool> {
+    let strong = side(chess, white);
+    let weak = side(chess, !white);
+    if weak[..KING].iter().any(|bits| *bits > 0) {
+        return None;
+    }
+    let bishops = strong[BISHOP];
+    let both_bishop_colours = bishops & LIGHT_SQUARES > 0 && bishops & !LIGHT_SQUARES > 0;
+    if strong[QUEEN] > 0 || strong[ROOK] > 0 || both_bishop_colours {
+        Some(false)
+    } else if bishops > 0 && strong[KNIGHT] > 0 {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+// Whether one side is mating a bare king. These few pieces are cheap to
+// search, and a ply more lets the search see the net closing.
+pub fn is_basic_mate(chess: &Chess) -> bool {
+    [true, false]
+        .iter()
+        .any(|&white| mating_material(chess, white).is_some())
+}
+
+// What the side with `white` gains for mating material against a bare king:
+// more the nearer the lone king is to its edge or corner and to the
+// attacking king, and the fewer squares it has
+fn mating_bonus(chess: &Chess, white: bool) -> Option<i32> {
+    let bishop_and_knight = mating_material(chess, white)?;
+    let strong = side(chess, white);
+    let king = square(strong[KING]);
+    let lone_king = square(side(chess, !white)[KING]);
+    // A piece the lone king is about to take may leave too little to mate
+    if chess.white_turn != white && hanging_piece(chess, strong, lone_king, white) {
+        return None;
+    }
+    let boxed_in = 8 - king_mobility(chess, !white);
+    let cornered = if bishop_and_knight {
+        // Bishop and knight only mate in a corner of the bishop's colour,
+        // the squares furthest from the long diagonal of the other colour
+        let from_diagonal = if strong[BISHOP] & LIGHT_SQUARES > 0 {
+            (lone_king.0 - lone_king.1).abs()
+        } else {
+            (lone_king.0 + lone_king.1 - 7).abs()
+        };
+        80 * from_diagonal + 10 * centre_distance(lone_king) + 4 * boxed_in
+    } else {
+        40 * centre_distance(lone_king) + 8 * boxed_in
+    };
+    Some(KNOWN_WIN + cornered + 4 * (7 - distance(king, lone_king)))
+}
+
+// Whether a piece of the side with `white` stands undefended next to the
+// other side's king
+fn hanging_piece(chess: &Chess, strong: &[u64], lone_king: (i32, i32), white: bool) -> bool {
+    strong[..KING].iter().any(|&bits| {
+        Chess::indices_of_set_bits(bits).into_iter().any(|idx| {
+            distance(square(1 << idx), lone_king) <= 1 && !chess.is_square_attacked(idx, white)
+        })
+    })
+}
+
+// Squares the king of the side with `white` could step to, found with the king
+// off the board so it doesn't hide squares behind it from sliding pieces.
+// Only exact when that side has nothing but its king.
+fn king_mobility(chess: &Chess, white: bool) -> i32 {
+    let king = side(chess, white)[KING];
+    let (file, rank) = square(king);
+    let mut board = chess.clone();
+    board.pieces[if white { KING } else { KING + 6 }] = 0;
+    let mut free = 0;
+    for df in -1..=1 {
+        for dr in -1..=1 {
+            let (f, r) = (file + df, rank + dr);
+            if (df, dr) != (0, 0)
+                && (0..8).contains(&f)
+                && (0..8).contains(&r)
+                && !board.is_square_attacked((r * 8 + f) as u8, !white)
+            {
+                free += 1;
+            }
+        }
+    }
+    free
+}
+
+fn is_draw(chess: &Chess) -> bool {
+    // A bare king to move that isn't in check and has nowhere to go is stalemated
+    let to_move = side(chess, chess.white_turn);
+    if to_move[..KING].iter().all(|bits| *bits == 0)
+        && chess.checkers() == 0
+        && king_mobility(chess, chess.white_turn) == 0
+    {
+        return true;
+    }
+    let pawns = chess.pieces[PAWN] | chess.pieces[PAWN + 6];
+    let majors =
+        chess.pieces[ROOK] | chess.pieces[QUEEN] | chess.pieces[ROOK + 6] | chess.pieces[QUEEN + 6];
+    if pawns == 0 && majors == 0 {
+        let minors = |white: bool| {
+            let side = side(chess, white);
+            (side[KNIGHT].count_ones(), side[BISHOP].count_ones())
+        };
+        return match (minors(true), minors(false)) {
+            // At most a minor piece each, or two knights against a bare king
+            ((n, b), (n2, b2)) if n + b <= 1 && n2 + b2 <= 1 => true,
+            ((2, 0), (0, 0)) | ((0, 0), (2, 0)) => true,
+            _ => false,
+        };
+    }
+    [true, false]
+        .iter()
+        .any(|&white| wrong_rook_pawn(chess, white))
+}
+
+// Rook pawns with no bishop, or only bishops that can't cover the queening
+// square, don't win once the defending king holds the corner in front
+fn wrong_rook_pawn(chess: &Chess, white: bool) -> bool {
+    let strong = side(chess, white);
+    let weak = side(chess, !white);
+    let pawns = strong[PAWN];
+    if pawns == 0
+        || weak[..KING].iter().any(|bits| *bits > 0)
+        || [ROOK, KNIGHT, QUEEN].iter().any(|&piece| strong[piece] > 0)
+    {
+        return false;
+    }
+    let file = if pawns & !A_FILE == 0 {
+        0
+    } else if pawns & !H_FILE == 0 {
+        7
+    } else {
+        return false;
+    };
+    let corner = (file, if white { 7 } else { 0 });
+    let corner_is_light = (corner.0 + corner.1) % 2 == 1;
+    let corner_mask = if corner_is_light {
+        LIGHT_SQUARES
+    } else {
+        !LIGHT_SQUARES
+    };
+    strong[BISHOP] & corner_mask == 0 && distance(square(weak[KING]), corner) <= 1
+}
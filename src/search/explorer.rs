@@ -0,0 +1,137 @@
+// Opening trees built from a game database: for each position, the moves
+// played from it and how the games went. The index is stored as 22-byte
+// big-endian entries of the position's Polyglot hash, the move encoded as in
+// Polyglot books, and the white wins, draws and black wins after it, sorted by
+// hash and move.
+use std::collections::BTreeMap;
+
+use super::book::{decode_move, encode_move};
+use crate::engine::chess::{Chess, Move};
+use crate::engine::pgn::PgnGame;
+
+const ENTRY_LEN: usize = 22;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExplorerEntry {
+    pub key: u64,
+    pub mv: u16,
+    // White wins, draws and black wins in games that played the move
+    pub results: [u32; 3],
+}
+
+// A move played in the position, with how its games ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExplorerMove {
+    pub mv: Move,
+    pub results: [u32; 3],
+}
+
+impl ExplorerMove {
+    pub fn games(&self) -> u32 {
+        self.results.iter().sum()
+    }
+
+    // White wins, draws and black wins as percentages of the games
+    pub fn percentages(&self) -> [f32; 3] {
+        let games = self.games().max(1) as f32;
+        self.results.map(|count| count as f32 * 100. / games)
+    }
+}
+
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
+#[derive(Clone, Debug, Default)]
+pub struct OpeningIndex {
+    entries: Vec<ExplorerEntry>,
+}
+
+impl OpeningIndex {
+    // Indexes the first `max_plies` moves of each finished game. Games are
+    // followed up to their first illegal move.
+    pub fn build<'a>(
+        games: impl IntoIterator<Item = &'a PgnGame>,
+        max_plies: usize,
+    ) -> OpeningIndex {
+        let mut counts = BTreeMap::<(u64, u16), [u32; 3]>::new();
+        for game in games {
+            let outcome = match game.result.as_str() {
+                "1-0" => 0,
+                "1/2-1/2" => 1,
+                "0-1" => 2,
+                _ => continue,
+            };
+            let Some((positions, moves)) = game.replay() else {
+                continue;
+            };
+            for (chess, mv) in positions.iter().zip(&moves).take(max_plies) {
+                counts
+                    .entry((chess.hash(), encode_move(chess, mv)))
+                    .or_default()[outcome] += 1;
+            }
+        }
+        OpeningIndex {
+            entries: counts
+                .into_iter()
+                .map(|((key, mv), results)| ExplorerEntry { key, mv, results })
+                .collect(),
+        }
+    }
+
+    // Reads an index file, ignoring a truncated entry at the end
+    pub fn from_bytes(bytes: &[u8]) -> OpeningIndex {
+        let mut entries = bytes
+            .chunks_exact(ENTRY_LEN)
+            .map(|chunk| {
+                let count = |at: usize| {
+                    u32::from_be_bytes(chunk[at..at + 4].try_into().unwrap_or_default())
+                };
+                ExplorerEntry {
+                    key: u64::from_be_bytes(chunk[0..8].try_into().unwrap_or_default()),
+                    mv: u16::from_be_bytes([chunk[8], chunk[9]]),
+                    results: [count(10), count(14), count(18)],
+                }
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|entry| (entry.key, entry.mv));
+        OpeningIndex { entries }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.entries.len() * ENTRY_LEN);
+        for entry in &self.entries {
+            bytes.extend_from_slice(&entry.key.to_be_bytes());
+            bytes.extend_from_slice(&entry.mv.to_be_bytes());
+            for count in entry.results {
+                bytes.extend_from_slice(&count.to_be_bytes());
+            }
+        }
+        bytes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Positions and moves indexed
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    // The legal moves played in the position, most played first. Entries
+    // whose move isn't legal, as from a hash collision, are left out.
+    pub fn moves(&self, chess: &Chess) -> Vec<ExplorerMove> {
+        let key = chess.hash();
+        let start = self.entries.partition_point(|entry| entry.key < key);
+        let mut moves = self.entries[start..]
+            .iter()
+            .take_while(|entry| entry.key == key)
+            .filter_map(|entry| {
+                Some(ExplorerMove {
+                    mv: decode_move(chess, entry.mv)?,
+                    results: entry.results,
+                })
+            })
+            .collect::<Vec<_>>();
+        moves.sort_by_key(|explored| std::cmp::Reverse(explored.games()));
+        moves
+    }
+}
@@ -0,0 +1,411 @@
+use std::cmp::{max, min};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use rand::{seq::SliceRandom, thread_rng};
+
+use super::endgame;
+use super::syzygy::{Tablebase, Wdl};
+use super::tt::{Bound, Entry, TranspositionTable};
+use crate::engine::chess::{Chess, LastMove, Move};
+
+const PIECES_WEIGHT: [i32; 12] = [10, 30, 30, 50, 90, 900, -10, -30, -30, -50, -90, -900];
+pub(crate) const MATE_SCORE: i32 = 100_000;
+// Tablebase wins rank below any mate the search sees
+pub(crate) const TABLEBASE_WIN: i32 = MATE_SCORE / 2;
+
+pub fn evaluate(chess: &Chess) -> i32 {
+    let mut total = 0;
+    for (idx, piece) in chess.pieces.iter().enumerate() {
+        let n = piece.count_ones();
+        total += (n as i32) * PIECES_WEIGHT[idx];
+    }
+    endgame::evaluate(chess, total).unwrap_or(total)
+}
+
+// The bot plays black, so it takes a draw only when its evaluation says it is behind
+pub fn accepts_draw(chess: &Chess) -> bool {
+    evaluate(chess) > 0
+}
+
+// How far a search has got
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SearchProgress {
+    // Deepest iteration completed
+    pub depth: u8,
+    // Positions visited so far
+    pub nodes: u64,
+    // Best move of the deepest iteration, and its evaluation
+    pub best: Option<Move>,
+    pub score: i32,
+}
+
+// Positions searched between progress reports within an iteration
+const PROGRESS_INTERVAL: u64 = 4096;
+
+// An iterative deepening minimax search. It searches one ply deeper at a time,
+// reporting after each iteration and every `PROGRESS_INTERVAL` positions, and
+// gives up as soon as `stop` is set. Scores and best moves are kept in a
+// transposition table, which searches on other threads may share.
+pub struct Search<'a> {
+    stop: &'a AtomicBool,
+    report: &'a mut dyn FnMut(&SearchProgress),
+    progress: SearchProgress,
+    table: Arc<TranspositionTable>,
+    // Takes the first of equally good moves rather than one at random
+    deterministic: bool,
+    // Tries moves in a random order, so helper threads search apart
+    shuffle: bool,
+    tablebase: Option<Arc<Tablebase>>,
+    // Hashes of the positions before the root, oldest first, followed by
+    // those on the line being searched
+    line: Vec<u64>,
+    // Positions after each move from the root with their exact scores, from
+    // the last iteration
+    root_moves: Vec<(Chess, i32)>,
+    // Where in `line` the earliest position repeated below the node being
+    // searched is. The draw scores of repetitions depend on how that position
+    // was reached, so the scores of nodes further along the line aren't kept
+    // in the table.
+    repeated_from: usize,
+}
+
+impl<'a> Search<'a> {
+    pub fn new(stop: &'a AtomicBool, report: &'a mut dyn FnMut(&SearchProgress)) -> Search<'a> {
+        Search {
+            stop,
+            report,
+            progress: SearchProgress::default(),
+            table: Arc::default(),
+            deterministic: false,
+            shuffle: false,
+            tablebase: None,
+            line: Vec::new(),
+            root_moves: Vec::new(),
+            repeated_from: usize::MAX,
+        }
+    }
+
+    pub fn with_table(mut self, table: Arc<TranspositionTable>) -> Search<'a> {
+        self.table = table;
+        self
+    }
+
+    pub fn with_tablebase(mut self, tablebase: Option<Arc<Tablebase>>) -> Search<'a> {
+        self.tablebase = tablebase;
+        self
+    }
+
+    // The game so far, as the hashes of its positions before the one searched.
+    // Lines repeating one of them, or themselves, score as draws.
+    pub fn with_history(mut self, history: Vec<u64>) -> Search<'a> {
+        self.line = history;
+        self
+    }
+
+    // Always finds the same move in the same position, for tests and benchmarks
+    pub fn deterministic(mut self) -> Search<'a> {
+        self.deterministic = true;
+        self
+    }
+
+    pub(crate) fn shuffled(mut self) -> Search<'a> {
+        self.shuffle = true;
+        self
+    }
+
+    // The best move found at `depth`, pawns being promoted to queens. None
+    // when the game is over or the search was stopped.
+    pub fn run(&mut self, chess: &Chess, depth: u8) -> Option<Move> {
+        // With few enough pieces the tablebase knows the best move outright
+        if let Some((best, wdl)) = self
+            .tablebase
+            .as_ref()
+            .and_then(|tablebase| tablebase.best_move(chess))
+        {
+            self.progress.depth = depth;
+            self.progress.best = Some(best);
+            self.progress.score = tablebase_score(chess, wdl, depth);
+            (self.report)(&self.progress);
+            return Some(best);
+        }
+        // Against a bare king the few pieces left are searched a ply deeper,
+        // which the bishop and knight mate needs
+        let depth = depth + u8::from(endgame::is_basic_mate(chess));
+        for iteration in 1..=depth {
+            let (after, score) =
+                self.minimax(chess, iteration, chess.white_turn, i32::MIN, i32::MAX, true);
+            if self.stopped() {
+                return None;
+            }
+            let LastMove(from, to) = after?.last_move?;
+            self.progress.depth = iteration;
+            self.progress.best = Some(Move {
+                from,
+                to,
+                promotion: None,
+            });
+            self.progress.score = score;
+            (self.report)(&self.progress);
+        }
+        self.progress.best
+    }
+
+    // Every move from `chess` with its score at `depth`, best first for the
+    // side to move. None once the search is stopped.
+    pub(crate) fn score_moves(&mut self, chess: &Chess, depth: u8) -> Option<Vec<(Move, i32)>> {
+        self.root_moves.clear();
+        self.minimax(chess, depth, chess.white_turn, i32::MIN, i32::MAX, true);
+        if self.stopped() {
+            return None;
+        }
+        let mut scores = self
+            .root_moves
+            .drain(..)
+            .filter_map(|(after, score)| {
+                let LastMove(from, to) = after.last_move?;
+                Some((
+                    Move {
+                        from,
+                        to,
+                        promotion: None,
+                    },
+                    score,
+                ))
+            })
+            .collect::<Vec<_>>();
+        // Stable, so equal moves keep the order they were searched in
+        if chess.white_turn {
+            scores.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        } else {
+            scores.sort_by_key(|(_, score)| *score);
+        }
+        Some(scores)
+    }
+
+    // The moves the table expects from `chess` on, up to `length` of them,
+    // stopping where a position would repeat
+    pub(crate) fn principal_variation(&self, chess: &Chess, length: usize) -> Vec<Move> {
+        let mut chess = chess.clone();
+        let mut seen = vec![chess.hash()];
+        let mut moves = Vec::new();
+        while moves.len() < length {
+            let Some((from, to)) = self.table.probe(chess.hash()).and_then(|entry| entry.best)
+            else {
+                break;
+            };
+            let mv = Move {
+                from,
+                to,
+                promotion: None,
+            };
+            if chess.play(&mv).is_none() || seen.contains(&chess.hash()) {
+                break;
+            }
+            seen.push(chess.hash());
+            moves.push(mv);
+        }
+        moves
+    }
+
+    pub fn progress(&self) -> &SearchProgress {
+        &self.progress
+    }
+
+    fn stopped(&self) -> bool {
+        self.stop.load(Ordering::Relaxed)
+    }
+
+    // Where in `line` the position came up before, since the last capture or
+    // pawn move made going back to it impossible
+    fn repeats(&self, chess: &Chess, hash: u64) -> Option<usize> {
+        self.line
+            .iter()
+            .enumerate()
+            .rev()
+            .take(chess.halfmove_clock as usize)
+            .find(|(_, seen)| **seen == hash)
+            .map(|(idx, _)| idx)
+    }
+
+    // The position after the best move and its evaluation. Gives up with no
+    // position once the search is stopped. Below the root, where only the
+    // score matters, a good enough table entry answers straight away.
+    fn minimax(
+        &mut self,
+        chess: &Chess,
+        depth: u8,
+        is_maximizing: bool,
+        alpha: i32,
+        beta: i32,
+        root: bool,
+    ) -> (Option<Chess>, i32) {
+        if self.stopped() {
+            return (None, 0);
+        }
+        self.progress.nodes += 1;
+        if self.progress.nodes.is_multiple_of(PROGRESS_INTERVAL) {
+            (self.report)(&self.progress);
+        }
+        let hash = chess.hash();
+        if let Some(idx) = self.repeats(chess, hash).filter(|_| !root) {
+            self.repeated_from = self.repeated_from.min(idx);
+            return (None, 0);
+        }
+        if let Some(wdl) = self
+            .tablebase
+            .as_ref()
+            .filter(|_| !root)
+            .and_then(|tablebase| tablebase.probe_wdl(chess))
+        {
+            return (None, tablebase_score(chess, wdl, depth));
+        }
+        // Mates on the last move searched count as mates
+        if depth == 0 && (chess.checkers() == 0 || !chess.possible_states().is_empty()) {
+            return (None, evaluate(chess));
+        }
+        let entry = self.table.probe(hash);
+        if let Some(entry) = entry.filter(|entry| !root && entry.depth >= depth) {
+            let usable = match entry.bound {
+                Bound::Exact => true,
+                Bound::Lower => entry.score >= beta,
+                Bound::Upper => entry.score <= alpha,
+            };
+            if usable {
+                return (None, entry.score);
+            }
+        }
+        let mut states = chess.possible_states();
+        if states.is_empty() {
+            // no legal moves: mated if in check (prefer quicker mates), otherwise stalemate
+            let eval = match (chess.checkers() > 0, chess.white_turn) {
+                (true, true) => -MATE_SCORE - depth as i32,
+                (true, false) => MATE_SCORE + depth as i32,
+                (false, _) => 0,
+            };
+            return (None, eval);
+        }
+        if self.shuffle {
+            states.shuffle(&mut thread_rng());
+        }
+        // The best move last time round is the likeliest to cut the search short
+        if let Some(best) = entry.and_then(|entry| entry.best) {
+            if let Some(i) = states.iter().position(|state| {
+                state
+                    .last_move
+                    .as_ref()
+                    .is_some_and(|LastMove(from, to)| (*from, *to) == best)
+            }) {
+                states[..=i].rotate_right(1);
+            }
+        }
+        let mut equally_best_moves = Vec::new();
+        // The window narrows as moves are searched, except at the root, where
+        // every move gets an exact score so equally good ones can be told apart
+        let (mut alpha_now, mut beta_now) = (alpha, beta);
+        let repeated_from = std::mem::replace(&mut self.repeated_from, usize::MAX);
+        self.line.push(hash);
+        let score = if is_maximizing {
+            let mut max_ev = i32::MIN;
+            for new_chess in states.iter() {
+                let (_, eval) =
+                    self.minimax(new_chess, depth - 1, false, alpha_now, beta_now, false);
+                if root {
+                    self.root_moves.push((new_chess.clone(), eval));
+                }
+                if eval > max_ev {
+                    max_ev = eval;
+                    equally_best_moves = Vec::from([new_chess.clone()])
+                }
+                if eval == max_ev {
+                    equally_best_moves.push(new_chess.clone())
+                }
+                let na = max(alpha_now, eval);
+                if beta_now <= na {
+                    break;
+                }
+                if !root {
+                    alpha_now = na;
+                }
+            }
+            max_ev
+        } else {
+            let mut min_ev = i32::MAX;
+            for new_chess in states.iter() {
+                let (_, eval) =
+                    self.minimax(new_chess, depth - 1, true, alpha_now, beta_now, false);
+                if root {
+                    self.root_moves.push((new_chess.clone(), eval));
+                }
+
+                if eval < min_ev {
+                    min_ev = eval;
+                    equally_best_moves = Vec::from([new_chess.clone()])
+                }
+                if eval == min_ev {
+                    equally_best_moves.push(new_chess.clone())
+                }
+                let nb = min(beta_now, eval);
+                if alpha_now >= nb {
+                    break;
+                }
+                if !root {
+                    beta_now = nb;
+                }
+            }
+            min_ev
+        };
+        self.line.pop();
+        let repeats_above = self.repeated_from < self.line.len();
+        self.repeated_from = self.repeated_from.min(repeated_from);
+        if self.stopped() {
+            return (None, 0);
+        }
+        let bound = if score >= beta {
+            Bound::Lower
+        } else if score <= alpha {
+            Bound::Upper
+        } else {
+            Bound::Exact
+        };
+        // Below a repetition only the best move is kept, for move ordering. At
+        // depth 0 the score never answers a probe.
+        self.table.store(
+            hash,
+            Entry {
+                depth: if repeats_above { 0 } else { depth },
+                score,
+                bound,
+                best: equally_best_moves[0]
+                    .last_move
+                    .as_ref()
+                    .map(|LastMove(from, to)| (*from, *to)),
+            },
+        );
+        let best = if self.deterministic {
+            equally_best_moves.first()
+        } else {
+            equally_best_moves.choose(&mut thread_rng())
+        };
+        (best.cloned(), score)
+    }
+}
+
+// A tablebase result as a score in White's favour, nearer wins ranking higher
+fn tablebase_score(chess: &Chess, wdl: Wdl, depth: u8) -> i32 {
+    let score = match wdl {
+        Wdl::Win => TABLEBASE_WIN + depth as i32,
+        Wdl::Draw => 0,
+        Wdl::Loss => -TABLEBASE_WIN - depth as i32,
+    };
+    if chess.white_turn {
+        score
+    } else {
+        -score
+    }
+}
+
+// The best move for the side to move at `depth`, see `Search::run`
+pub fn best_move(chess: &Chess, depth: u8, stop: &AtomicBool) -> Option<Move> {
+    Search::new(stop, &mut |_| {}).run(chess, depth)
+}
@@ -0,0 +1,9 @@
+pub mod analysis;
+pub mod book;
+pub mod endgame;
+pub mod explorer;
+pub mod minimax;
+pub mod review;
+pub mod smp;
+pub mod syzygy;
+pub mod tt;
@@ -0,0 +1,145 @@
+// Post-game review. Each position of the game is searched for every move's
+// score, which gives both the engine's choice and what the move played was
+// worth; moves are judged by how much of the mover's chance of winning they
+// gave away, with the thresholds and accuracy formula Lichess uses.
+use std::sync::atomic::AtomicBool;
+
+use super::minimax::Search;
+use crate::engine::chess::{Chess, Move};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Judgement {
+    Best,
+    Good,
+    Inaccuracy,
+    Mistake,
+    Blunder,
+}
+
+impl Judgement {
+    pub fn label(self) -> &'static str {
+        match self {
+            Judgement::Best => "Best",
+            Judgement::Good => "Good",
+            Judgement::Inaccuracy => "Inaccuracy",
+            Judgement::Mistake => "Mistake",
+            Judgement::Blunder => "Blunder",
+        }
+    }
+
+    // Annotation symbol written after the move
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Judgement::Best | Judgement::Good => "",
+            Judgement::Inaccuracy => "?!",
+            Judgement::Mistake => "?",
+            Judgement::Blunder => "??",
+        }
+    }
+}
+
+// One move of the game against the engine's choice. Scores favour White.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoveReview {
+    // Whether White played the move
+    pub white: bool,
+    // The engine's move and its score
+    pub best: Move,
+    pub best_score: i32,
+    // Score of the move played
+    pub score: i32,
+}
+
+impl MoveReview {
+    // How much of the mover's chance of winning, out of 100, the move gave
+    // away against the engine's choice
+    pub fn win_chance_lost(&self) -> f32 {
+        (win_chance(self.best_score, self.white) - win_chance(self.score, self.white)).max(0.)
+    }
+
+    pub fn judgement(&self) -> Judgement {
+        let lost = self.win_chance_lost();
+        if self.score == self.best_score {
+            Judgement::Best
+        } else if lost >= 15. {
+            Judgement::Blunder
+        } else if lost >= 10. {
+            Judgement::Mistake
+        } else if lost >= 5. {
+            Judgement::Inaccuracy
+        } else {
+            Judgement::Good
+        }
+    }
+
+    // From 100 for the engine's move down to 0
+    pub fn accuracy(&self) -> f32 {
+        (103.166_8 * (-0.043_54 * self.win_chance_lost()).exp() - 3.166_9).clamp(0., 100.)
+    }
+}
+
+// The chance out of 100 the side with `white` wins from a score in tenths of
+// a pawn, on Lichess's curve
+pub fn win_chance(score: i32, white: bool) -> f32 {
+    let centipawns = score as f32 * if white { 10. } else { -10. };
+    100. / (1. + (-0.003_682_08 * centipawns).exp())
+}
+
+// Average accuracy of the moves the side with `white` played, None if it
+// played none
+pub fn accuracy(reviews: &[MoveReview], white: bool) -> Option<f32> {
+    let accuracies = reviews
+        .iter()
+        .filter(|review| review.white == white)
+        .map(MoveReview::accuracy)
+        .collect::<Vec<_>>();
+    (!accuracies.is_empty()).then(|| accuracies.iter().sum::<f32>() / accuracies.len() as f32)
+}
+
+// Reviews `played` from `chess` with a search to `depth`, None once `stop` is
+// set or when there are no moves. `history` holds the hashes of the game's
+// positions before `chess`.
+pub fn review_move(
+    chess: &Chess,
+    history: &[u64],
+    played: &Move,
+    depth: u8,
+    stop: &AtomicBool,
+) -> Option<MoveReview> {
+    let mut ignore = |_: &_| {};
+    let mut search = Search::new(stop, &mut ignore)
+        .with_history(history.to_vec())
+        .deterministic();
+    let scores = search.score_moves(chess, depth)?;
+    let (best, best_score) = scores.first().copied()?;
+    // The search only promotes to queens, so an underpromotion is taken
+    // for as good as one
+    let score = scores
+        .iter()
+        .find(|(mv, _)| mv.from == played.from && mv.to == played.to)
+        .map_or(best_score, |(_, score)| *score);
+    Some(MoveReview {
+        white: chess.white_turn,
+        best,
+        best_score,
+        score,
+    })
+}
+
+// Reviews each move of a game, given the positions before each move, and
+// reports them in order with their ply. Stops early once `stop` is set.
+pub fn review_game(
+    positions: &[Chess],
+    moves: &[Move],
+    depth: u8,
+    stop: &AtomicBool,
+    report: &mut dyn FnMut(usize, &MoveReview),
+) {
+    let hashes = positions.iter().map(Chess::hash).collect::<Vec<_>>();
+    for (ply, (chess, played)) in positions.iter().zip(moves).enumerate() {
+        let Some(review) = review_move(chess, &hashes[..ply], played, depth, stop) else {
+            return;
+        };
+        report(ply, &review);
+    }
+}
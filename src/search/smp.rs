@@ -0,0 +1,70 @@
+// Lazy SMP: several searches of the same position run at once and share one
+// transposition table. Helpers try moves in random orders, so they work
+// through different parts of the tree and leave results in the table that
+// the main search picks up; only the main search's answer is used.
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use super::minimax::{Search, SearchProgress};
+use super::syzygy::Tablebase;
+use super::tt::TranspositionTable;
+use crate::engine::chess::Chess;
+
+// The main search's last progress, with the positions visited by all threads.
+// Its best move is None when the game is over or the search was stopped.
+// `history` holds the hashes of the game's positions before this one. The
+// table is kept by the caller, so what one move's search learns helps the next.
+#[allow(clippy::too_many_arguments)]
+pub fn parallel_search(
+    chess: &Chess,
+    history: &[u64],
+    depth: u8,
+    threads: usize,
+    table: Arc<TranspositionTable>,
+    tablebase: Option<Arc<Tablebase>>,
+    stop: &AtomicBool,
+    report: &mut dyn FnMut(&SearchProgress),
+) -> SearchProgress {
+    // Browsers don't let wasm start threads of its own
+    let helpers = if cfg!(target_arch = "wasm32") {
+        0
+    } else {
+        threads.saturating_sub(1)
+    };
+    let done = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let helpers = (0..helpers)
+            .map(|_| {
+                let table = table.clone();
+                let tablebase = tablebase.clone();
+                let done = &done;
+                scope.spawn(move || {
+                    let mut report = |_: &SearchProgress| {};
+                    let mut search = Search::new(done, &mut report)
+                        .with_table(table)
+                        .with_tablebase(tablebase)
+                        .with_history(history.to_vec())
+                        .shuffled();
+                    search.run(chess, depth);
+                    search.progress().nodes
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut search = Search::new(stop, report)
+            .with_table(table)
+            .with_tablebase(tablebase)
+            .with_history(history.to_vec());
+        let best = search.run(chess, depth);
+        done.store(true, Ordering::Relaxed);
+        let helper_nodes = helpers
+            .into_iter()
+            .map(|helper| helper.join().unwrap_or(0))
+            .sum::<u64>();
+        SearchProgress {
+            best,
+            nodes: search.progress().nodes + helper_nodes,
+            ..*search.progress()
+        }
+    })
+}
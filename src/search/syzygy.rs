@@ -0,0 +1,118 @@
+// Syzygy endgame tablebases read from a local directory, with the `syzygy`
+// feature. Positions are handed to shakmaty-syzygy as FEN. Built without the
+// feature, or without any tables, every probe comes back empty and the search
+// plays as it always has.
+use std::io;
+use std::path::Path;
+
+use crate::engine::chess::{Chess, Move};
+
+// The result with best play for the side to move. Wins and losses that the
+// fifty-move rule turns into draws count as draws.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wdl {
+    Loss,
+    Draw,
+    Win,
+}
+
+pub struct Tablebase {
+    #[cfg(feature = "syzygy")]
+    tables: shakmaty_syzygy::Tablebase<shakmaty::Chess>,
+}
+
+impl Tablebase {
+    pub fn piece_count(chess: &Chess) -> usize {
+        chess
+            .pieces
+            .iter()
+            .map(|bits| bits.count_ones() as usize)
+            .sum()
+    }
+}
+
+#[cfg(feature = "syzygy")]
+impl Tablebase {
+    // Opens the tables in `dir`, failing when it holds none
+    pub fn open(dir: impl AsRef<Path>) -> io::Result<Tablebase> {
+        let mut tables = shakmaty_syzygy::Tablebase::new();
+        if tables.add_directory(dir)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no Syzygy tables in the directory",
+            ));
+        }
+        Ok(Tablebase { tables })
+    }
+
+    // Pieces, kings included, in the largest tables opened
+    pub fn max_pieces(&self) -> usize {
+        self.tables.max_pieces()
+    }
+
+    // Only needs WDL tables, and assumes the last move was a capture or a
+    // pawn move, as it is when the search steps into tablebase positions.
+    // None for positions with castling rights, too many pieces or no table.
+    pub fn probe_wdl(&self, chess: &Chess) -> Option<Wdl> {
+        use shakmaty_syzygy::Wdl as Syzygy;
+
+        if Tablebase::piece_count(chess) > self.max_pieces() {
+            return None;
+        }
+        let wdl = match self
+            .tables
+            .probe_wdl_after_zeroing(&position(chess)?)
+            .ok()?
+        {
+            Syzygy::Win => Wdl::Win,
+            Syzygy::Loss => Wdl::Loss,
+            Syzygy::CursedWin | Syzygy::Draw | Syzygy::BlessedLoss => Wdl::Draw,
+        };
+        Some(wdl)
+    }
+
+    // The move keeping the best result fastest by distance to zeroing, which
+    // needs DTZ tables as well, and that result
+    pub fn best_move(&self, chess: &Chess) -> Option<(Move, Wdl)> {
+        if Tablebase::piece_count(chess) > self.max_pieces() {
+            return None;
+        }
+        let position = position(chess)?;
+        let wdl = match self.tables.probe_wdl(&position).ok()?.signum() {
+            1 => Wdl::Win,
+            -1 => Wdl::Loss,
+            _ => Wdl::Draw,
+        };
+        let (mv, _) = self.tables.best_move(&position).ok()??;
+        let uci = mv.to_uci(shakmaty::CastlingMode::Standard).to_string();
+        Some((chess.parse_uci(&uci)?, wdl))
+    }
+}
+
+#[cfg(not(feature = "syzygy"))]
+impl Tablebase {
+    pub fn open(_dir: impl AsRef<Path>) -> io::Result<Tablebase> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "built without the syzygy feature",
+        ))
+    }
+
+    pub fn max_pieces(&self) -> usize {
+        0
+    }
+
+    pub fn probe_wdl(&self, _chess: &Chess) -> Option<Wdl> {
+        None
+    }
+
+    pub fn best_move(&self, _chess: &Chess) -> Option<(Move, Wdl)> {
+        None
+    }
+}
+
+#[cfg(feature = "syzygy")]
+fn position(chess: &Chess) -> Option<shakmaty::Chess> {
+    let fen: shakmaty::fen::Fen = chess.to_fen().parse().ok()?;
+    fen.into_position(shakmaty::CastlingMode::Standard).ok()
+}
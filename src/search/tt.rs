@@ -0,0 +1,98 @@
+// A transposition table that searches on several threads can share without
+// locks. Each slot is two atomic words, the position's hash xored with the
+// entry and the entry itself, so a slot torn by two threads writing at once
+// no longer matches any hash and is ignored (Hyatt's lockless hashing).
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// How a stored score relates to the position's true score, with alpha-beta
+// cutting the search short
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bound {
+    Exact,
+    // The true score is at least this
+    Lower,
+    // The true score is at most this
+    Upper,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub depth: u8,
+    pub score: i32,
+    pub bound: Bound,
+    // Squares of the best move found, tried first the next time round
+    pub best: Option<(u8, u8)>,
+}
+
+impl Entry {
+    // Score in bits 0-31, depth in 32-39, bound in 40-41, and the best move
+    // in 42-53 with bit 54 telling whether there is one
+    fn pack(&self) -> u64 {
+        let bound = match self.bound {
+            Bound::Exact => 0,
+            Bound::Lower => 1,
+            Bound::Upper => 2,
+        };
+        let best = match self.best {
+            Some((from, to)) => 1 << 12 | (from as u64) << 6 | to as u64,
+            None => 0,
+        };
+        self.score as u32 as u64 | (self.depth as u64) << 32 | bound << 40 | best << 42
+    }
+
+    fn unpack(data: u64) -> Entry {
+        let best = data >> 42;
+        Entry {
+            score: data as u32 as i32,
+            depth: (data >> 32) as u8,
+            bound: match (data >> 40) & 3 {
+                0 => Bound::Exact,
+                1 => Bound::Lower,
+                _ => Bound::Upper,
+            },
+            best: (best & 1 << 12 > 0).then_some(((best >> 6 & 63) as u8, (best & 63) as u8)),
+        }
+    }
+}
+
+pub struct TranspositionTable {
+    slots: Vec<[AtomicU64; 2]>,
+}
+
+impl TranspositionTable {
+    pub const DEFAULT_MB: usize = 16;
+
+    pub fn new(size_mb: usize) -> TranspositionTable {
+        let len = (size_mb << 20) / std::mem::size_of::<[AtomicU64; 2]>();
+        TranspositionTable {
+            slots: (0..len.max(1))
+                .map(|_| [AtomicU64::new(0), AtomicU64::new(0)])
+                .collect(),
+        }
+    }
+
+    fn slot(&self, hash: u64) -> &[AtomicU64; 2] {
+        &self.slots[(hash % self.slots.len() as u64) as usize]
+    }
+
+    // `hash` is the position's `Chess::hash`
+    pub fn probe(&self, hash: u64) -> Option<Entry> {
+        let [check, data] = self.slot(hash);
+        let data = data.load(Ordering::Relaxed);
+        (check.load(Ordering::Relaxed) ^ data == hash).then(|| Entry::unpack(data))
+    }
+
+    // Replaces whatever the slot held, deeper entries for other positions included
+    pub fn store(&self, hash: u64, entry: Entry) {
+        let data = entry.pack();
+        let [check, slot] = self.slot(hash);
+        check.store(hash ^ data, Ordering::Relaxed);
+        slot.store(data, Ordering::Relaxed);
+    }
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        TranspositionTable::new(TranspositionTable::DEFAULT_MB)
+    }
+}
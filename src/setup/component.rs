@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use crate::engine::chess::Chess;
+use crate::engine::setup::SetupError;
+
+// What pressing on a square of the board does
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SetupTool {
+    // Drags the piece to another square, or off the board to remove it
+    #[default]
+    Move,
+    // Places the piece, an index into `Chess::pieces`, on each square passed
+    // over, or takes it off a square it is already on
+    Place(u8),
+    Erase,
+}
+
+// The editor's state. The position itself is the board's.
+#[derive(Resource, Debug, Default)]
+pub struct PositionSetup {
+    pub tool: SetupTool,
+    // The position on the board before editing, put back unless the edited
+    // one is played or analysed
+    pub original: Chess,
+    pub keep: bool,
+    // Square the piece being dragged was picked up from
+    pub dragging: Option<u8>,
+    // Square last painted while the button is held, so each is changed once
+    pub painted: Option<u8>,
+    // Why the position can't be played yet, shown under the controls
+    pub error: Option<SetupError>,
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupButton {
+    Tool(SetupTool),
+    // Gives the move to the other side
+    Turn,
+    // Toggles the castling right with this flag
+    Castling(u8),
+    // Steps through the en passant squares the position allows
+    EnPassant,
+    Clear,
+    StartPosition,
+    PlayBot,
+    PlayHuman,
+    Analyse,
+    Back,
+}
+
+impl SetupButton {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SetupButton::Tool(SetupTool::Move) => "Move",
+            SetupButton::Tool(SetupTool::Erase) => "Erase",
+            SetupButton::Tool(SetupTool::Place(_)) => "",
+            SetupButton::Turn => "White to move",
+            SetupButton::Castling(_) => "",
+            SetupButton::EnPassant => "En passant: -",
+            SetupButton::Clear => "Clear board",
+            SetupButton::StartPosition => "Start position",
+            SetupButton::PlayBot => "Play vs bot",
+            SetupButton::PlayHuman => "Play vs human",
+            SetupButton::Analyse => "Analyse",
+            SetupButton::Back => "Back",
+        }
+    }
+}
+
+// Whether the position is ready to play, or what is wrong with it
+#[derive(Component, Debug)]
+pub struct SetupStatusText;
@@ -0,0 +1,12 @@
+use bevy::color::*;
+
+// Tools and toggles that are in use
+pub const SETUP_SELECTED: Srgba = Srgba::new(0.482_352_94, 0.380_392_16, 1., 1.);
+// Text of castling rights the placement rules out
+pub const SETUP_DISABLED_TEXT: Srgba = Srgba::new(0.5, 0.5, 0.55, 1.);
+pub const SETUP_ERROR_TEXT: Srgba = Srgba::new(0.95, 0.45, 0.4, 1.);
+
+pub const PANEL_WIDTH: f32 = 236.;
+pub const PALETTE_SQUARE: f32 = 36.;
+pub const SETUP_BUTTON_HEIGHT: f32 = 30.;
+pub const SETUP_GAP: f32 = 4.;
@@ -0,0 +1,31 @@
+mod component;
+mod constants;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::component::GameState;
+use component::*;
+use system::*;
+
+// Setting up a position by hand: pieces are placed from a palette or dragged
+// about the board, the side to move, castling rights and en passant square
+// are set beside it, and a valid position is played or analysed from
+pub struct SetupPlugin;
+
+impl Plugin for SetupPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PositionSetup>()
+            .add_systems(OnEnter(GameState::Setup), start_setup)
+            .add_systems(OnExit(GameState::Setup), stop_setup)
+            .add_systems(
+                Update,
+                (
+                    handle_setup_buttons,
+                    edit_board.after(handle_setup_buttons),
+                    show_setup.after(edit_board),
+                )
+                    .run_if(in_state(GameState::Setup)),
+            );
+    }
+}
@@ -0,0 +1,485 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::component::*;
+use super::constants::*;
+use crate::chessboard::component::{
+    AnyOverlay, Board, GameCommand, GameState, Piece, PlayerKind, Players, TextInfo,
+};
+use crate::chessboard::constants::{MOVING_PIECE_Z, PIECES_CODE};
+use crate::chessboard::system::sync_board;
+use crate::chessboard::utils::world_position_to_chess_position;
+use crate::engine::chess::*;
+use crate::engine::notation::square_name;
+use crate::menu::constants::{BUTTON_HOVERED, BUTTON_NORMAL, TEXT_COLOR};
+use crate::menu::{button_text_style, side_panel};
+
+const CASTLING: [(u8, &str); 4] = [
+    (WHITE_KING_SIDE, "K"),
+    (WHITE_QUEEN_SIDE, "Q"),
+    (BLACK_KING_SIDE, "k"),
+    (BLACK_QUEEN_SIDE, "q"),
+];
+
+fn spawn_setup_button(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    button: SetupButton,
+    label: &str,
+    width: f32,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(width),
+                    height: Val::Px(SETUP_BUTTON_HEIGHT),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: BUTTON_NORMAL.into(),
+                ..default()
+            },
+            button,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                label,
+                button_text_style(asset_server, 18.),
+            ));
+        });
+}
+
+fn row() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            width: Val::Px(PANEL_WIDTH),
+            column_gap: Val::Px(SETUP_GAP),
+            row_gap: Val::Px(SETUP_GAP),
+            flex_wrap: FlexWrap::Wrap,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        ..default()
+    }
+}
+
+// Edits the position on the board, laying out the piece palette and the
+// controls beside it
+#[allow(clippy::too_many_arguments)]
+pub fn start_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut setup: ResMut<PositionSetup>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut q_texts: Query<(&TextInfo, &mut Transform)>,
+) {
+    let mut chess = q_chess.single_mut();
+    *setup = PositionSetup {
+        original: chess.clone(),
+        error: chess.validate_setup().err(),
+        ..default()
+    };
+    chess.last_move = None;
+    sync_board(
+        &mut commands,
+        &asset_server,
+        &chess,
+        &mut q_board.single_mut(),
+        &q_piece,
+        &q_overlays,
+    );
+    // The game over banner would hide the board
+    for (text_info, mut transform) in q_texts.iter_mut() {
+        if text_info.text_type == 1 || text_info.text_type == 2 {
+            transform.scale = Vec3::splat(0.);
+        }
+    }
+
+    let half = (PANEL_WIDTH - SETUP_GAP) / 2.;
+    commands
+        .spawn((side_panel(), StateScoped(GameState::Setup)))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Set up position",
+                button_text_style(&asset_server, 28.),
+            ));
+            parent.spawn(row()).with_children(|parent| {
+                for (piece, code) in PIECES_CODE.iter().enumerate() {
+                    parent.spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(PALETTE_SQUARE),
+                                height: Val::Px(PALETTE_SQUARE),
+                                ..default()
+                            },
+                            image: UiImage::new(asset_server.load(format!("pieces/{code}.png"))),
+                            background_color: BUTTON_NORMAL.into(),
+                            ..default()
+                        },
+                        SetupButton::Tool(SetupTool::Place(piece as u8)),
+                    ));
+                }
+            });
+            parent.spawn(row()).with_children(|parent| {
+                for tool in [SetupTool::Move, SetupTool::Erase] {
+                    let button = SetupButton::Tool(tool);
+                    spawn_setup_button(parent, &asset_server, button, button.label(), half);
+                }
+            });
+            spawn_setup_button(
+                parent,
+                &asset_server,
+                SetupButton::Turn,
+                SetupButton::Turn.label(),
+                PANEL_WIDTH,
+            );
+            parent.spawn(row()).with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    "Castling",
+                    button_text_style(&asset_server, 18.),
+                ));
+                for (right, letter) in CASTLING {
+                    spawn_setup_button(
+                        parent,
+                        &asset_server,
+                        SetupButton::Castling(right),
+                        letter,
+                        SETUP_BUTTON_HEIGHT,
+                    );
+                }
+            });
+            spawn_setup_button(
+                parent,
+                &asset_server,
+                SetupButton::EnPassant,
+                SetupButton::EnPassant.label(),
+                PANEL_WIDTH,
+            );
+            parent.spawn(row()).with_children(|parent| {
+                for button in [SetupButton::Clear, SetupButton::StartPosition] {
+                    spawn_setup_button(parent, &asset_server, button, button.label(), half);
+                }
+            });
+            parent.spawn((
+                TextBundle::from_section("", button_text_style(&asset_server, 18.)).with_style(
+                    Style {
+                        max_width: Val::Px(PANEL_WIDTH),
+                        ..default()
+                    },
+                ),
+                SetupStatusText,
+            ));
+            parent.spawn(row()).with_children(|parent| {
+                for button in [
+                    SetupButton::PlayBot,
+                    SetupButton::PlayHuman,
+                    SetupButton::Analyse,
+                    SetupButton::Back,
+                ] {
+                    spawn_setup_button(parent, &asset_server, button, button.label(), half);
+                }
+            });
+        });
+}
+
+// Puts the position from before editing back on the board, unless the
+// edited one is being played or analysed
+#[allow(clippy::too_many_arguments)]
+pub fn stop_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut setup: ResMut<PositionSetup>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut q_texts: Query<(&TextInfo, &mut Transform)>,
+) {
+    setup.dragging = None;
+    if setup.keep {
+        return;
+    }
+    let mut chess = q_chess.single_mut();
+    let mut board = q_board.single_mut();
+    *chess = setup.original.clone();
+    sync_board(
+        &mut commands,
+        &asset_server,
+        &chess,
+        &mut board,
+        &q_piece,
+        &q_overlays,
+    );
+    if board.game_over {
+        for (text_info, mut transform) in q_texts.iter_mut() {
+            if text_info.text_type == 1 || text_info.text_type == 2 {
+                transform.scale = Vec3::splat(1.);
+            }
+        }
+    }
+}
+
+// Places, removes and drags pieces on the board with the tool in hand
+#[allow(clippy::too_many_arguments)]
+pub fn edit_board(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+    mut setup: ResMut<PositionSetup>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut q_sprites: Query<(&Piece, &mut Transform)>,
+) {
+    let (camera, camera_transform) = q_camera.single();
+    let cursor = q_window
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
+        .map(|ray| ray.origin.truncate());
+    let mut board = q_board.single_mut();
+    let mut chess = q_chess.single_mut();
+    let square = cursor
+        .and_then(|world| world_position_to_chess_position((world.x, world.y), board.flipped))
+        .map(|position| Chess::position_to_index(&position));
+    let piece_at = |chess: &Chess, idx: u8| chess.get_piece(&Chess::index_to_position(idx));
+
+    let mut edited = false;
+    match setup.tool {
+        SetupTool::Move => {
+            if mouse.just_pressed(MouseButton::Left) {
+                setup.dragging = square.filter(|idx| piece_at(&chess, *idx).is_some());
+            }
+            let Some(from) = setup.dragging else {
+                return;
+            };
+            if mouse.pressed(MouseButton::Left) {
+                let from = Chess::index_to_position(from);
+                if let (Some(cursor), Some((_, mut transform))) = (
+                    cursor,
+                    q_sprites
+                        .iter_mut()
+                        .find(|(piece, _)| piece.position == from),
+                ) {
+                    transform.translation = cursor.extend(MOVING_PIECE_Z);
+                }
+                return;
+            }
+            setup.dragging = None;
+            // Dropped off the board, the piece is removed
+            if square != Some(from) {
+                let piece = piece_at(&chess, from);
+                chess.set_piece(from, None);
+                if let Some(to) = square {
+                    chess.set_piece(to, piece);
+                }
+            }
+            // Dropping it back on its square still puts the sprite back
+            edited = true;
+        }
+        SetupTool::Place(_) | SetupTool::Erase => {
+            if mouse.just_pressed(MouseButton::Left) {
+                setup.painted = None;
+            }
+            let Some(idx) = square
+                .filter(|idx| mouse.pressed(MouseButton::Left) && setup.painted != Some(*idx))
+            else {
+                return;
+            };
+            setup.painted = Some(idx);
+            let mut piece = match setup.tool {
+                SetupTool::Place(piece) => Some(piece),
+                _ => None,
+            };
+            // Clicking a piece with itself takes it off
+            if mouse.just_pressed(MouseButton::Left) && piece_at(&chess, idx) == piece {
+                piece = None;
+            }
+            if piece_at(&chess, idx) != piece {
+                chess.set_piece(idx, piece);
+                edited = true;
+            }
+        }
+    }
+    if edited {
+        setup.error = chess.validate_setup().err();
+        sync_board(
+            &mut commands,
+            &asset_server,
+            &chess,
+            &mut board,
+            &q_piece,
+            &q_overlays,
+        );
+    }
+}
+
+// Changes the side to move, castling rights and en passant square, and starts
+// play or analysis from the position once it is valid
+#[allow(clippy::too_many_arguments)]
+pub fn handle_setup_buttons(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    q_buttons: Query<(&Interaction, &SetupButton), Changed<Interaction>>,
+    mut setup: ResMut<PositionSetup>,
+    mut q_chess: Query<&mut Chess>,
+    mut q_board: Query<&mut Board>,
+    q_piece: Query<Entity, With<Piece>>,
+    q_overlays: Query<Entity, AnyOverlay>,
+    mut players: ResMut<Players>,
+    mut ev_command: EventWriter<GameCommand>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let mut chess = q_chess.single_mut();
+    let mut edited = false;
+    for (interaction, button) in q_buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            SetupButton::Tool(tool) => {
+                setup.tool = *tool;
+                continue;
+            }
+            SetupButton::Turn => {
+                let white = !chess.white_turn;
+                chess.set_turn(white);
+            }
+            SetupButton::Castling(right) => {
+                if chess.possible_castling_rights() & right > 0 {
+                    chess.castling_rights ^= right;
+                }
+            }
+            SetupButton::EnPassant => {
+                let squares = chess.en_passant_squares();
+                chess.en_passant = match chess.en_passant {
+                    Some(current) => squares.into_iter().skip_while(|idx| *idx != current).nth(1),
+                    None => squares.first().copied(),
+                };
+            }
+            SetupButton::Clear => *chess = Chess::empty(),
+            SetupButton::StartPosition => *chess = Chess::new(),
+            SetupButton::PlayBot | SetupButton::PlayHuman | SetupButton::Analyse => {
+                if chess.validate_setup().is_err() {
+                    continue;
+                }
+                // Against the bot the human plays White, as in a new game
+                *players = if *button == SetupButton::PlayHuman {
+                    Players {
+                        white: PlayerKind::Human,
+                        black: PlayerKind::Human,
+                    }
+                } else {
+                    Players::default()
+                };
+                setup.keep = true;
+                ev_command.send(GameCommand::LoadGame {
+                    fen: chess.to_fen(),
+                    moves: Vec::new(),
+                });
+                next_state.set(if *button == SetupButton::Analyse {
+                    GameState::Analysis
+                } else {
+                    GameState::Playing
+                });
+                continue;
+            }
+            SetupButton::Back => {
+                next_state.set(GameState::Menu);
+                continue;
+            }
+        }
+        edited = true;
+    }
+    if edited {
+        setup.error = chess.validate_setup().err();
+        sync_board(
+            &mut commands,
+            &asset_server,
+            &chess,
+            &mut q_board.single_mut(),
+            &q_piece,
+            &q_overlays,
+        );
+    }
+}
+
+// Highlights the tool in hand and the settings in force, and says whether
+// the position can be played
+pub fn show_setup(
+    setup: Res<PositionSetup>,
+    q_chess: Query<&Chess>,
+    mut q_buttons: Query<(&SetupButton, &Interaction, &mut BackgroundColor, &Children)>,
+    mut q_labels: Query<&mut Text, Without<SetupStatusText>>,
+    mut q_status: Query<&mut Text, With<SetupStatusText>>,
+) {
+    let chess = q_chess.single();
+    let possible_rights = chess.possible_castling_rights();
+    for (button, interaction, mut background, children) in q_buttons.iter_mut() {
+        let active = match button {
+            SetupButton::Tool(tool) => setup.tool == *tool,
+            SetupButton::Castling(right) => chess.castling_rights & right > 0,
+            _ => false,
+        };
+        background.set_if_neq(
+            if active {
+                SETUP_SELECTED
+            } else if *interaction == Interaction::Hovered {
+                BUTTON_HOVERED
+            } else {
+                BUTTON_NORMAL
+            }
+            .into(),
+        );
+        let label = match button {
+            SetupButton::Turn if !chess.white_turn => Some("Black to move".to_string()),
+            SetupButton::Turn => Some("White to move".to_string()),
+            SetupButton::EnPassant => Some(format!(
+                "En passant: {}",
+                chess.en_passant.map_or("-".to_string(), square_name)
+            )),
+            _ => None,
+        };
+        for child in children.iter() {
+            let Ok(mut text) = q_labels.get_mut(*child) else {
+                continue;
+            };
+            if let Some(label) = label
+                .as_ref()
+                .filter(|label| text.sections[0].value != **label)
+            {
+                text.sections[0].value.clone_from(label);
+            }
+            if let SetupButton::Castling(right) = button {
+                let color = if possible_rights & right > 0 {
+                    TEXT_COLOR
+                } else {
+                    SETUP_DISABLED_TEXT
+                };
+                if text.sections[0].style.color != color.into() {
+                    text.sections[0].style.color = color.into();
+                }
+            }
+        }
+    }
+    if !setup.is_changed() {
+        return;
+    }
+    let (status, color) = match setup.error {
+        Some(error) => (error.message(), SETUP_ERROR_TEXT),
+        None => ("Ready to play", TEXT_COLOR),
+    };
+    for mut text in q_status.iter_mut() {
+        text.sections[0].value = status.to_string();
+        text.sections[0].style.color = color.into();
+    }
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const SETTINGS_KEY: &str = "sound";
+
+#[derive(Resource, Debug, Serialize, Deserialize)]
+pub struct SoundSettings {
+    pub muted: bool,
+    pub volume: f32,
+}
+
+impl Default for SoundSettings {
+    fn default() -> Self {
+        SoundSettings {
+            muted: false,
+            volume: 0.8,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Sound {
+    Move,
+    Capture,
+    Castle,
+    Check,
+    Promotion,
+    Illegal,
+    LowTime,
+    GameOver,
+}
+
+#[derive(Resource, Debug)]
+pub struct GameSounds {
+    pub move_piece: Handle<AudioSource>,
+    pub capture: Handle<AudioSource>,
+    pub castle: Handle<AudioSource>,
+    pub check: Handle<AudioSource>,
+    pub promotion: Handle<AudioSource>,
+    pub illegal: Handle<AudioSource>,
+    pub low_time: Handle<AudioSource>,
+    pub game_over: Handle<AudioSource>,
+}
+
+impl GameSounds {
+    pub fn get(&self, sound: Sound) -> Handle<AudioSource> {
+        match sound {
+            Sound::Move => self.move_piece.clone(),
+            Sound::Capture => self.capture.clone(),
+            Sound::Castle => self.castle.clone(),
+            Sound::Check => self.check.clone(),
+            Sound::Promotion => self.promotion.clone(),
+            Sound::Illegal => self.illegal.clone(),
+            Sound::LowTime => self.low_time.clone(),
+            Sound::GameOver => self.game_over.clone(),
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct SoundIndicator;
@@ -0,0 +1,27 @@
+mod component;
+mod system;
+
+use bevy::prelude::*;
+
+use crate::chessboard::component::GameState;
+use crate::storage;
+use component::*;
+use system::*;
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load_json::<SoundSettings>(SETTINGS_KEY))
+            .add_systems(Startup, (load_sounds, spawn_sound_indicator))
+            .add_systems(
+                Update,
+                (
+                    play_game_sounds,
+                    // Letters there are typed into the database's filter
+                    handle_sound_keys.run_if(not(in_state(GameState::Database))),
+                    update_sound_indicator.run_if(resource_changed::<SoundSettings>),
+                ),
+            );
+    }
+}
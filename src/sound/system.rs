@@ -0,0 +1,119 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use super::component::*;
+use crate::chessboard::component::{GameEvent, MoveInput, Players};
+use crate::storage;
+
+pub fn load_sounds(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(GameSounds {
+        move_piece: asset_server.load("sounds/move.wav"),
+        capture: asset_server.load("sounds/capture.wav"),
+        castle: asset_server.load("sounds/castle.wav"),
+        check: asset_server.load("sounds/check.wav"),
+        promotion: asset_server.load("sounds/promote.wav"),
+        illegal: asset_server.load("sounds/illegal.wav"),
+        low_time: asset_server.load("sounds/low_time.wav"),
+        game_over: asset_server.load("sounds/game_over.wav"),
+    });
+}
+
+pub fn spawn_sound_indicator(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font: Handle<Font> = asset_server.load("fonts/Gantari.ttf");
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font,
+                    color: Color::WHITE,
+                    font_size: 20.,
+                },
+            ),
+            transform: Transform::from_xyz(-560., -330., 30.),
+            ..default()
+        },
+        SoundIndicator,
+    ));
+}
+
+// Picks one sound per event, the most significant outcome of a move winning
+fn sound_for(ev: &GameEvent, players: &Players) -> Option<Sound> {
+    match ev {
+        GameEvent::Moved(outcome) => Some(if outcome.checkmate || outcome.stalemate {
+            Sound::GameOver
+        } else if outcome.check {
+            Sound::Check
+        } else if outcome.promoted.is_some() {
+            Sound::Promotion
+        } else if outcome.castled {
+            Sound::Castle
+        } else if outcome.captured.is_some() {
+            Sound::Capture
+        } else {
+            Sound::Move
+        }),
+        GameEvent::IllegalMove => Some(Sound::Illegal),
+        // Only players at this board are warned about low time
+        GameEvent::LowTime { is_white } => players.is_human(*is_white).then_some(Sound::LowTime),
+        GameEvent::TimeOut | GameEvent::Resigned | GameEvent::DrawAgreed => Some(Sound::GameOver),
+        GameEvent::DrawDeclined => None,
+    }
+}
+
+pub fn play_game_sounds(
+    mut commands: Commands,
+    mut ev_game: EventReader<GameEvent>,
+    sounds: Res<GameSounds>,
+    settings: Res<SoundSettings>,
+    players: Res<Players>,
+) {
+    for ev in ev_game.read() {
+        if settings.muted {
+            continue;
+        }
+        if let Some(sound) = sound_for(ev, &players) {
+            commands.spawn(AudioBundle {
+                source: sounds.get(sound),
+                settings: PlaybackSettings::DESPAWN.with_volume(Volume::new(settings.volume)),
+            });
+        }
+    }
+}
+
+// M toggles mute, - and = lower and raise the volume, unless a move is being
+// typed where those keys are part of the notation
+pub fn handle_sound_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    move_input: Res<MoveInput>,
+    mut settings: ResMut<SoundSettings>,
+) {
+    if !move_input.text.is_empty() {
+        return;
+    }
+    if keys.just_pressed(KeyCode::KeyM) {
+        settings.muted = !settings.muted;
+    } else if keys.just_pressed(KeyCode::Minus) {
+        settings.volume = ((settings.volume - 0.1) * 10.).round() / 10.;
+        settings.volume = settings.volume.max(0.);
+    } else if keys.just_pressed(KeyCode::Equal) {
+        settings.volume = ((settings.volume + 0.1) * 10.).round() / 10.;
+        settings.volume = settings.volume.min(1.);
+    } else {
+        return;
+    }
+    storage::save_json(SETTINGS_KEY, &*settings);
+}
+
+pub fn update_sound_indicator(
+    settings: Res<SoundSettings>,
+    mut q_indicator: Query<&mut Text, With<SoundIndicator>>,
+) {
+    for mut text in q_indicator.iter_mut() {
+        text.sections[0].value = if settings.muted {
+            "Sound off (M)".to_string()
+        } else {
+            format!("Sound {}% (M, -/=)", (settings.volume * 100.).round())
+        };
+    }
+}
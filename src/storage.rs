@@ -0,0 +1,110 @@
+// Small key/value persistence for settings and saved games. Native builds
+// keep one file per key in the user's data directory, the web build uses the
+// browser's localStorage.
+
+#[cfg(not(target_arch = "wasm32"))]
+fn data_dir() -> std::path::PathBuf {
+    use std::{env, path::PathBuf};
+
+    env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("yet-another-chess")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(key: &str) -> Option<String> {
+    std::fs::read_to_string(data_dir().join(format!("{key}.json"))).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(key: &str, value: &str) {
+    let dir = data_dir();
+    if let Err(err) = std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(dir.join(format!("{key}.json")), value))
+    {
+        bevy::log::warn!("Could not save {key}: {err}");
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn remove(key: &str) {
+    let _ = std::fs::remove_file(data_dir().join(format!("{key}.json")));
+}
+
+// Writes a file for the user to keep, next to the saved data
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_file(name: &str, contents: &str) {
+    let path = data_dir().join(name);
+    match std::fs::create_dir_all(data_dir()).and_then(|_| std::fs::write(&path, contents)) {
+        Ok(_) => bevy::log::info!("Exported {}", path.display()),
+        Err(err) => bevy::log::warn!("Could not export {name}: {err}"),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load(key: &str) -> Option<String> {
+    local_storage()?.get_item(&format!("chess.{key}")).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save(key: &str, value: &str) {
+    if let Some(storage) = local_storage() {
+        if storage.set_item(&format!("chess.{key}"), value).is_err() {
+            bevy::log::warn!("Could not save {key}");
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn remove(key: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(&format!("chess.{key}"));
+    }
+}
+
+// Loads a value stored as JSON, falling back to its default when missing or
+// unreadable.
+pub fn load_json<T: serde::de::DeserializeOwned + Default>(key: &str) -> T {
+    load(key)
+        .and_then(|value| serde_json::from_str(&value).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_json<T: serde::Serialize>(key: &str, value: &T) {
+    if let Ok(value) = serde_json::to_string(value) {
+        save(key, &value);
+    }
+}
+
+// Offers the file as a download from the page
+#[cfg(target_arch = "wasm32")]
+pub fn export_file(name: &str, contents: &str) {
+    use wasm_bindgen::JsCast;
+
+    let download = || -> Option<()> {
+        let parts = js_sys::Array::of1(&contents.into());
+        let blob = web_sys::Blob::new_with_str_sequence(&parts).ok()?;
+        let url = web_sys::Url::create_object_url_with_blob(&blob).ok()?;
+        let document = web_sys::window()?.document()?;
+        let anchor = document
+            .create_element("a")
+            .ok()?
+            .dyn_into::<web_sys::HtmlAnchorElement>()
+            .ok()?;
+        anchor.set_href(&url);
+        anchor.set_download(name);
+        anchor.click();
+        web_sys::Url::revoke_object_url(&url).ok()
+    };
+    if download().is_none() {
+        bevy::log::warn!("Could not export {name}");
+    }
+}
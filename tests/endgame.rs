@@ -0,0 +1,76 @@
+// The bot's endgame knowledge: basic mates without tablebases, played out by
+// the bot on both sides, and positions that are known draws
+use std::sync::atomic::AtomicBool;
+
+use chess::search::minimax::{evaluate, Search};
+use chess::Chess;
+
+// Plays the bot against itself from `fen`, remembering the game so it
+// doesn't go round in circles, and returns how many moves the side to move
+// took to mate, None if it hadn't within 50
+fn moves_to_mate(fen: &str) -> Option<usize> {
+    let mut chess = Chess::from_fen(fen).expect("the position is valid");
+    let mut history = Vec::new();
+    let stop = AtomicBool::new(false);
+    for ply in 0..100usize {
+        if chess.is_checkmate() {
+            return Some(ply.div_ceil(2));
+        }
+        let mut report = |_: &_| {};
+        let mv = Search::new(&stop, &mut report)
+            .with_history(history.clone())
+            .deterministic()
+            .run(&chess, 3)?;
+        history.push(chess.hash());
+        chess.play(&mv).expect("the move is legal");
+    }
+    chess.is_checkmate().then_some(50)
+}
+
+#[test]
+fn mates_with_king_and_queen() {
+    assert!(moves_to_mate("8/8/8/4k3/8/8/8/4K2Q w - - 0 1").is_some());
+}
+
+#[test]
+fn mates_with_king_and_rook() {
+    assert!(moves_to_mate("8/8/8/4k3/8/8/8/R3K3 w - - 0 1").is_some());
+}
+
+#[test]
+fn mates_with_two_bishops() {
+    assert!(moves_to_mate("8/8/8/4k3/8/8/8/2B1KB2 w - - 0 1").is_some());
+}
+
+#[test]
+fn mates_with_bishop_and_knight() {
+    assert!(moves_to_mate("8/8/8/4k3/8/8/8/1N2KB2 w - - 0 1").is_some());
+}
+
+#[test]
+fn black_mates_too() {
+    assert!(moves_to_mate("4k2q/8/8/8/4K3/8/8/8 b - - 0 1").is_some());
+}
+
+#[test]
+fn recognises_drawn_endgames() {
+    for fen in [
+        // a minor piece against a bare king
+        "8/8/8/4k3/8/8/8/4KB2 w - - 0 1",
+        "8/8/8/4k3/8/8/8/1N2K3 w - - 0 1",
+        // two knights
+        "8/8/8/4k3/8/8/8/1N2K1N1 w - - 0 1",
+        // bishop against knight
+        "8/8/8/2n1k3/8/8/8/4KB2 w - - 0 1",
+        // a light bishop can't drive the king off the dark h8 queening square
+        "7k/8/8/7P/8/8/8/3BK3 w - - 0 1",
+        // a lone rook pawn with the defender in front
+        "k7/8/8/P7/8/8/8/4K3 w - - 0 1",
+    ] {
+        let chess = Chess::from_fen(fen).expect("the position is valid");
+        assert_eq!(evaluate(&chess), 0, "{fen}");
+    }
+    // the right bishop wins
+    let right = Chess::from_fen("7k/8/8/7P/8/8/8/2B1K3 w - - 0 1").expect("the position is valid");
+    assert!(evaluate(&right) > 0);
+}
@@ -0,0 +1,468 @@
+// The engine library on its own, as tools use it without the game
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use chess::engine::pgn::{PgnGame, ResultFilter};
+use chess::engine::puzzle::{update_rating, Attempt, Puzzle};
+use chess::engine::setup::SetupError;
+use chess::search::analysis::analyse;
+use chess::search::book::{decode_move, encode_move, BookEntry, OpeningBook};
+use chess::search::explorer::OpeningIndex;
+use chess::search::minimax::{best_move, Search};
+use chess::search::review::{accuracy, review_game, win_chance, Judgement};
+use chess::search::smp::parallel_search;
+use chess::Chess;
+
+#[test]
+fn plays_a_game_from_fen_and_uci_moves() {
+    let mut chess = Chess::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+        .expect("the start position is valid");
+    assert_eq!(chess.hash(), Chess::new().hash());
+    for uci in ["e2e4", "c7c5", "g1f3"] {
+        let mv = chess.parse_uci(uci).expect("the move is legal");
+        chess.play(&mv).expect("the move is played");
+    }
+    assert_eq!(
+        chess.to_fen(),
+        "rnbqkbnr/pp1ppppp/8/2p5/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+    );
+    assert!(chess.parse_uci("e1e2").is_none());
+}
+
+// Counts the move paths `depth` plies deep, to compare with known totals
+fn perft(chess: &Chess, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    chess
+        .legal_moves()
+        .iter()
+        .map(|mv| {
+            let mut next = chess.clone();
+            next.play(mv).expect("legal moves can be played");
+            perft(&next, depth - 1)
+        })
+        .sum()
+}
+
+#[test]
+fn perft_counts_match_known_totals() {
+    // Position 3 of the Chess Programming Wiki's perft results, full of
+    // pawns capturing next to enemy pawns and en passant pins
+    let chess = Chess::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1")
+        .expect("the position is valid");
+    assert_eq!(perft(&chess, 4), 43238);
+}
+
+#[test]
+fn capturing_beside_an_enemy_pawn_is_not_en_passant() {
+    let mut chess =
+        Chess::from_fen("4k3/8/8/3n4/3pP3/8/8/4K3 w - - 0 1").expect("the position is valid");
+    let mv = chess.parse_uci("e4d5").expect("the capture is legal");
+    chess.play(&mv).expect("the capture is played");
+    assert_eq!(chess.to_fen(), "4k3/8/8/3P4/3p4/8/8/4K3 b - - 0 1");
+}
+
+#[test]
+fn search_finds_mate_in_one() {
+    let chess =
+        Chess::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 4 4")
+            .expect("the position is valid");
+    let mv = best_move(&chess, 2, &AtomicBool::new(false)).expect("white has moves");
+    assert_eq!(mv.to_uci(), "f3f7");
+}
+
+#[test]
+fn search_stops_when_asked() {
+    assert!(best_move(&Chess::new(), 3, &AtomicBool::new(true)).is_none());
+}
+
+#[test]
+fn search_reports_progress_after_each_depth() {
+    let stop = AtomicBool::new(false);
+    let mut reports = Vec::new();
+    let mut report = |progress: &_| reports.push(*progress);
+    let best = Search::new(&stop, &mut report).run(&Chess::new(), 3);
+
+    let mut depths = reports
+        .iter()
+        .map(|progress| progress.depth)
+        .collect::<Vec<_>>();
+    depths.dedup();
+    assert_eq!(depths, [1, 2, 3]);
+    assert!(reports
+        .windows(2)
+        .all(|pair| pair[0].nodes <= pair[1].nodes));
+    assert_eq!(reports.last().and_then(|progress| progress.best), best);
+}
+
+#[test]
+fn deterministic_search_repeats_itself() {
+    let chess = Chess::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+        .expect("the position is valid");
+    let stop = AtomicBool::new(false);
+    let search = || {
+        let mut report = |_: &_| {};
+        let mut search = Search::new(&stop, &mut report).deterministic();
+        let best = search.run(&chess, 3);
+        (best, search.progress().score, search.progress().nodes)
+    };
+    let first = search();
+    assert!(first.0.is_some());
+    assert_eq!(search(), first);
+}
+
+#[test]
+fn parallel_search_finds_mate_in_one() {
+    let chess =
+        Chess::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 4 4")
+            .expect("the position is valid");
+    let stop = AtomicBool::new(false);
+    let progress = parallel_search(&chess, &[], 3, 4, Arc::default(), None, &stop, &mut |_| {});
+    assert_eq!(progress.best.map(|mv| mv.to_uci()).as_deref(), Some("f3f7"));
+    assert_eq!(progress.depth, 3);
+}
+
+#[test]
+fn analysis_reports_the_best_lines() {
+    let chess =
+        Chess::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 4 4")
+            .expect("the position is valid");
+    let stop = AtomicBool::new(false);
+    let mut depths = Vec::new();
+    let analysis = analyse(&chess, &[], 3, 3, None, &stop, &mut |analysis| {
+        depths.push(analysis.depth)
+    });
+    assert_eq!(depths, [1, 2, 3]);
+    assert_eq!(analysis.lines.len(), 3);
+    let best = &analysis.lines[0];
+    assert_eq!(best.moves[0].to_uci(), "f3f7");
+    assert_eq!(analysis.score_text(best.score), "#1");
+    assert!(analysis
+        .lines
+        .windows(2)
+        .all(|pair| pair[0].score >= pair[1].score));
+    // Each line is played out from the position
+    for line in &analysis.lines {
+        let mut after = chess.clone();
+        for mv in &line.moves {
+            assert!(after.play(mv).is_some(), "{:?}", line.moves);
+        }
+    }
+    assert!(
+        analyse(&chess, &[], 3, 3, None, &AtomicBool::new(true), &mut |_| {})
+            .lines
+            .is_empty()
+    );
+}
+
+#[test]
+fn bundled_book_knows_the_start_position() {
+    let book = OpeningBook::bundled();
+    let chess = Chess::new();
+    // Polyglot's published key for the start position
+    assert_eq!(chess.hash(), 0x463b96181691fc9c);
+    let moves = book
+        .moves(&chess)
+        .into_iter()
+        .map(|(mv, weight)| (mv.to_uci(), weight))
+        .collect::<Vec<_>>();
+    assert!(moves.contains(&("e2e4".to_string(), 12)));
+    assert!(moves.contains(&("d2d4".to_string(), 8)));
+    let mv = book.pick(&chess).expect("the book has moves");
+    assert!(moves.iter().any(|(uci, _)| *uci == mv.to_uci()));
+    assert_eq!(
+        OpeningBook::from_bytes(&book.to_bytes())
+            .moves(&chess)
+            .len(),
+        moves.len()
+    );
+}
+
+#[test]
+fn book_moves_castle_by_taking_the_rook() {
+    let chess = Chess::from_fen("r3k2r/pppppppp/8/8/8/8/PPPPPPPP/R3K2R w KQkq - 0 1")
+        .expect("the position is valid");
+    let castle = chess.parse_uci("e1g1").expect("white may castle");
+    // e1 is square 4 and h1 square 7
+    assert_eq!(encode_move(&chess, &castle), 4 << 6 | 7);
+    assert_eq!(decode_move(&chess, 4 << 6 | 7), Some(castle));
+    assert_eq!(
+        decode_move(&chess, 4 << 6).map(|mv| mv.to_uci()).as_deref(),
+        Some("e1c1")
+    );
+
+    let book = OpeningBook::from_entries(vec![BookEntry {
+        key: chess.hash(),
+        mv: 4 << 6 | 7,
+        weight: 1,
+    }]);
+    assert_eq!(book.pick(&chess), Some(castle));
+    assert!(book.pick(&Chess::new()).is_none());
+}
+
+#[test]
+fn review_judges_moves_against_the_engine() {
+    // White misses mate with Qxf7
+    let mut chess =
+        Chess::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 4 4")
+            .expect("the position is valid");
+    let mut positions = Vec::new();
+    let mut moves = Vec::new();
+    for uci in ["g1e2", "d8h4", "e2g3"] {
+        let mv = chess.parse_uci(uci).expect("the move is legal");
+        positions.push(chess.clone());
+        moves.push(mv);
+        chess.play(&mv);
+    }
+    let mut reviews = Vec::new();
+    review_game(
+        &positions,
+        &moves,
+        2,
+        &AtomicBool::new(false),
+        &mut |ply, review| reviews.push((ply, *review)),
+    );
+    assert_eq!(
+        reviews.iter().map(|(ply, _)| *ply).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+    let (_, missed_mate) = reviews[0];
+    assert!(missed_mate.white);
+    assert_eq!(missed_mate.best.to_uci(), "f3f7");
+    assert_eq!(missed_mate.judgement(), Judgement::Blunder);
+    assert!(missed_mate.accuracy() < 10.);
+    let reviews = reviews
+        .into_iter()
+        .map(|(_, review)| review)
+        .collect::<Vec<_>>();
+    assert!(accuracy(&reviews, true).is_some_and(|white| white < 60.));
+    assert!(accuracy(&[], false).is_none());
+    assert_eq!(win_chance(0, true), 50.);
+}
+
+#[test]
+fn setup_checks_the_position_before_play() {
+    let mut chess = Chess::empty();
+    assert_eq!(
+        chess.validate_setup(),
+        Err(SetupError::KingCount { white: true })
+    );
+    // Kings on e1 and e8, with a white rook on h1 and a black pawn on d5
+    for (idx, piece) in [(4, 5), (60, 11), (7, 1), (35, 6)] {
+        chess.set_piece(idx, Some(piece));
+    }
+    assert_eq!(chess.validate_setup(), Ok(()));
+    assert_eq!(chess.possible_castling_rights(), 1);
+    assert_eq!(chess.en_passant_squares(), [43]);
+
+    chess.castling_rights = chess.possible_castling_rights();
+    chess.en_passant = Some(43);
+    assert_eq!(chess.to_fen(), "4k3/8/8/3p4/8/8/8/4K2R w K d6 0 1");
+    assert!(chess.parse_uci("e1g1").is_some());
+
+    // Moving the rook loses castling, and passing the move drops en passant
+    chess.set_piece(7, None);
+    chess.set_piece(6, Some(1));
+    assert_eq!(chess.castling_rights, 0);
+    chess.set_turn(false);
+    assert_eq!(chess.en_passant, None);
+    assert_eq!(chess.validate_setup(), Ok(()));
+
+    // The rook on e-file checks the king of the side not to move
+    chess.set_piece(6, None);
+    chess.set_piece(52, Some(1));
+    chess.set_turn(true);
+    assert_eq!(chess.validate_setup(), Err(SetupError::OpponentInCheck));
+    chess.set_piece(52, None);
+    chess.set_piece(0, Some(0));
+    assert_eq!(chess.validate_setup(), Err(SetupError::PawnOnBackRank));
+}
+
+#[test]
+fn bundled_puzzles_are_playable() {
+    let puzzles = Puzzle::bundled();
+    assert!(!puzzles.is_empty());
+    for puzzle in puzzles {
+        let mut chess = Chess::from_fen(&puzzle.fen).expect("the puzzle's position is valid");
+        let mut mated = false;
+        for uci in &puzzle.moves {
+            let mv = chess.parse_uci(uci).expect("the solution is legal");
+            mated = chess.play(&mv).expect("the move is played").checkmate;
+        }
+        if puzzle.themes.iter().any(|theme| theme.starts_with("mate")) {
+            assert!(mated, "puzzle {} ends in mate", puzzle.id);
+        }
+    }
+}
+
+#[test]
+fn puzzles_judge_the_solver_moves() {
+    assert_eq!(Puzzle::from_csv_line("PuzzleId,FEN,Moves,Rating"), None);
+    let puzzle = Puzzle::from_csv_line(
+        "p1,7k/8/8/8/8/8/RR6/6K1 b - - 0 1,h8g8 a2a7 g8f8 b2b8,1100,80,95,1000,mate mateIn2,,",
+    )
+    .expect("the line is a puzzle");
+    assert!(puzzle.solver_is_white());
+    assert_eq!(puzzle.themes, ["mate", "mateIn2"]);
+
+    let mut chess = Chess::from_fen(&puzzle.fen).unwrap();
+    chess.play(&chess.parse_uci("h8g8").unwrap());
+    let wrong = chess.parse_uci("a2a8").unwrap();
+    assert_eq!(puzzle.attempt(&chess, 1, &wrong), Attempt::Wrong);
+    let correct = chess.parse_uci("a2a7").unwrap();
+    assert_eq!(puzzle.attempt(&chess, 1, &correct), Attempt::Correct);
+    chess.play(&correct);
+    chess.play(&chess.parse_uci("g8f8").unwrap());
+    let mate = chess.parse_uci("b2b8").unwrap();
+    assert_eq!(puzzle.attempt(&chess, 3, &mate), Attempt::Solved);
+
+    // Any mate solves the puzzle, not only the one in the solution
+    let puzzle = Puzzle::from_csv_line("p2,k7/8/1K6/8/8/8/8/6RR b - - 0 1,a8b8 h1h8,800").unwrap();
+    let mut chess = Chess::from_fen(&puzzle.fen).unwrap();
+    chess.play(&chess.parse_uci("a8b8").unwrap());
+    let other_mate = chess.parse_uci("g1g8").unwrap();
+    assert_eq!(puzzle.attempt(&chess, 1, &other_mate), Attempt::Solved);
+
+    assert_eq!(update_rating(1500, 1500, true), 1516);
+    assert_eq!(update_rating(1500, 1500, false), 1484);
+    assert!(update_rating(1500, 2500, true) > update_rating(1500, 1000, true));
+}
+
+const GAMES: &str = r#"[Event "Club \"open\""]
+[White "Ann"]
+[Black "Bob"]
+[Result "1-0"]
+
+1. e4 {Best by test} e5 (1... c5 2. Nf3) 2. Nf3 $1 Nc6 3.Bb5 a6 ; the Morphy
+4. Ba4 1-0
+
+[Event "Club"]
+[White "Bob"]
+[Black "Ann"]
+[Result "1/2-1/2"]
+[Opening "Sicilian Defence"]
+
+1. e4 c5 2. Nf3 d6 3. Bc4 Nf6 4. 0-0 Nc6 5.Nc3 Bg4 6.d3 Qd7 7. Be3 O-O-O 1/2-1/2
+
+[White "Cid"]
+[Black "Ann"]
+[FEN "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1"]
+
+1. e4 Kd7 *
+"#;
+
+#[test]
+fn reads_games_from_pgn() {
+    let games = PgnGame::parse_all(GAMES);
+    assert_eq!(games.len(), 3);
+    let first = &games[0];
+    assert_eq!(first.header("Event"), Some("Club \"open\""));
+    assert_eq!(first.moves, ["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "Ba4"]);
+    assert_eq!(
+        first.comments.get(&1).map(String::as_str),
+        Some("Best by test")
+    );
+    assert_eq!(
+        first.comments.get(&6).map(String::as_str),
+        Some("the Morphy")
+    );
+    assert_eq!(first.result, "1-0");
+
+    let (positions, moves) = first
+        .replay()
+        .expect("the game starts from the standard position");
+    assert_eq!((positions.len(), moves.len()), (8, 7));
+
+    // Castling, also written with zeros, with move numbers either way round
+    let (positions, moves) = games[1].replay().unwrap();
+    assert_eq!(games[1].moves[6], "0-0");
+    assert_eq!(moves.len(), 14);
+    assert_eq!(
+        positions[7].to_fen(),
+        "rnbqkb1r/pp2pppp/3p1n2/2p5/2B1P3/5N2/PPPP1PPP/RNBQ1RK1 b kq - 3 4"
+    );
+    assert_eq!(
+        positions[14].to_fen(),
+        "2kr1b1r/pp1qpppp/2np1n2/2p5/2B1P1b1/2NPBN2/PPP2PPP/R2Q1RK1 w - - 3 8"
+    );
+    assert_eq!(games[2].result, "*");
+    let (positions, _) = games[2].replay().unwrap();
+    assert_eq!(positions[2].to_fen(), "8/3k4/8/8/4P3/8/8/4K3 w - - 1 2");
+
+    // What the game writes, it reads back
+    let again = PgnGame::parse_all(&first.to_pgn());
+    assert_eq!(again[0].moves, first.moves);
+    assert_eq!(again[0].comments, first.comments);
+}
+
+#[test]
+fn filters_games_by_player_opening_and_result() {
+    let games = PgnGame::parse_all(GAMES);
+    let passing = |text: &str, result: ResultFilter| {
+        games
+            .iter()
+            .enumerate()
+            .filter(|(_, game)| game.matches(text, result))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(passing("", ResultFilter::All), [0, 1, 2]);
+    assert_eq!(passing("ann", ResultFilter::All), [0, 1, 2]);
+    assert_eq!(passing("CID", ResultFilter::All), [2]);
+    assert_eq!(passing("sicilian", ResultFilter::All), [1]);
+    assert_eq!(passing("open", ResultFilter::All), [0]);
+    assert_eq!(passing("", ResultFilter::WhiteWins), [0]);
+    assert!(passing("", ResultFilter::BlackWins).is_empty());
+    assert_eq!(passing("bob", ResultFilter::Draws), [1]);
+    assert!(passing("cid", ResultFilter::Draws).is_empty());
+}
+
+#[test]
+fn replay_steps_through_positions_and_comments() {
+    let game = &PgnGame::parse_all(GAMES)[0];
+    let (positions, moves) = game.replay().unwrap();
+
+    // Stepping forward from any ply plays the next move, and stepping back
+    // shows the position before it
+    for (ply, mv) in moves.iter().enumerate() {
+        let mut chess = positions[ply].clone();
+        assert_eq!(chess.to_san(mv), game.moves[ply]);
+        chess.play(mv);
+        assert_eq!(chess.to_fen(), positions[ply + 1].to_fen());
+    }
+    // The comment shown after each step is the one following that move
+    let shown = (0..=moves.len())
+        .filter_map(|ply| {
+            game.comments
+                .get(&ply)
+                .map(|comment| (ply, comment.as_str()))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(shown, [(1, "Best by test"), (6, "the Morphy")]);
+}
+
+#[test]
+fn opening_index_counts_results_by_move() {
+    let games = PgnGame::parse_all(GAMES);
+    let index = OpeningIndex::from_bytes(&OpeningIndex::build(&games, 3).to_bytes());
+
+    // The unfinished game isn't counted
+    let start = index.moves(&Chess::new());
+    assert_eq!(start.len(), 1);
+    assert_eq!(start[0].mv.to_uci(), "e2e4");
+    assert_eq!(start[0].results, [1, 1, 0]);
+    assert_eq!(start[0].percentages(), [50., 50., 0.]);
+
+    let mut chess = Chess::new();
+    chess.play(&chess.parse_uci("e2e4").unwrap());
+    let replies = index.moves(&chess);
+    assert_eq!(
+        replies.iter().map(|explored| explored.games()).sum::<u32>(),
+        2
+    );
+    // Only the first three plies of each game are indexed
+    chess.play(&chess.parse_uci("e7e5").unwrap());
+    chess.play(&chess.parse_uci("g1f3").unwrap());
+    assert!(index.moves(&chess).is_empty());
+}
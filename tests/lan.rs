@@ -0,0 +1,246 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use chess::net::lan;
+use chess::net::protocol::*;
+
+const TIME_CONTROL: TimeControl = TimeControl {
+    initial_secs: 300.,
+    increment_secs: 2.,
+};
+
+struct Player {
+    outgoing: Sender<ClientMessage>,
+    incoming: Receiver<ServerMessage>,
+}
+
+impl Player {
+    fn send(&self, message: ClientMessage) {
+        self.outgoing.send(message).unwrap();
+    }
+
+    fn receive(&self) -> ServerMessage {
+        self.incoming.recv_timeout(Duration::from_secs(5)).unwrap()
+    }
+}
+
+fn start_host() -> (Player, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let (outgoing, outgoing_rx) = channel();
+    let (incoming_tx, incoming) = channel();
+    thread::spawn(move || {
+        lan::host(
+            listener,
+            Color::White,
+            TIME_CONTROL,
+            &outgoing_rx,
+            |message| {
+                let _ = incoming_tx.send(message);
+            },
+        )
+    });
+    (Player { outgoing, incoming }, address)
+}
+
+// Joins or watches a hosted game
+type Connect =
+    fn(&str, &Receiver<ClientMessage>, Box<dyn FnMut(ServerMessage)>) -> std::io::Result<()>;
+
+fn connect(address: String, connect: Connect) -> Player {
+    let (outgoing, outgoing_rx) = channel();
+    let (incoming_tx, incoming) = channel();
+    thread::spawn(move || {
+        connect(
+            &address,
+            &outgoing_rx,
+            Box::new(move |message| {
+                let _ = incoming_tx.send(message);
+            }),
+        )
+    });
+    Player { outgoing, incoming }
+}
+
+fn join(address: String) -> Player {
+    connect(address, |address, outgoing, deliver| {
+        lan::join(address, outgoing, deliver)
+    })
+}
+
+fn watch(address: String) -> Player {
+    connect(address, |address, outgoing, deliver| {
+        lan::watch(address, outgoing, deliver)
+    })
+}
+
+fn expect_handshake(player: &Player, color: Color) {
+    assert!(matches!(player.receive(), ServerMessage::Joined { color: c, .. } if c == color));
+    assert_eq!(
+        player.receive(),
+        ServerMessage::TimeControl {
+            time_control: TIME_CONTROL
+        }
+    );
+    assert!(matches!(player.receive(), ServerMessage::Sync { moves, .. } if moves.is_empty()));
+    assert_eq!(player.receive(), ServerMessage::OpponentJoined);
+}
+
+fn moved(seq: usize, uci: &str) -> ServerMessage {
+    ServerMessage::Moved {
+        seq,
+        uci: uci.to_string(),
+    }
+}
+
+#[test]
+fn plays_moves_between_host_and_joining_player() {
+    let (host, address) = start_host();
+    let guest = join(address);
+    expect_handshake(&guest, Color::Black);
+    expect_handshake(&host, Color::White);
+
+    host.send(ClientMessage::Move {
+        seq: 1,
+        uci: "e2e4".to_string(),
+    });
+    assert_eq!(host.receive(), moved(1, "e2e4"));
+    assert_eq!(guest.receive(), moved(1, "e2e4"));
+
+    guest.send(ClientMessage::Move {
+        seq: 2,
+        uci: "e7e5".to_string(),
+    });
+    assert_eq!(guest.receive(), moved(2, "e7e5"));
+    assert_eq!(host.receive(), moved(2, "e7e5"));
+
+    guest.send(ClientMessage::Resign);
+    assert_eq!(
+        host.receive(),
+        ServerMessage::Resigned {
+            color: Color::Black
+        }
+    );
+}
+
+#[test]
+fn refuses_illegal_local_moves() {
+    let (host, address) = start_host();
+    let guest = join(address);
+    expect_handshake(&guest, Color::Black);
+    expect_handshake(&host, Color::White);
+
+    guest.send(ClientMessage::Move {
+        seq: 1,
+        uci: "e7e5".to_string(),
+    });
+    assert!(matches!(
+        guest.receive(),
+        ServerMessage::Rejected { seq: 1, .. }
+    ));
+    assert!(matches!(guest.receive(), ServerMessage::Sync { .. }));
+
+    host.send(ClientMessage::Move {
+        seq: 1,
+        uci: "e2e5".to_string(),
+    });
+    assert!(matches!(
+        host.receive(),
+        ServerMessage::Rejected { seq: 1, .. }
+    ));
+}
+
+#[test]
+fn host_resyncs_a_peer_whose_position_differs() {
+    let (host, address) = start_host();
+    let stream = TcpStream::connect(address).unwrap();
+    let mut writer = stream.try_clone().unwrap();
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    assert!(line.contains("\"type\":\"hello\""));
+    writeln!(writer, "{{\"type\":\"ready\",\"version\":1}}").unwrap();
+    expect_handshake(&host, Color::White);
+
+    host.send(ClientMessage::Move {
+        seq: 1,
+        uci: "e2e4".to_string(),
+    });
+    assert_eq!(host.receive(), moved(1, "e2e4"));
+    line.clear();
+    reader.read_line(&mut line).unwrap();
+    assert!(line.contains("\"type\":\"move\""));
+
+    // Acknowledge the move with a hash of some other position
+    writeln!(writer, "{{\"type\":\"ack\",\"seq\":1,\"hash\":1}}").unwrap();
+    assert!(matches!(host.receive(), ServerMessage::Error { .. }));
+    line.clear();
+    reader.read_line(&mut line).unwrap();
+    assert!(line.contains("\"type\":\"sync\"") && line.contains("e2e4"));
+}
+
+#[test]
+fn hosting_goes_on_past_bad_connections() {
+    let (host, address) = start_host();
+    // One connection never answers, the other answers with nonsense
+    let _silent = TcpStream::connect(&address).unwrap();
+    let stream = TcpStream::connect(&address).unwrap();
+    let mut writer = stream.try_clone().unwrap();
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line).unwrap();
+    writeln!(writer, "{{\"type\":\"ready\",\"version\":0}}").unwrap();
+
+    let guest = join(address);
+    expect_handshake(&guest, Color::Black);
+    expect_handshake(&host, Color::White);
+}
+
+#[test]
+fn spectators_follow_the_hosted_game() {
+    let (host, address) = start_host();
+    let early = watch(address.clone());
+    assert!(matches!(early.receive(), ServerMessage::Watching { .. }));
+    early.receive();
+    assert!(matches!(early.receive(), ServerMessage::Sync { moves, .. } if moves.is_empty()));
+
+    let guest = join(address.clone());
+    expect_handshake(&guest, Color::Black);
+    expect_handshake(&host, Color::White);
+    host.send(ClientMessage::Move {
+        seq: 1,
+        uci: "d2d4".to_string(),
+    });
+    assert_eq!(guest.receive(), moved(1, "d2d4"));
+    assert_eq!(early.receive(), moved(1, "d2d4"));
+
+    // Joining late gets the moves played so far
+    let late = watch(address);
+    late.receive();
+    assert_eq!(
+        late.receive(),
+        ServerMessage::TimeControl {
+            time_control: TIME_CONTROL
+        }
+    );
+    assert!(matches!(late.receive(), ServerMessage::Sync { moves, .. } if moves == ["d2d4"]));
+
+    guest.send(ClientMessage::Move {
+        seq: 2,
+        uci: "d7d5".to_string(),
+    });
+    assert_eq!(early.receive(), moved(2, "d7d5"));
+    assert_eq!(late.receive(), moved(2, "d7d5"));
+
+    guest.send(ClientMessage::Resign);
+    for spectator in [&early, &late] {
+        assert_eq!(
+            spectator.receive(),
+            ServerMessage::Resigned {
+                color: Color::Black
+            }
+        );
+    }
+}
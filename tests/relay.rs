@@ -0,0 +1,258 @@
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use chess::net::protocol::*;
+use chess::net::relay::serve;
+use tungstenite::{connect, stream::MaybeTlsStream, Message, WebSocket};
+
+type Client = WebSocket<MaybeTlsStream<TcpStream>>;
+
+fn start_relay() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener));
+    format!("ws://{address}")
+}
+
+fn send(client: &mut Client, message: ClientMessage) {
+    client.send(Message::Text(message.to_json())).unwrap();
+}
+
+fn receive(client: &mut Client) -> ServerMessage {
+    loop {
+        if let Message::Text(text) = client.read().unwrap() {
+            return ServerMessage::from_json(&text).unwrap();
+        }
+    }
+}
+
+fn join(url: &str, message: ClientMessage) -> (Client, String, Color, String) {
+    let (mut client, _) = connect(url).unwrap();
+    send(&mut client, message);
+    let ServerMessage::Joined { code, color, token } = receive(&mut client) else {
+        panic!("expected to join a room");
+    };
+    (client, code, color, token)
+}
+
+#[test]
+fn relays_moves_between_players_of_a_room() {
+    let url = start_relay();
+    let (mut white, code, color, _) = join(&url, ClientMessage::CreateRoom);
+    assert_eq!(color, Color::White);
+    assert_eq!(code.len(), 5);
+    assert!(matches!(receive(&mut white), ServerMessage::Sync { moves, .. } if moves.is_empty()));
+
+    let (mut black, _, color, _) = join(&url, ClientMessage::JoinRoom { code: code.clone() });
+    assert_eq!(color, Color::Black);
+    receive(&mut black);
+    assert_eq!(receive(&mut white), ServerMessage::OpponentJoined);
+
+    let e4 = ClientMessage::Move {
+        seq: 1,
+        uci: "e2e4".to_string(),
+    };
+    send(&mut white, e4);
+    let moved = ServerMessage::Moved {
+        seq: 1,
+        uci: "e2e4".to_string(),
+    };
+    assert_eq!(receive(&mut white), moved);
+    assert_eq!(receive(&mut black), moved);
+
+    let (mut third, _) = connect(&url).unwrap();
+    send(&mut third, ClientMessage::JoinRoom { code });
+    assert!(matches!(receive(&mut third), ServerMessage::Error { .. }));
+}
+
+#[test]
+fn rejects_illegal_and_out_of_turn_moves() {
+    let url = start_relay();
+    let (mut white, code, ..) = join(&url, ClientMessage::CreateRoom);
+    receive(&mut white);
+    let (mut black, ..) = join(&url, ClientMessage::JoinRoom { code });
+    receive(&mut black);
+    receive(&mut white);
+
+    send(
+        &mut black,
+        ClientMessage::Move {
+            seq: 1,
+            uci: "e7e5".to_string(),
+        },
+    );
+    assert!(matches!(
+        receive(&mut black),
+        ServerMessage::Rejected { seq: 1, .. }
+    ));
+    assert!(matches!(receive(&mut black), ServerMessage::Sync { .. }));
+
+    send(
+        &mut white,
+        ClientMessage::Move {
+            seq: 1,
+            uci: "e2e5".to_string(),
+        },
+    );
+    assert!(matches!(
+        receive(&mut white),
+        ServerMessage::Rejected { seq: 1, .. }
+    ));
+    assert!(matches!(receive(&mut white), ServerMessage::Sync { .. }));
+
+    send(
+        &mut white,
+        ClientMessage::Move {
+            seq: 2,
+            uci: "e2e4".to_string(),
+        },
+    );
+    assert!(matches!(
+        receive(&mut white),
+        ServerMessage::Rejected { seq: 2, .. }
+    ));
+}
+
+#[test]
+fn resyncs_a_player_who_reconnects() {
+    let url = start_relay();
+    let (mut white, code, ..) = join(&url, ClientMessage::CreateRoom);
+    receive(&mut white);
+    let (mut black, _, _, token) = join(&url, ClientMessage::JoinRoom { code: code.clone() });
+    receive(&mut black);
+    receive(&mut white);
+
+    for (seq, uci) in [(1, "e2e4"), (2, "e7e5")] {
+        let player = if seq % 2 == 1 { &mut white } else { &mut black };
+        send(
+            player,
+            ClientMessage::Move {
+                seq,
+                uci: uci.to_string(),
+            },
+        );
+        receive(&mut white);
+        receive(&mut black);
+    }
+
+    black.close(None).unwrap();
+    assert_eq!(receive(&mut white), ServerMessage::OpponentLeft);
+
+    // The code is typed in again, in lower case
+    let (mut black, _, color, _) = join(
+        &url,
+        ClientMessage::Rejoin {
+            code: code.to_lowercase(),
+            token,
+        },
+    );
+    assert_eq!(color, Color::Black);
+    assert_eq!(
+        receive(&mut black),
+        ServerMessage::Sync {
+            fen: "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string(),
+            moves: vec!["e2e4".to_string(), "e7e5".to_string()],
+        }
+    );
+    assert_eq!(receive(&mut white), ServerMessage::OpponentJoined);
+}
+
+#[test]
+fn spectators_get_the_game_so_far_and_its_moves() {
+    let url = start_relay();
+    let (mut white, code, ..) = join(&url, ClientMessage::CreateRoom);
+    receive(&mut white);
+    let (mut black, ..) = join(&url, ClientMessage::JoinRoom { code: code.clone() });
+    receive(&mut black);
+    receive(&mut white);
+    send(
+        &mut white,
+        ClientMessage::Move {
+            seq: 1,
+            uci: "e2e4".to_string(),
+        },
+    );
+    receive(&mut white);
+    receive(&mut black);
+
+    // Joining late, with the code typed in lower case
+    let (mut spectator, _) = connect(&url).unwrap();
+    send(
+        &mut spectator,
+        ClientMessage::Watch {
+            code: code.to_lowercase(),
+        },
+    );
+    assert_eq!(
+        receive(&mut spectator),
+        ServerMessage::Watching { code: code.clone() }
+    );
+    assert!(
+        matches!(receive(&mut spectator), ServerMessage::Sync { moves, .. } if moves == ["e2e4"])
+    );
+
+    send(
+        &mut black,
+        ClientMessage::Move {
+            seq: 2,
+            uci: "e7e5".to_string(),
+        },
+    );
+    assert_eq!(
+        receive(&mut spectator),
+        ServerMessage::Moved {
+            seq: 2,
+            uci: "e7e5".to_string(),
+        }
+    );
+
+    // Spectators can't play
+    send(
+        &mut spectator,
+        ClientMessage::Move {
+            seq: 3,
+            uci: "g1f3".to_string(),
+        },
+    );
+    assert!(matches!(
+        receive(&mut spectator),
+        ServerMessage::Error { .. }
+    ));
+
+    send(&mut white, ClientMessage::Resign);
+    assert_eq!(
+        receive(&mut spectator),
+        ServerMessage::Resigned {
+            color: Color::White
+        }
+    );
+}
+
+#[test]
+fn ignores_moves_once_a_player_resigns() {
+    let url = start_relay();
+    let (mut white, code, ..) = join(&url, ClientMessage::CreateRoom);
+    receive(&mut white);
+    let (mut black, ..) = join(&url, ClientMessage::JoinRoom { code });
+    receive(&mut black);
+    receive(&mut white);
+
+    send(&mut black, ClientMessage::Resign);
+    assert_eq!(
+        receive(&mut white),
+        ServerMessage::Resigned {
+            color: Color::Black
+        }
+    );
+    send(
+        &mut white,
+        ClientMessage::Move {
+            seq: 1,
+            uci: "e2e4".to_string(),
+        },
+    );
+    assert!(matches!(
+        receive(&mut white),
+        ServerMessage::Rejected { seq: 1, .. }
+    ));
+}
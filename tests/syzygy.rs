@@ -0,0 +1,60 @@
+// Syzygy probing. Tests needing tables run with the `syzygy` feature and
+// SYZYGY_PATH set to a directory holding at least the KQvK tables.
+use std::sync::atomic::AtomicBool;
+
+use chess::search::minimax::Search;
+use chess::search::syzygy::Tablebase;
+use chess::Chess;
+
+#[test]
+fn opening_a_directory_without_tables_fails() {
+    let dir = std::env::temp_dir().join(format!("chess-no-tables-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("the directory is created");
+    assert!(Tablebase::open(&dir).is_err());
+    let _ = std::fs::remove_dir(&dir);
+}
+
+#[test]
+fn search_without_tablebase_still_finds_mate() {
+    let chess =
+        Chess::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 4 4")
+            .expect("the position is valid");
+    let stop = AtomicBool::new(false);
+    let mut report = |_: &_| {};
+    let best = Search::new(&stop, &mut report)
+        .with_tablebase(None)
+        .run(&chess, 2);
+    assert_eq!(best.map(|mv| mv.to_uci()).as_deref(), Some("f3f7"));
+}
+
+#[cfg(feature = "syzygy")]
+#[test]
+fn tablebase_plays_winning_moves() {
+    use std::sync::Arc;
+
+    use chess::search::syzygy::Wdl;
+
+    let Ok(dir) = std::env::var("SYZYGY_PATH") else {
+        eprintln!("SYZYGY_PATH isn't set, skipping");
+        return;
+    };
+    let tablebase = Arc::new(Tablebase::open(dir).expect("the tables open"));
+    let white = Chess::from_fen("8/8/8/4k3/8/8/8/4K2Q w - - 0 1").expect("the position is valid");
+    let black = Chess::from_fen("8/8/8/4k3/8/8/8/4K2Q b - - 0 1").expect("the position is valid");
+    assert_eq!(tablebase.probe_wdl(&white), Some(Wdl::Win));
+    assert_eq!(tablebase.probe_wdl(&black), Some(Wdl::Loss));
+    assert!(tablebase.probe_wdl(&Chess::new()).is_none());
+
+    let (best, wdl) = tablebase.best_move(&white).expect("KQvK is in the tables");
+    assert_eq!(wdl, Wdl::Win);
+    let mut after = white.clone();
+    after.play(&best).expect("the move is legal");
+    assert_eq!(tablebase.probe_wdl(&after), Some(Wdl::Loss));
+
+    let stop = AtomicBool::new(false);
+    let mut report = |_: &_| {};
+    let mv = Search::new(&stop, &mut report)
+        .with_tablebase(Some(tablebase.clone()))
+        .run(&white, 3);
+    assert_eq!(mv, Some(best));
+}
@@ -0,0 +1,17 @@
+// Runs the bot's search, analysis and game reviews for the web build away from
+// the page, so the board keeps rendering while the engine thinks
+import init, { analysePosition, reviewGame, searchBestMove } from './out/chess.js'
+
+const ready = init()
+
+onmessage = async ({ data }) => {
+  await ready
+  const post = message => postMessage(message)
+  if (data.review) {
+    reviewGame(data.fen, data.moves, data.depth, post)
+  } else if (data.lines) {
+    analysePosition(data.fen, data.history, data.lines, post)
+  } else {
+    searchBestMove(data.fen, data.history, data.depth, post)
+  }
+}